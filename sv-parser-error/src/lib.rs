@@ -1,3 +1,4 @@
+use std::fmt;
 use std::path::PathBuf;
 use thiserror::Error;
 
@@ -18,8 +19,8 @@ pub enum Error {
         #[from]
         source: Box<Error>,
     },
-    #[error("Parse error: {0:?}")]
-    Parse(Option<(PathBuf, usize)>),
+    #[error("Parse error: {}", .0.as_ref().map_or(String::from("unknown position"), |x| x.to_string()))]
+    Parse(Option<Box<ParseDiagnostic>>),
     #[error("Preprocess error")]
     Preprocess,
     #[error("Define argument not found: {0}")]
@@ -32,4 +33,65 @@ pub enum Error {
     ExceedRecursiveLimit,
     #[error("Include line can't have other items")]
     IncludeLine,
+    #[error("Include cycle: {}", .chain.iter().map(|x| x.to_string_lossy()).collect::<Vec<_>>().join(" -> "))]
+    IncludeCycle { chain: Vec<PathBuf> },
+    #[error("Exceed nesting depth limit at {}", .0.as_ref().map_or(String::from("unknown position"), |x| x.location()))]
+    ExceedDepthLimit(Option<Box<ParseDiagnostic>>),
+    #[error("Reparse error: the syntax tree doesn't have its source")]
+    Reparse,
+    #[error("Edit range error: {begin}..{end} isn't on char boundaries of the source")]
+    EditRange { begin: usize, end: usize },
+}
+
+// -----------------------------------------------------------------------------
+
+/// Detailed information of a parse failure
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseDiagnostic {
+    /// Source file of the failing position, `None` if the position has no origin
+    pub path: Option<PathBuf>,
+    /// Byte offset in the source file
+    pub offset: usize,
+    /// 1-based line number in the source file
+    pub line: usize,
+    /// 1-based column number in the source file
+    pub column: usize,
+    /// Physical path and line if a `` `line `` directive changed `path` and `line`
+    pub physical: Option<(PathBuf, usize)>,
+    /// Error kinds and contexts from the innermost to the outermost parser
+    pub context: Vec<String>,
+    /// Tokens which would have been accepted at the failing position
+    pub expected: Vec<String>,
+    /// Token found at the failing position, `None` at end of input
+    pub found: Option<String>,
+}
+
+impl ParseDiagnostic {
+    /// Get `path:line:column` of the failing position
+    pub fn location(&self) -> String {
+        match self.path {
+            Some(ref path) => format!("{}:{}:{}", path.to_string_lossy(), self.line, self.column),
+            None => format!("{}:{}", self.line, self.column),
+        }
+    }
+}
+
+impl fmt::Display for ParseDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.location())?;
+        let found = match self.found {
+            Some(ref x) => format!("'{}'", x),
+            None => String::from("end of input"),
+        };
+        if self.expected.is_empty() {
+            write!(f, "unexpected {}", found)
+        } else {
+            write!(
+                f,
+                "expected one of {}, found {}",
+                self.expected.join(", "),
+                found
+            )
+        }
+    }
 }
@@ -3,9 +3,9 @@
 extern crate proc_macro;
 
 use crate::proc_macro::TokenStream;
-use quote::quote;
+use quote::{quote, ToTokens};
 use syn::Data::{Enum, Struct};
-use syn::{self, DeriveInput};
+use syn::{self, parse_quote, DeriveInput, FnArg, ItemFn, Stmt};
 
 #[proc_macro_derive(Node)]
 pub fn node_derive(input: TokenStream) -> TokenStream {
@@ -16,7 +16,7 @@ pub fn node_derive(input: TokenStream) -> TokenStream {
 fn impl_node(ast: &DeriveInput) -> TokenStream {
     let name = &ast.ident;
 
-    let next = match ast.data {
+    let (next, next_mut) = match ast.data {
         Enum(ref data) => {
             let mut items = quote! {};
             for v in &data.variants {
@@ -30,22 +30,29 @@ fn impl_node(ast: &DeriveInput) -> TokenStream {
                 };
             }
 
-            quote! {
+            let next = quote! {
                 match self {
                     #items
                 }
-            }
+            };
+            (next.clone(), next)
         }
-        Struct(_) => {
+        Struct(_) => (
             quote! {
                 (&(self.nodes)).into()
-            }
-        }
-        _ => {
+            },
+            quote! {
+                (&mut (self.nodes)).into()
+            },
+        ),
+        _ => (
             quote! {
                 vec![].into()
-            }
-        }
+            },
+            quote! {
+                vec![].into()
+            },
+        ),
     };
 
     let gen = quote! {
@@ -61,6 +68,24 @@ fn impl_node(ast: &DeriveInput) -> TokenStream {
             }
         }
 
+        impl<'a> NodeMut<'a> for #name {
+            fn next_mut(&'a mut self) -> RefNodesMut<'a> {
+                #next_mut
+            }
+        }
+
+        impl<'a> From<&'a mut #name> for RefNodesMut<'a> {
+            fn from(x: &'a mut #name) -> Self {
+                vec![RefNodeMut::#name(x)].into()
+            }
+        }
+
+        impl<'a> From<&'a mut #name> for RefNodeMut<'a> {
+            fn from(x: &'a mut #name) -> Self {
+                RefNodeMut::#name(x)
+            }
+        }
+
         impl From<#name> for AnyNode {
             fn from(x: #name) -> Self {
                 AnyNode::#name(x)
@@ -73,6 +98,16 @@ fn impl_node(ast: &DeriveInput) -> TokenStream {
             }
         }
 
+        impl<'a> core::convert::TryFrom<RefNode<'a>> for &'a #name {
+            type Error = ();
+            fn try_from(x: RefNode<'a>) -> Result<Self, Self::Error> {
+                match x {
+                    RefNode::#name(x) => Ok(x),
+                    _ => Err(()),
+                }
+            }
+        }
+
         impl core::convert::TryFrom<#name> for Locate {
             type Error = ();
             fn try_from(x: #name) -> Result<Self, Self::Error> {
@@ -127,6 +162,7 @@ fn impl_any_node(ast: &DeriveInput) -> TokenStream {
 
     let mut try_froms = quote! {};
     let mut from_items = quote! {};
+    let mut from_mut_items = quote! {};
     for v in &data.variants {
         let ident = &v.ident;
 
@@ -134,7 +170,7 @@ fn impl_any_node(ast: &DeriveInput) -> TokenStream {
             #try_froms
             impl TryFrom<AnyNode> for #ident  {
                 type Error = ();
-                fn try_from(x: AnyNode) -> Result<Self, Self::Error> {
+                fn try_from(x: AnyNode) -> Result<Self, ()> {
                     match x {
                         AnyNode::#ident(x) => Ok(x),
                         _ => Err(()),
@@ -147,6 +183,11 @@ fn impl_any_node(ast: &DeriveInput) -> TokenStream {
             #from_items
             AnyNode::#ident(x) => RefNode::#ident(&x),
         };
+
+        from_mut_items = quote! {
+            #from_mut_items
+            AnyNode::#ident(x) => RefNodeMut::#ident(x),
+        };
     }
 
     let gen = quote! {
@@ -159,6 +200,14 @@ fn impl_any_node(ast: &DeriveInput) -> TokenStream {
                 }
             }
         }
+
+        impl<'a> From<&'a mut AnyNode> for RefNodeMut<'a>  {
+            fn from(x: &'a mut AnyNode) -> Self {
+                match x {
+                    #from_mut_items
+                }
+            }
+        }
     };
     gen.into()
 }
@@ -177,12 +226,17 @@ fn impl_ref_node(ast: &DeriveInput) -> TokenStream {
 
     let mut next_items = quote! {};
     let mut into_iter_items = quote! {};
+    let mut type_id_items = quote! {};
     for v in &data.variants {
         let ident = &v.ident;
         next_items = quote! {
             #next_items
             RefNode::#ident(x) => x.next(),
         };
+        type_id_items = quote! {
+            #type_id_items
+            RefNode::#ident(_) => core::any::TypeId::of::<#ident>(),
+        };
         into_iter_items = quote! {
             #into_iter_items
             RefNode::#ident(x) => x.into_iter(),
@@ -197,6 +251,13 @@ fn impl_ref_node(ast: &DeriveInput) -> TokenStream {
                     #next_items
                 }
             }
+
+            /// `TypeId` of the node type referred by this variant
+            pub fn node_type_id(&self) -> core::any::TypeId {
+                match self {
+                    #type_id_items
+                }
+            }
         }
 
         impl<'a> IntoIterator for #name<'a> {
@@ -212,3 +273,111 @@ fn impl_ref_node(ast: &DeriveInput) -> TokenStream {
     };
     gen.into()
 }
+
+/// Packrat memoization for parsers taking a `Span`
+///
+/// This is the same as `nom_packrat::packrat_parser`, but the memo is stored in the
+/// `ParserContext` of the input instead of a thread-local storage.
+/// Failures aren't memoized while recovering from errors, because the memo doesn't have
+/// the error position which the skipped region depends on.
+/// The memo keeps the nesting depth reached by a node, so a cache hit fails beyond
+/// `ParserOptions::max_depth` as parsing the node again does.
+#[proc_macro_attribute]
+pub fn packrat_parser(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item = syn::parse_macro_input!(item as ItemFn);
+    impl_packrat_parser(&item)
+}
+
+fn impl_packrat_parser(item: &ItemFn) -> TokenStream {
+    let ident = &item.sig.ident;
+    let input = match item.sig.inputs.first() {
+        Some(FnArg::Typed(arg)) => &arg.pat,
+        _ => panic!("function with #[packrat_parser] must have an argument"),
+    };
+    let body = item.block.as_ref();
+
+    let before: Stmt = parse_quote! {
+        let org_input = if let Some(x) = (|| {
+            use nom::AsBytes;
+            use nom_packrat::HasExtraState;
+            let ptr = #input.as_bytes().as_ptr();
+            let extra = #input.get_extra_state();
+            let storage = #input.extra.context.packrat.borrow();
+            match storage.get(&(stringify!(#ident), ptr, extra)) {
+                Some(Some((x, y))) => Some(Some((x.node(), x.height, *y))),
+                Some(None) => Some(None),
+                None => None,
+            }
+        })() {
+            if let Some((x, height, y)) = x {
+                crate::utils::reach(#input, #input.extra.context.depth.get() + height)?;
+                use nom::InputTake;
+                let (s, _) = #input.take_split(y);
+                use std::convert::TryInto;
+                let x = x.try_into().map_err(|_| nom::Err::Error(nom::error::make_error(#input, nom::error::ErrorKind::Fix)))?;
+                #[cfg(feature = "trace")]
+                {
+                    use nom_tracable::Tracable;
+                    nom_tracable::custom_trace(&#input, stringify!(#ident), "packrat cache hit (accepted)", "\u{001b}[1;33m")
+                };
+                return Ok((s, x))
+            } else {
+                #[cfg(feature = "trace")]
+                {
+                    use nom_tracable::Tracable;
+                    nom_tracable::custom_trace(&#input, stringify!(#ident), "packrat cache hit (rejected)", "\u{001b}[1;33m")
+                };
+                return Err(nom::Err::Error(nom::error::make_error(#input, nom::error::ErrorKind::Fix)));
+            }
+        } else {
+            #input
+        };
+    };
+
+    let body: Stmt = parse_quote! {
+        let (body_ret, body_height) = {
+            let packrat_context = #input.extra.context;
+            let reached = packrat_context.reached.replace(packrat_context.depth.get());
+            let body = || { #body };
+            let ret = body();
+            let height = packrat_context.reached.get() - packrat_context.depth.get();
+            packrat_context.reached.set(reached.max(packrat_context.reached.get()));
+            (ret, height)
+        };
+    };
+
+    let after: Stmt = parse_quote! {
+        {
+            use nom::AsBytes;
+            use nom_packrat::HasExtraState;
+            let ptr = org_input.as_bytes().as_ptr();
+            let extra = org_input.get_extra_state();
+            let storage = &org_input.extra.context.packrat;
+            if let Ok((s, x)) = &body_ret {
+                use nom::Offset;
+                let len = org_input.offset(&s);
+                (|| {
+                    let memo = crate::Memo::new(x, body_height);
+                    storage.borrow_mut().insert((stringify!(#ident), ptr, extra), Some((memo, len)));
+                })();
+                #[cfg(feature = "trace")]
+                {
+                    use nom_tracable::Tracable;
+                    nom_tracable::custom_trace(&org_input, stringify!(#ident), "packrat cache store (accepted)", "\u{001b}[1;33m");
+                };
+            } else if !org_input.extra.context.recover.get() {
+                storage.borrow_mut().insert((stringify!(#ident), ptr, extra), None);
+                #[cfg(feature = "trace")]
+                {
+                    use nom_tracable::Tracable;
+                    nom_tracable::custom_trace(&org_input, stringify!(#ident), "packrat cache store (rejected)", "\u{001b}[1;33m");
+                };
+            }
+            body_ret
+        }
+    };
+
+    let mut item = item.clone();
+    item.block.stmts = vec![before, body, after];
+    item.into_token_stream().into()
+}
@@ -63,12 +63,12 @@ pub(crate) fn pattern_identifier_list(s: Span) -> IResult<Span, Pattern> {
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn assignment_pattern(s: Span) -> IResult<Span, AssignmentPattern> {
-    alt((
+    ieee1800(alt((
         assignment_pattern_list,
         assignment_pattern_structure,
         assignment_pattern_array,
         assignment_pattern_repeat,
-    ))(s)
+    )))(s)
 }
 
 #[tracable_parser]
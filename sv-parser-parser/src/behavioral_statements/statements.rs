@@ -30,7 +30,7 @@ pub(crate) fn statement(s: Span) -> IResult<Span, Statement> {
         terminated(symbol(":"), peek(not(symbol(":")))),
     ))(s)?;
     let (s, b) = many0(attribute_instance)(s)?;
-    let (s, c) = statement_item(s)?;
+    let (s, c) = nested(statement_item)(s)?;
     Ok((s, Statement { nodes: (a, b, c) }))
 }
 
@@ -99,7 +99,7 @@ pub(crate) fn event_control_event_expression(s: Span) -> IResult<Span, EventCont
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn event_control_asterisk(s: Span) -> IResult<Span, EventControl> {
-    let (s, a) = symbol("@*")(s)?;
+    let (s, a) = ieee1364_2001(symbol("@*"))(s)?;
     Ok((
         s,
         EventControl::Asterisk(Box::new(EventControlAsterisk { nodes: (a,) })),
@@ -110,7 +110,7 @@ pub(crate) fn event_control_asterisk(s: Span) -> IResult<Span, EventControl> {
 #[packrat_parser]
 pub(crate) fn event_control_paren_asterisk(s: Span) -> IResult<Span, EventControl> {
     let (s, a) = symbol("@")(s)?;
-    let (s, b) = paren(symbol("*"))(s)?;
+    let (s, b) = ieee1364_2001(paren(symbol("*")))(s)?;
     Ok((
         s,
         EventControl::ParenAsterisk(Box::new(EventControlParenAsterisk { nodes: (a, b) })),
@@ -184,7 +184,7 @@ pub(crate) fn event_expression_or(s: Span) -> IResult<Span, EventExpression> {
 #[packrat_parser]
 pub(crate) fn event_expression_comma(s: Span) -> IResult<Span, EventExpression> {
     let (s, a) = event_expression(s)?;
-    let (s, b) = symbol(",")(s)?;
+    let (s, b) = ieee1364_2001(symbol(","))(s)?;
     let (s, c) = event_expression(s)?;
     Ok((
         s,
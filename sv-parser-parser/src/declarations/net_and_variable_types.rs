@@ -45,17 +45,18 @@ pub(crate) fn data_type(s: Span) -> IResult<Span, DataType> {
         data_type_vector,
         data_type_atom,
         map(non_integer_type, |x| DataType::NonIntegerType(Box::new(x))),
-        data_type_struct_union,
+        nested(data_type_struct_union),
         data_type_enum,
         map(keyword("string"), |x| DataType::String(Box::new(x))),
         map(keyword("chandle"), |x| DataType::Chandle(Box::new(x))),
         data_type_virtual,
-        map(terminated(class_type, peek(not(packed_dimension))), |x| {
-            DataType::ClassType(Box::new(x))
-        }),
-        data_type_type,
+        map(
+            ieee1800(terminated(class_type, peek(not(packed_dimension)))),
+            |x| DataType::ClassType(Box::new(x)),
+        ),
+        ieee1800(data_type_type),
         map(keyword("event"), |x| DataType::Chandle(Box::new(x))),
-        map(ps_covergroup_identifier, |x| {
+        map(ieee1800(ps_covergroup_identifier), |x| {
             DataType::PsCovergroupIdentifier(Box::new(x))
         }),
         map(type_reference, |x| DataType::TypeReference(Box::new(x))),
@@ -235,7 +236,7 @@ pub(crate) fn enum_name_declaration(s: Span) -> IResult<Span, EnumNameDeclaratio
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn class_scope(s: Span) -> IResult<Span, ClassScope> {
-    let (s, a) = class_type_class_scope(s)?;
+    let (s, a) = ieee1800(class_type_class_scope)(s)?;
     let (s, b) = symbol("::")(s)?;
     Ok((s, ClassScope { nodes: (a, b) }))
 }
@@ -213,7 +213,7 @@ pub(crate) fn vector_scalar(s: Span) -> IResult<Span, VectorScalar> {
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn net_declaration_net_type_identifier(s: Span) -> IResult<Span, NetDeclaration> {
-    let (s, a) = net_type_identifier(s)?;
+    let (s, a) = ieee1800(net_type_identifier)(s)?;
     let (s, b) = opt(delay_control)(s)?;
     let (s, c) = list_of_net_decl_assignments(s)?;
     let (s, d) = symbol(";")(s)?;
@@ -33,53 +33,171 @@ pub(crate) fn inc_or_dec_expression_suffix(s: Span) -> IResult<Span, IncOrDecExp
     ))
 }
 
-#[recursive_parser]
+// The predicate, `?`, attributes, the true expression and `:` of `conditional_expression`
+// except the first operand of the predicate
+type ConditionalExpressionHead = (
+    Option<(Keyword, Pattern)>,
+    Vec<(Symbol, ExpressionOrCondPattern)>,
+    Symbol,
+    Vec<AttributeInstance>,
+    Expression,
+    Symbol,
+);
+
+fn conditional_expression_head(s: Span) -> IResult<Span, ConditionalExpressionHead> {
+    let (s, a) = opt(pair(keyword("matches"), pattern))(s)?;
+    let (s, b) = many0(pair(symbol("&&&"), expression_or_cond_pattern_ternary))(s)?;
+    let (s, c) = symbol("?")(s)?;
+    let (s, d) = many0(attribute_instance)(s)?;
+    let (s, e) = expression(s)?;
+    let (s, f) = symbol(":")(s)?;
+    Ok((s, (a, b, c, d, e, f)))
+}
+
 #[tracable_parser]
 #[packrat_parser]
-pub(crate) fn conditional_expression(s: Span) -> IResult<Span, ConditionalExpression> {
-    let (s, a) = cond_predicate_ternary(s)?;
-    let (s, b) = symbol("?")(s)?;
-    let (s, c) = many0(attribute_instance)(s)?;
-    let (s, d) = expression(s)?;
-    let (s, e) = symbol(":")(s)?;
-    let (s, f) = expression(s)?;
-    Ok((
-        s,
-        ConditionalExpression {
-            nodes: (a, b, c, d, e, f),
-        },
-    ))
+pub(crate) fn expression_or_cond_pattern_ternary(
+    s: Span,
+) -> IResult<Span, ExpressionOrCondPattern> {
+    let (s, a) = expression_binary(s)?;
+    let (s, b) = opt(pair(keyword("matches"), pattern))(s)?;
+    Ok((s, expression_or_cond_pattern(a, b)))
+}
+
+fn expression_or_cond_pattern(
+    a: Expression,
+    b: Option<(Keyword, Pattern)>,
+) -> ExpressionOrCondPattern {
+    match b {
+        Some((b, c)) => {
+            ExpressionOrCondPattern::CondPattern(Box::new(CondPattern { nodes: (a, b, c) }))
+        }
+        None => ExpressionOrCondPattern::Expression(Box::new(a)),
+    }
 }
 
-#[recursive_parser]
 #[tracable_parser]
 #[packrat_parser]
-pub(crate) fn cond_predicate_ternary(s: Span) -> IResult<Span, CondPredicate> {
-    let (s, a) = list(symbol("&&&"), expression_or_cond_pattern_ternary)(s)?;
-    Ok((s, CondPredicate { nodes: (a,) }))
+pub(crate) fn constant_expression(s: Span) -> IResult<Span, ConstantExpression> {
+    nested(constant_expression_implication)(s)
 }
 
+// `->` and `<->` have the lowest precedence and are right associative
 #[tracable_parser]
 #[packrat_parser]
-pub(crate) fn expression_or_cond_pattern_ternary(
-    s: Span,
-) -> IResult<Span, ExpressionOrCondPattern> {
-    alt((
-        map(cond_pattern, |x| {
-            ExpressionOrCondPattern::CondPattern(Box::new(x))
-        }),
-        map(expression, |x| {
-            ExpressionOrCondPattern::Expression(Box::new(x))
-        }),
-    ))(s)
+pub(crate) fn constant_expression_implication(s: Span) -> IResult<Span, ConstantExpression> {
+    let (s, a) = constant_expression_ternary(s)?;
+    let (s, b) = many0(tuple((
+        implication_operator,
+        many0(attribute_instance),
+        constant_expression_ternary,
+    )))(s)?;
+    let mut operands = vec![a];
+    let mut operators = Vec::new();
+    for (b, c, d) in b {
+        operators.push((0, b, c));
+        operands.push(d);
+    }
+    reduce_binary(
+        &mut operands,
+        &mut operators,
+        0,
+        &constant_expression_binary_node,
+    );
+    Ok((s, operands.pop().unwrap()))
 }
 
+// `?:` is right associative, so `a ? b : c ? d : e` is folded from the last operand
 #[tracable_parser]
 #[packrat_parser]
-pub(crate) fn constant_expression(s: Span) -> IResult<Span, ConstantExpression> {
+pub(crate) fn constant_expression_ternary(s: Span) -> IResult<Span, ConstantExpression> {
+    let mut heads = Vec::new();
+    let mut s = s;
+    let last = loop {
+        let (t, a) = match constant_expression_binary(s) {
+            Ok(x) => x,
+            // `a ? b :` without the false expression is `a`
+            Err(Err::Error(e)) => match heads.pop() {
+                Some((t, a, _)) => {
+                    s = t;
+                    break a;
+                }
+                None => return Err(Err::Error(e)),
+            },
+            Err(x) => return Err(x),
+        };
+        let head = tuple((
+            symbol("?"),
+            many0(attribute_instance),
+            constant_expression,
+            symbol(":"),
+        ))(t);
+        match head {
+            Ok((u, b)) => {
+                heads.push((t, a, b));
+                s = u;
+            }
+            Err(Err::Error(_)) => {
+                s = t;
+                break a;
+            }
+            Err(x) => return Err(x),
+        }
+    };
+    let ret = heads
+        .into_iter()
+        .rev()
+        .fold(last, |f, (_, a, (b, c, d, e))| {
+            ConstantExpression::Ternary(Box::new(ConstantExpressionTernary {
+                nodes: (a, b, c, d, e, f),
+            }))
+        });
+    Ok((s, ret))
+}
+
+// Binary operators are parsed by precedence climbing with explicit stacks,
+// so a long chain of them doesn't consume the call stack
+#[tracable_parser]
+#[packrat_parser]
+pub(crate) fn constant_expression_binary(s: Span) -> IResult<Span, ConstantExpression> {
+    let (mut s, a) = constant_expression_operand(s)?;
+    let mut operands = vec![a];
+    let mut operators = Vec::new();
+    loop {
+        let next = tuple((
+            binary_operator_precedence,
+            many0(attribute_instance),
+            constant_expression_operand,
+        ))(s);
+        match next {
+            Ok((t, ((p, b), c, d))) => {
+                reduce_binary(
+                    &mut operands,
+                    &mut operators,
+                    p,
+                    &constant_expression_binary_node,
+                );
+                operators.push((p, b, c));
+                operands.push(d);
+                s = t;
+            }
+            Err(Err::Error(_)) => break,
+            Err(x) => return Err(x),
+        }
+    }
+    reduce_binary(
+        &mut operands,
+        &mut operators,
+        0,
+        &constant_expression_binary_node,
+    );
+    Ok((s, operands.pop().unwrap()))
+}
+
+#[tracable_parser]
+#[packrat_parser]
+pub(crate) fn constant_expression_operand(s: Span) -> IResult<Span, ConstantExpression> {
     alt((
-        constant_expression_ternary,
-        constant_expression_binary,
         constant_expression_unary,
         map(constant_primary, |x| {
             ConstantExpression::ConstantPrimary(Box::new(x))
@@ -99,38 +217,65 @@ pub(crate) fn constant_expression_unary(s: Span) -> IResult<Span, ConstantExpres
     ))
 }
 
-#[recursive_parser]
-#[tracable_parser]
-#[packrat_parser]
-pub(crate) fn constant_expression_binary(s: Span) -> IResult<Span, ConstantExpression> {
-    let (s, a) = constant_expression(s)?;
-    let (s, b) = binary_operator(s)?;
-    let (s, c) = many0(attribute_instance)(s)?;
-    let (s, d) = constant_expression(s)?;
-    Ok((
-        s,
-        ConstantExpression::Binary(Box::new(ConstantExpressionBinary {
-            nodes: (a, b, c, d),
-        })),
-    ))
-}
-
-#[recursive_parser]
-#[tracable_parser]
-#[packrat_parser]
-pub(crate) fn constant_expression_ternary(s: Span) -> IResult<Span, ConstantExpression> {
-    let (s, a) = constant_expression(s)?;
-    let (s, b) = symbol("?")(s)?;
-    let (s, c) = many0(attribute_instance)(s)?;
-    let (s, d) = constant_expression(s)?;
-    let (s, e) = symbol(":")(s)?;
-    let (s, f) = constant_expression(s)?;
-    Ok((
-        s,
-        ConstantExpression::Ternary(Box::new(ConstantExpressionTernary {
-            nodes: (a, b, c, d, e, f),
-        })),
-    ))
+fn constant_expression_binary_node(
+    a: ConstantExpression,
+    b: BinaryOperator,
+    c: Vec<AttributeInstance>,
+    d: ConstantExpression,
+) -> ConstantExpression {
+    ConstantExpression::Binary(Box::new(ConstantExpressionBinary {
+        nodes: (a, b, c, d),
+    }))
+}
+
+// Precedence of `inside`, which is the same as relational operators
+const INSIDE_PRECEDENCE: usize = 7;
+
+// Binary operator except `->` and `<->` with its precedence, where a larger one binds tighter
+fn binary_operator_precedence(s: Span) -> IResult<Span, (usize, BinaryOperator)> {
+    // `&&&` of `cond_predicate` isn't `&&`
+    if s.fragment().starts_with("&&&") {
+        return Err(Err::Error(make_error(s, ErrorKind::Tag)));
+    }
+    let (t, a) = binary_operator(s)?;
+    let p = match &s.fragment()[..a.nodes.0.nodes.0.len] {
+        "**" => 11,
+        "*" | "/" | "%" => 10,
+        "+" | "-" => 9,
+        "<<" | ">>" | "<<<" | ">>>" => 8,
+        "<" | "<=" | ">" | ">=" => INSIDE_PRECEDENCE,
+        "==" | "!=" | "===" | "!==" | "==?" | "!=?" => 6,
+        "&" => 5,
+        "^" | "^~" | "~^" => 4,
+        "|" => 3,
+        "&&" => 2,
+        "||" => 1,
+        _ => return Err(Err::Error(make_error(s, ErrorKind::Tag))),
+    };
+    Ok((t, (p, a)))
+}
+
+fn implication_operator(s: Span) -> IResult<Span, BinaryOperator> {
+    let (s, a) = alt((symbol("<->"), symbol("->")))(s)?;
+    Ok((s, BinaryOperator { nodes: (a,) }))
+}
+
+// Build nodes of the operators on the stack whose precedence is `precedence` or higher,
+// so operators of the same precedence are left associative
+fn reduce_binary<T, F>(
+    operands: &mut Vec<T>,
+    operators: &mut Vec<(usize, BinaryOperator, Vec<AttributeInstance>)>,
+    precedence: usize,
+    node: &F,
+) where
+    F: Fn(T, BinaryOperator, Vec<AttributeInstance>, T) -> T,
+{
+    while matches!(operators.last(), Some(x) if x.0 >= precedence) {
+        let (_, b, c) = operators.pop().unwrap();
+        let d = operands.pop().unwrap();
+        let a = operands.pop().unwrap();
+        operands.push(node(a, b, c, d));
+    }
 }
 
 #[tracable_parser]
@@ -232,7 +377,7 @@ pub(crate) fn constant_range(s: Span) -> IResult<Span, ConstantRange> {
 #[packrat_parser]
 pub(crate) fn constant_indexed_range(s: Span) -> IResult<Span, ConstantIndexedRange> {
     let (s, a) = constant_expression(s)?;
-    let (s, b) = alt((symbol("+:"), symbol("-:")))(s)?;
+    let (s, b) = ieee1364_2001(alt((symbol("+:"), symbol("-:"))))(s)?;
     let (s, c) = constant_expression(s)?;
     Ok((s, ConstantIndexedRange { nodes: (a, b, c) }))
 }
@@ -240,17 +385,132 @@ pub(crate) fn constant_indexed_range(s: Span) -> IResult<Span, ConstantIndexedRa
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn expression(s: Span) -> IResult<Span, Expression> {
-    alt((
+    nested(alt((
         map(terminated(primary, peek(one_of(",();"))), |x| {
             Expression::Primary(Box::new(x))
         }),
-        expression_binary,
-        map(conditional_expression, |x| {
-            Expression::ConditionalExpression(Box::new(x))
-        }),
-        map(inside_expression, |x| {
-            Expression::InsideExpression(Box::new(x))
-        }),
+        expression_implication,
+    )))(s)
+}
+
+// `->` and `<->` have the lowest precedence and are right associative
+#[tracable_parser]
+#[packrat_parser]
+pub(crate) fn expression_implication(s: Span) -> IResult<Span, Expression> {
+    let (s, a) = expression_conditional(s)?;
+    let (s, b) = many0(tuple((
+        implication_operator,
+        many0(attribute_instance),
+        expression_conditional,
+    )))(s)?;
+    let mut operands = vec![a];
+    let mut operators = Vec::new();
+    for (b, c, d) in b {
+        operators.push((0, b, c));
+        operands.push(d);
+    }
+    reduce_binary(&mut operands, &mut operators, 0, &expression_binary_node);
+    Ok((s, operands.pop().unwrap()))
+}
+
+// `?:` is right associative, so `a ? b : c ? d : e` is folded from the last operand
+#[tracable_parser]
+#[packrat_parser]
+pub(crate) fn expression_conditional(s: Span) -> IResult<Span, Expression> {
+    let mut heads = Vec::new();
+    let mut s = s;
+    let last = loop {
+        let (t, a) = match expression_binary(s) {
+            Ok(x) => x,
+            // `a ? b :` without the false expression is `a`
+            Err(Err::Error(e)) => match heads.pop() {
+                Some((t, a, _)) => {
+                    s = t;
+                    break a;
+                }
+                None => return Err(Err::Error(e)),
+            },
+            Err(x) => return Err(x),
+        };
+        match conditional_expression_head(t) {
+            Ok((u, b)) => {
+                heads.push((t, a, b));
+                s = u;
+            }
+            Err(Err::Error(_)) => {
+                s = t;
+                break a;
+            }
+            Err(x) => return Err(x),
+        }
+    };
+    let ret = heads
+        .into_iter()
+        .rev()
+        .fold(last, |g, (_, a, (b, c, d, e, f, h))| {
+            let predicate = CondPredicate {
+                nodes: (List {
+                    nodes: (expression_or_cond_pattern(a, b), c),
+                },),
+            };
+            Expression::ConditionalExpression(Box::new(ConditionalExpression {
+                nodes: (predicate, d, e, f, h, g),
+            }))
+        });
+    Ok((s, ret))
+}
+
+// Binary operators are parsed by precedence climbing with explicit stacks,
+// so a long chain of them doesn't consume the call stack
+#[tracable_parser]
+#[packrat_parser]
+pub(crate) fn expression_binary(s: Span) -> IResult<Span, Expression> {
+    let (mut s, a) = expression_operand(s)?;
+    let mut operands = vec![a];
+    let mut operators = Vec::new();
+    loop {
+        match pair(keyword("inside"), brace(open_range_list))(s) {
+            Ok((t, (b, c))) => {
+                reduce_binary(
+                    &mut operands,
+                    &mut operators,
+                    INSIDE_PRECEDENCE,
+                    &expression_binary_node,
+                );
+                let a = operands.pop().unwrap();
+                operands.push(Expression::InsideExpression(Box::new(InsideExpression {
+                    nodes: (a, b, c),
+                })));
+                s = t;
+                continue;
+            }
+            Err(Err::Error(_)) => (),
+            Err(x) => return Err(x),
+        }
+        let next = tuple((
+            binary_operator_precedence,
+            many0(attribute_instance),
+            expression_operand,
+        ))(s);
+        match next {
+            Ok((t, ((p, b), c, d))) => {
+                reduce_binary(&mut operands, &mut operators, p, &expression_binary_node);
+                operators.push((p, b, c));
+                operands.push(d);
+                s = t;
+            }
+            Err(Err::Error(_)) => break,
+            Err(x) => return Err(x),
+        }
+    }
+    reduce_binary(&mut operands, &mut operators, 0, &expression_binary_node);
+    Ok((s, operands.pop().unwrap()))
+}
+
+#[tracable_parser]
+#[packrat_parser]
+pub(crate) fn expression_operand(s: Span) -> IResult<Span, Expression> {
+    alt((
         expression_unary,
         map(inc_or_dec_expression, |x| {
             Expression::IncOrDecExpression(Box::new(x))
@@ -278,27 +538,22 @@ pub(crate) fn expression_unary(s: Span) -> IResult<Span, Expression> {
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn expression_operator_assignment(s: Span) -> IResult<Span, Expression> {
-    let (s, a) = paren(operator_assignment)(s)?;
+    let (s, a) = ieee1800(paren(operator_assignment))(s)?;
     Ok((
         s,
         Expression::OperatorAssignment(Box::new(ExpressionOperatorAssignment { nodes: (a,) })),
     ))
 }
 
-#[recursive_parser]
-#[tracable_parser]
-#[packrat_parser]
-pub(crate) fn expression_binary(s: Span) -> IResult<Span, Expression> {
-    let (s, a) = expression(s)?;
-    let (s, b) = binary_operator(s)?;
-    let (s, c) = many0(attribute_instance)(s)?;
-    let (s, d) = expression(s)?;
-    Ok((
-        s,
-        Expression::Binary(Box::new(ExpressionBinary {
-            nodes: (a, b, c, d),
-        })),
-    ))
+fn expression_binary_node(
+    a: Expression,
+    b: BinaryOperator,
+    c: Vec<AttributeInstance>,
+    d: Expression,
+) -> Expression {
+    Expression::Binary(Box::new(ExpressionBinary {
+        nodes: (a, b, c, d),
+    }))
 }
 
 #[tracable_parser]
@@ -310,16 +565,6 @@ pub(crate) fn tagged_union_expression(s: Span) -> IResult<Span, TaggedUnionExpre
     Ok((s, TaggedUnionExpression { nodes: (a, b, c) }))
 }
 
-#[recursive_parser]
-#[tracable_parser]
-#[packrat_parser]
-pub(crate) fn inside_expression(s: Span) -> IResult<Span, InsideExpression> {
-    let (s, a) = expression(s)?;
-    let (s, b) = keyword("inside")(s)?;
-    let (s, c) = brace(open_range_list)(s)?;
-    Ok((s, InsideExpression { nodes: (a, b, c) }))
-}
-
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn value_range(s: Span) -> IResult<Span, ValueRange> {
@@ -478,7 +723,7 @@ pub(crate) fn part_select_range(s: Span) -> IResult<Span, PartSelectRange> {
 #[packrat_parser]
 pub(crate) fn indexed_range(s: Span) -> IResult<Span, IndexedRange> {
     let (s, a) = expression(s)?;
-    let (s, b) = alt((symbol("+:"), symbol("-:")))(s)?;
+    let (s, b) = ieee1364_2001(alt((symbol("+:"), symbol("-:"))))(s)?;
     let (s, c) = constant_expression(s)?;
     Ok((s, IndexedRange { nodes: (a, b, c) }))
 }
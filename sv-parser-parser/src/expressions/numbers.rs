@@ -299,7 +299,7 @@ pub(crate) fn decimal_base(s: Span) -> IResult<Span, DecimalBase> {
 
 #[tracable_parser]
 pub(crate) fn decimal_base_impl(s: Span) -> IResult<Span, Locate> {
-    let (s, a) = alt((tag_no_case("'d"), tag_no_case("'sd")))(s)?;
+    let (s, a) = alt((tag_no_case("'d"), ieee1364_2001(tag_no_case("'sd"))))(s)?;
     Ok((s, into_locate(a)))
 }
 
@@ -312,7 +312,7 @@ pub(crate) fn binary_base(s: Span) -> IResult<Span, BinaryBase> {
 
 #[tracable_parser]
 pub(crate) fn binary_base_impl(s: Span) -> IResult<Span, Locate> {
-    let (s, a) = alt((tag_no_case("'b"), tag_no_case("'sb")))(s)?;
+    let (s, a) = alt((tag_no_case("'b"), ieee1364_2001(tag_no_case("'sb"))))(s)?;
     Ok((s, into_locate(a)))
 }
 
@@ -325,7 +325,7 @@ pub(crate) fn octal_base(s: Span) -> IResult<Span, OctalBase> {
 
 #[tracable_parser]
 pub(crate) fn octal_base_impl(s: Span) -> IResult<Span, Locate> {
-    let (s, a) = alt((tag_no_case("'o"), tag_no_case("'so")))(s)?;
+    let (s, a) = alt((tag_no_case("'o"), ieee1364_2001(tag_no_case("'so"))))(s)?;
     Ok((s, into_locate(a)))
 }
 
@@ -338,7 +338,7 @@ pub(crate) fn hex_base(s: Span) -> IResult<Span, HexBase> {
 
 #[tracable_parser]
 pub(crate) fn hex_base_impl(s: Span) -> IResult<Span, Locate> {
-    let (s, a) = alt((tag_no_case("'h"), tag_no_case("'sh")))(s)?;
+    let (s, a) = alt((tag_no_case("'h"), ieee1364_2001(tag_no_case("'sh"))))(s)?;
     Ok((s, into_locate(a)))
 }
 
@@ -377,13 +377,13 @@ pub(crate) fn z_number_impl(s: Span) -> IResult<Span, Locate> {
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn unbased_unsized_literal(s: Span) -> IResult<Span, UnbasedUnsizedLiteral> {
-    let (s, a) = alt((
+    let (s, a) = ieee1800(alt((
         symbol("'0"),
         symbol("'1"),
         symbol("'z"),
         symbol("'x"),
         symbol("'Z"),
         symbol("'X"),
-    ))(s)?;
+    )))(s)?;
     Ok((s, UnbasedUnsizedLiteral { nodes: (a,) }))
 }
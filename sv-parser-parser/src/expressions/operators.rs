@@ -29,15 +29,15 @@ pub(crate) fn binary_operator(s: Span) -> IResult<Span, BinaryOperator> {
             symbol("+"),
             symbol("->"),
             symbol("-"),
-            symbol("**"),
+            ieee1364_2001(symbol("**")),
             symbol("*"),
             symbol("/"),
             symbol("%"),
             symbol("==="),
-            symbol("==?"),
+            ieee1800(symbol("==?")),
             symbol("=="),
             symbol("!=="),
-            symbol("!=?"),
+            ieee1800(symbol("!=?")),
             symbol("!="),
             symbol("&&"),
             symbol("||"),
@@ -48,9 +48,9 @@ pub(crate) fn binary_operator(s: Span) -> IResult<Span, BinaryOperator> {
             symbol("^~"),
             symbol("^"),
             symbol("~^"),
-            symbol(">>>"),
+            ieee1364_2001(symbol(">>>")),
             symbol(">>"),
-            symbol("<<<"),
+            ieee1364_2001(symbol("<<<")),
             symbol("<<"),
             symbol("<->"),
             symbol("<="),
@@ -65,7 +65,7 @@ pub(crate) fn binary_operator(s: Span) -> IResult<Span, BinaryOperator> {
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn inc_or_dec_operator(s: Span) -> IResult<Span, IncOrDecOperator> {
-    let (s, a) = alt((symbol("++"), symbol("--")))(s)?;
+    let (s, a) = ieee1800(alt((symbol("++"), symbol("--"))))(s)?;
     Ok((s, IncOrDecOperator { nodes: (a,) }))
 }
 
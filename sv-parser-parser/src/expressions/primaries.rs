@@ -323,7 +323,7 @@ pub(crate) fn primary_literal(s: Span) -> IResult<Span, PrimaryLiteral> {
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn time_literal(s: Span) -> IResult<Span, TimeLiteral> {
-    alt((time_literal_unsigned, time_literal_fixed_point))(s)
+    ieee1800(alt((time_literal_unsigned, time_literal_fixed_point)))(s)
 }
 
 #[tracable_parser]
@@ -455,7 +455,7 @@ pub(crate) fn constant_let_expression(s: Span) -> IResult<Span, ConstantLetExpre
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn cast(s: Span) -> IResult<Span, Cast> {
-    let (s, a) = casting_type(s)?;
+    let (s, a) = ieee1800(casting_type)(s)?;
     let (s, b) = symbol("'")(s)?;
     let (s, c) = paren(expression)(s)?;
     Ok((s, Cast { nodes: (a, b, c) }))
@@ -5,7 +5,7 @@ use crate::*;
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn attribute_instance(s: Span) -> IResult<Span, AttributeInstance> {
-    let (s, a) = symbol("(*")(s)?;
+    let (s, a) = ieee1364_2001(symbol("(*"))(s)?;
     let (s, b) = list(symbol(","), attr_spec)(s)?;
     let (s, c) = symbol("*)")(s)?;
     Ok((s, AttributeInstance { nodes: (a, b, c) }))
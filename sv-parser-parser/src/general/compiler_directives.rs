@@ -5,7 +5,7 @@ use crate::*;
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn compiler_directive(s: Span) -> IResult<Span, CompilerDirective> {
-    begin_directive();
+    begin_directive(&s);
     let ret = alt((
         map(resetall_compiler_directive, |x| {
             CompilerDirective::ResetallCompilerDirective(Box::new(x))
@@ -60,14 +60,14 @@ pub(crate) fn compiler_directive(s: Span) -> IResult<Span, CompilerDirective> {
             CompilerDirective::TextMacroUsage(Box::new(x))
         }),
     ))(s);
-    end_directive();
+    end_directive(&s);
     ret
 }
 
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn compiler_directive_without_resetall(s: Span) -> IResult<Span, CompilerDirective> {
-    begin_directive();
+    begin_directive(&s);
     let ret = alt((
         map(include_compiler_directive, |x| {
             CompilerDirective::IncludeCompilerDirective(Box::new(x))
@@ -119,7 +119,7 @@ pub(crate) fn compiler_directive_without_resetall(s: Span) -> IResult<Span, Comp
             CompilerDirective::TextMacroUsage(Box::new(x))
         }),
     ))(s);
-    end_directive();
+    end_directive(&s);
     ret
 }
 
@@ -213,9 +213,10 @@ pub(crate) fn angle_bracket_literal_impl(s: Span) -> IResult<Span, Locate> {
 pub(crate) fn text_macro_definition(s: Span) -> IResult<Span, TextMacroDefinition> {
     let (s, a) = symbol("`")(s)?;
     let (s, b) = keyword("define")(s)?;
-    begin_keywords("directive");
-    let (s, c) = text_macro_name(s)?;
-    end_keywords();
+    begin_keywords(&s, "directive");
+    let c = text_macro_name(s);
+    end_keywords(&s);
+    let (s, c) = c?;
     let (s, d) = opt(macro_text)(s)?;
     Ok((
         s,
@@ -293,7 +294,8 @@ pub(crate) fn macro_text(s: Span) -> IResult<Span, MacroText> {
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn default_text(s: Span) -> IResult<Span, DefaultText> {
-    let (s, a) = define_argument(s)?;
+    // Default text can be empty like `define MACRO(a=)
+    let (s, a) = alt((define_argument, take(0usize)))(s)?;
     Ok((
         s,
         DefaultText {
@@ -306,9 +308,10 @@ pub(crate) fn default_text(s: Span) -> IResult<Span, DefaultText> {
 #[packrat_parser]
 pub(crate) fn text_macro_usage(s: Span) -> IResult<Span, TextMacroUsage> {
     let (s, a) = symbol("`")(s)?;
-    begin_keywords("directive");
-    let (s, b) = text_macro_identifier(s)?;
-    end_keywords();
+    begin_keywords(&s, "directive");
+    let b = text_macro_identifier(s);
+    end_keywords(&s);
+    let (s, b) = b?;
     let (s, c) = opt(paren(list_of_actual_arguments))(s)?;
     Ok((s, TextMacroUsage { nodes: (a, b, c) }))
 }
@@ -460,12 +463,12 @@ pub(crate) fn conditional_compiler_directive(
 pub(crate) fn ifdef_directive(s: Span) -> IResult<Span, IfdefDirective> {
     let (s, a) = symbol("`")(s)?;
     let (s, b) = keyword("ifdef")(s)?;
-    let (s, c) = text_macro_identifier(s)?;
+    let (s, c) = ifdef_condition(s)?;
     let (s, d) = ifdef_group_of_lines(s)?;
     let (s, e) = many0(tuple((
         symbol("`"),
         keyword("elsif"),
-        text_macro_identifier,
+        ifdef_condition,
         elsif_group_of_lines,
     )))(s)?;
     let (s, f) = opt(tuple((symbol("`"), keyword("else"), else_group_of_lines)))(s)?;
@@ -484,12 +487,12 @@ pub(crate) fn ifdef_directive(s: Span) -> IResult<Span, IfdefDirective> {
 pub(crate) fn ifndef_directive(s: Span) -> IResult<Span, IfndefDirective> {
     let (s, a) = symbol("`")(s)?;
     let (s, b) = keyword("ifndef")(s)?;
-    let (s, c) = text_macro_identifier(s)?;
+    let (s, c) = ifdef_condition(s)?;
     let (s, d) = ifndef_group_of_lines(s)?;
     let (s, e) = many0(tuple((
         symbol("`"),
         keyword("elsif"),
-        text_macro_identifier,
+        ifdef_condition,
         elsif_group_of_lines,
     )))(s)?;
     let (s, f) = opt(tuple((symbol("`"), keyword("else"), else_group_of_lines)))(s)?;
@@ -503,6 +506,114 @@ pub(crate) fn ifndef_directive(s: Span) -> IResult<Span, IfndefDirective> {
     ))
 }
 
+#[tracable_parser]
+#[packrat_parser]
+pub(crate) fn ifdef_condition(s: Span) -> IResult<Span, IfdefCondition> {
+    alt((
+        map(
+            preceded(peek(alt((char('('), char('!')))), ifdef_macro_expression),
+            |x| IfdefCondition::IfdefMacroExpression(Box::new(x)),
+        ),
+        map(text_macro_identifier, |x| {
+            IfdefCondition::TextMacroIdentifier(Box::new(x))
+        }),
+    ))(s)
+}
+
+// `->` and `<->` are right associative and have the lowest precedence, then `||`, `&&` and `!`.
+#[tracable_parser]
+#[packrat_parser]
+pub(crate) fn ifdef_macro_expression(s: Span) -> IResult<Span, IfdefMacroExpression> {
+    let (s, a) = ifdef_macro_expression_or(s)?;
+    let (s, b) = opt(pair(
+        alt((
+            binary_logical_operator("<->"),
+            binary_logical_operator("->"),
+        )),
+        ifdef_macro_expression,
+    ))(s)?;
+    let ret = match b {
+        Some((b, c)) => {
+            IfdefMacroExpression::Binary(Box::new(IfdefMacroExpressionBinary { nodes: (a, b, c) }))
+        }
+        None => a,
+    };
+    Ok((s, ret))
+}
+
+#[tracable_parser]
+#[packrat_parser]
+pub(crate) fn ifdef_macro_expression_or(s: Span) -> IResult<Span, IfdefMacroExpression> {
+    let (s, a) = ifdef_macro_expression_and(s)?;
+    let (s, b) = many0(pair(
+        binary_logical_operator("||"),
+        ifdef_macro_expression_and,
+    ))(s)?;
+    Ok((s, fold_ifdef_macro_expression(a, b)))
+}
+
+#[tracable_parser]
+#[packrat_parser]
+pub(crate) fn ifdef_macro_expression_and(s: Span) -> IResult<Span, IfdefMacroExpression> {
+    let (s, a) = ifdef_macro_expression_primary(s)?;
+    let (s, b) = many0(pair(
+        binary_logical_operator("&&"),
+        ifdef_macro_expression_primary,
+    ))(s)?;
+    Ok((s, fold_ifdef_macro_expression(a, b)))
+}
+
+#[tracable_parser]
+#[packrat_parser]
+pub(crate) fn ifdef_macro_expression_primary(s: Span) -> IResult<Span, IfdefMacroExpression> {
+    alt((
+        map(text_macro_identifier, |x| {
+            IfdefMacroExpression::TextMacroIdentifier(Box::new(x))
+        }),
+        ifdef_macro_expression_paren,
+        ifdef_macro_expression_not,
+    ))(s)
+}
+
+#[tracable_parser]
+#[packrat_parser]
+pub(crate) fn ifdef_macro_expression_paren(s: Span) -> IResult<Span, IfdefMacroExpression> {
+    let (s, a) = paren(ifdef_macro_expression)(s)?;
+    Ok((
+        s,
+        IfdefMacroExpression::Paren(Box::new(IfdefMacroExpressionParen { nodes: (a,) })),
+    ))
+}
+
+#[tracable_parser]
+#[packrat_parser]
+pub(crate) fn ifdef_macro_expression_not(s: Span) -> IResult<Span, IfdefMacroExpression> {
+    let (s, a) = symbol("!")(s)?;
+    let (s, b) = ifdef_macro_expression_primary(s)?;
+    Ok((
+        s,
+        IfdefMacroExpression::Not(Box::new(IfdefMacroExpressionNot { nodes: (a, b) })),
+    ))
+}
+
+pub(crate) fn binary_logical_operator<'a>(
+    t: &'static str,
+) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, BinaryLogicalOperator> {
+    move |s: Span<'a>| {
+        let (s, a) = symbol(t)(s)?;
+        Ok((s, BinaryLogicalOperator { nodes: (a,) }))
+    }
+}
+
+fn fold_ifdef_macro_expression(
+    a: IfdefMacroExpression,
+    b: Vec<(BinaryLogicalOperator, IfdefMacroExpression)>,
+) -> IfdefMacroExpression {
+    b.into_iter().fold(a, |a, (b, c)| {
+        IfdefMacroExpression::Binary(Box::new(IfdefMacroExpressionBinary { nodes: (a, b, c) }))
+    })
+}
+
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn ifdef_group_of_lines(s: Span) -> IResult<Span, IfdefGroupOfLines> {
@@ -830,35 +941,35 @@ pub(crate) fn keywords_directive(s: Span) -> IResult<Span, KeywordsDirective> {
 pub(crate) fn version_specifier(s: Span) -> IResult<Span, VersionSpecifier> {
     let (s, a) = alt((
         map(keyword("1800-2017"), |x| {
-            begin_keywords("1800-2017");
+            begin_keywords(&s, "1800-2017");
             x
         }),
         map(keyword("1800-2012"), |x| {
-            begin_keywords("1800-2012");
+            begin_keywords(&s, "1800-2012");
             x
         }),
         map(keyword("1800-2009"), |x| {
-            begin_keywords("1800-2009");
+            begin_keywords(&s, "1800-2009");
             x
         }),
         map(keyword("1800-2005"), |x| {
-            begin_keywords("1800-2005");
+            begin_keywords(&s, "1800-2005");
             x
         }),
         map(keyword("1364-2005"), |x| {
-            begin_keywords("1364-2005");
+            begin_keywords(&s, "1364-2005");
             x
         }),
         map(keyword("1364-2001-noconfig"), |x| {
-            begin_keywords("1364-2001-noconfig");
+            begin_keywords(&s, "1364-2001-noconfig");
             x
         }),
         map(keyword("1364-2001"), |x| {
-            begin_keywords("1364-2001");
+            begin_keywords(&s, "1364-2001");
             x
         }),
         map(keyword("1364-1995"), |x| {
-            begin_keywords("1364-1995");
+            begin_keywords(&s, "1364-1995");
             x
         }),
     ))(s)?;
@@ -870,6 +981,6 @@ pub(crate) fn version_specifier(s: Span) -> IResult<Span, VersionSpecifier> {
 pub(crate) fn endkeywords_directive(s: Span) -> IResult<Span, EndkeywordsDirective> {
     let (s, a) = symbol("`")(s)?;
     let (s, b) = keyword("end_keywords")(s)?;
-    end_keywords();
+    end_keywords(&s);
     Ok((s, EndkeywordsDirective { nodes: (a, b) }))
 }
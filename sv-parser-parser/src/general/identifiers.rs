@@ -280,14 +280,17 @@ pub(crate) fn hierarchical_variable_identifier(
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn identifier(s: Span) -> IResult<Span, Identifier> {
-    alt((
-        map(escaped_identifier, |x| {
-            Identifier::EscapedIdentifier(Box::new(x))
-        }),
-        map(simple_identifier, |x| {
-            Identifier::SimpleIdentifier(Box::new(x))
-        }),
-    ))(s)
+    expect(
+        "identifier",
+        alt((
+            map(escaped_identifier, |x| {
+                Identifier::EscapedIdentifier(Box::new(x))
+            }),
+            map(simple_identifier, |x| {
+                Identifier::SimpleIdentifier(Box::new(x))
+            }),
+        )),
+    )(s)
 }
 
 #[tracable_parser]
@@ -396,10 +399,10 @@ pub(crate) fn package_identifier(s: Span) -> IResult<Span, PackageIdentifier> {
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn package_scope(s: Span) -> IResult<Span, PackageScope> {
-    alt((
+    ieee1800(alt((
         package_scope_package,
         map(unit, |x| PackageScope::Unit(Box::new(x))),
-    ))(s)
+    )))(s)
 }
 
 #[tracable_parser]
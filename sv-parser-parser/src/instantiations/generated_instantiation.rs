@@ -163,10 +163,10 @@ pub(crate) fn case_generate_item_default(s: Span) -> IResult<Span, CaseGenerateI
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn generate_block(s: Span) -> IResult<Span, GenerateBlock> {
-    alt((
+    nested(alt((
         map(generate_item, |x| GenerateBlock::GenerateItem(Box::new(x))),
         generate_block_multiple,
-    ))(s)
+    )))(s)
 }
 
 #[tracable_parser]
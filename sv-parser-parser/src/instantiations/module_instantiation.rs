@@ -160,7 +160,7 @@ pub(crate) fn named_port_connection_identifier(s: Span) -> IResult<Span, NamedPo
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn named_port_connection_asterisk(s: Span) -> IResult<Span, NamedPortConnection> {
-    let (s, (a, b)) = many_till(attribute_instance, symbol(".*"))(s)?;
+    let (s, (a, b)) = many_till(attribute_instance, ieee1800(symbol(".*")))(s)?;
     Ok((
         s,
         NamedPortConnection::Asterisk(Box::new(NamedPortConnectionAsterisk { nodes: (a, b) })),
@@ -37,27 +37,253 @@ pub(crate) use nom::error::{context, make_error, ErrorKind};
 pub(crate) use nom::multi::*;
 pub(crate) use nom::sequence::*;
 pub(crate) use nom::Err;
-pub(crate) use nom_greedyerror::GreedyError;
-pub(crate) use nom_packrat::{self, packrat_parser, HasExtraState};
+pub(crate) use nom_greedyerror::{error_position, GreedyError, GreedyErrorKind};
+pub(crate) use nom_packrat::{self, HasExtraState, PackratStorage};
 pub(crate) use nom_recursive::{recursive_parser, HasRecursiveInfo, RecursiveInfo};
 pub(crate) use nom_tracable::tracable_parser;
 #[cfg(feature = "trace")]
 pub(crate) use nom_tracable::{HasTracableInfo, TracableInfo};
+pub(crate) use sv_parser_macros::packrat_parser;
 pub(crate) use sv_parser_syntaxtree::*;
 
+use std::cell::{Cell, RefCell};
+use std::fmt;
+
 // -----------------------------------------------------------------------------
 
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
-pub struct SpanInfo {
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpanInfo<'a> {
     #[cfg(feature = "trace")]
     pub tracable_info: TracableInfo,
     pub recursive_info: RecursiveInfo,
+    pub context: &'a ParserContext,
+}
+
+impl<'a> SpanInfo<'a> {
+    pub fn new(context: &'a ParserContext) -> Self {
+        SpanInfo {
+            #[cfg(feature = "trace")]
+            tracable_info: TracableInfo::default(),
+            recursive_info: RecursiveInfo::default(),
+            context,
+        }
+    }
+}
+
+/// Options of the parser
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ParserOptions {
+    /// Maximum nesting depth, which is unlimited by default.
+    /// Each nested expression, statement, generate block, struct or union type and class item
+    /// is a level.
+    /// A deeper construct fails the parse, and its position is got by
+    /// `ParserContext::exceeded_depth`.
+    /// The parser allocates its stack on demand, but dropping and cloning the tree
+    /// use the stack in proportion to the depth.
+    pub max_depth: usize,
+    /// Version of the language outside of `` `begin_keywords `` blocks.
+    /// If `None`, IEEE 1800-2017 is used.
+    /// Constructs of IEEE 1800 fail only if this is a version of IEEE 1364,
+    /// and the main additions of IEEE 1364-2001 fail in IEEE 1364-1995 (see `Version`);
+    /// `` `begin_keywords `` changes the reserved keywords only.
+    pub version: Option<Version>,
+}
+
+impl core::default::Default for ParserOptions {
+    fn default() -> Self {
+        ParserOptions {
+            max_depth: usize::MAX,
+            version: None,
+        }
+    }
+}
+
+/// Version of the language specified by `` `begin_keywords ``
+///
+/// The grammar tells IEEE 1364 from IEEE 1800, and IEEE 1364-1995 from the later versions.
+/// In IEEE 1364-1995, ANSI port lists, parameter port lists, `**`, `<<<`, `>>>`, `+:`, `-:`,
+/// signed based numbers, attributes, `@*`, `,` in event lists and generate constructs fail,
+/// and keywords like `signed`, `localparam` and `genvar` are not reserved.
+/// Other differences, like variable initializers, multi-dimensional arrays and ANSI ports of
+/// tasks, are not checked, nor are the differences between IEEE 1364-2001 and IEEE 1364-2005.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Version {
+    Ieee1364_1995,
+    Ieee1364_2001,
+    Ieee1364_2001Noconfig,
+    Ieee1364_2005,
+    Ieee1800_2005,
+    Ieee1800_2009,
+    Ieee1800_2012,
+    Ieee1800_2017,
+    Directive,
+}
+
+impl Version {
+    /// Get the version from a version specifier like `"1800-2017"`
+    pub fn from_specifier(s: &str) -> Option<Version> {
+        match s {
+            "1364-1995" => Some(Version::Ieee1364_1995),
+            "1364-2001" => Some(Version::Ieee1364_2001),
+            "1364-2001-noconfig" => Some(Version::Ieee1364_2001Noconfig),
+            "1364-2005" => Some(Version::Ieee1364_2005),
+            "1800-2005" => Some(Version::Ieee1800_2005),
+            "1800-2009" => Some(Version::Ieee1800_2009),
+            "1800-2012" => Some(Version::Ieee1800_2012),
+            "1800-2017" => Some(Version::Ieee1800_2017),
+            "directive" => Some(Version::Directive),
+            _ => None,
+        }
+    }
+}
+
+/// Mutable state of a parse
+///
+/// Every parse started by the entry functions like `sv_parser` resets the context of its input,
+/// so a context can be reused for sequential parses. Parses running at the same time need
+/// separate contexts.
+pub struct ParserContext {
+    pub(crate) options: ParserOptions,
+    pub(crate) in_directive: Cell<usize>,
+    pub(crate) version: RefCell<Vec<Version>>,
+    pub(crate) expected_position: Cell<usize>,
+    pub(crate) expected: RefCell<Vec<&'static str>>,
+    pub(crate) recover: Cell<bool>,
+    pub(crate) recovered: RefCell<Vec<RecoveredError>>,
+    pub(crate) close_at_eof: Cell<bool>,
+    pub(crate) packrat: RefCell<PackratStorage<Memo, bool>>,
+    pub(crate) depth: Cell<usize>,
+    pub(crate) reached: Cell<usize>,
+    pub(crate) exceeded_depth: Cell<Option<usize>>,
 }
 
-pub type Span<'a> = nom_locate::LocatedSpan<&'a str, SpanInfo>;
+impl ParserContext {
+    pub fn new() -> Self {
+        Self::with_options(ParserOptions::default())
+    }
+
+    pub fn with_options(options: ParserOptions) -> Self {
+        ParserContext {
+            options,
+            in_directive: Cell::new(0),
+            version: RefCell::new(Vec::new()),
+            expected_position: Cell::new(0),
+            expected: RefCell::new(Vec::new()),
+            recover: Cell::new(false),
+            recovered: RefCell::new(Vec::new()),
+            close_at_eof: Cell::new(false),
+            packrat: RefCell::new(PackratStorage::new(Some(1024))),
+            depth: Cell::new(0),
+            reached: Cell::new(0),
+            exceeded_depth: Cell::new(None),
+        }
+    }
+
+    /// Get the position where the last parse exceeded `ParserOptions::max_depth`
+    pub fn exceeded_depth(&self) -> Option<usize> {
+        self.exceeded_depth.get()
+    }
+
+    /// Get the furthest position where the last parse failed to match a token,
+    /// and the tokens which would have been accepted there
+    pub fn expected_tokens(&self) -> (usize, Vec<&'static str>) {
+        (self.expected_position.get(), self.expected.borrow().clone())
+    }
+
+    /// Get the errors skipped by the last `sv_parser_recover`
+    pub fn recovered_errors(&self) -> Vec<RecoveredError> {
+        self.recovered.replace(Vec::new())
+    }
+
+    fn clear(&self) {
+        self.in_directive.set(0);
+        *self.version.borrow_mut() = self.options.version.into_iter().collect();
+        self.expected_position.set(0);
+        self.expected.borrow_mut().clear();
+        self.recover.set(false);
+        self.recovered.borrow_mut().clear();
+        self.close_at_eof.set(false);
+        self.packrat.borrow_mut().clear();
+        self.depth.set(0);
+        self.reached.set(0);
+        self.exceeded_depth.set(None);
+    }
+}
+
+impl core::default::Default for ParserContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for ParserContext {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ParserContext")
+            .field("options", &self.options)
+            .field("in_directive", &self.in_directive)
+            .field("version", &self.version)
+            .field("expected_position", &self.expected_position)
+            .field("expected", &self.expected)
+            .field("recover", &self.recover)
+            .field("recovered", &self.recovered)
+            .field("close_at_eof", &self.close_at_eof)
+            .field("depth", &self.depth)
+            .field("reached", &self.reached)
+            .field("exceeded_depth", &self.exceeded_depth)
+            .finish()
+    }
+}
+
+// Success of a parser memoized by `#[packrat_parser]`
+#[derive(Debug)]
+pub(crate) struct Memo {
+    // It is taken only while the memo is dropped
+    node: Option<AnyNode>,
+    // Nesting depth reached by the node, which is counted from the depth at the position
+    pub(crate) height: usize,
+}
+
+// Cloning and dropping a node recurse over its depth, so they run on a stack for its height
+impl Memo {
+    pub(crate) fn new<T: Clone + Into<AnyNode>>(node: &T, height: usize) -> Self {
+        let node = grow_for_height(height, || node.clone().into());
+        Memo {
+            node: Some(node),
+            height,
+        }
+    }
+
+    pub(crate) fn node(&self) -> AnyNode {
+        grow_for_height(self.height, || self.node.clone().unwrap())
+    }
+}
+
+impl Drop for Memo {
+    fn drop(&mut self) {
+        let node = self.node.take();
+        grow_for_height(self.height, || drop(node));
+    }
+}
+
+impl PartialEq for ParserContext {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+/// Parse error which was skipped by the error-recovering parser
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecoveredError {
+    pub position: usize,
+    pub context: Vec<String>,
+    pub expected: Vec<&'static str>,
+}
+
+pub type Span<'a> = nom_locate::LocatedSpan<&'a str, SpanInfo<'a>>;
 pub type IResult<T, U> = nom::IResult<T, U, GreedyError<T, ErrorKind>>;
 
-impl HasRecursiveInfo for SpanInfo {
+impl<'a> HasRecursiveInfo for SpanInfo<'a> {
     fn get_recursive_info(&self) -> RecursiveInfo {
         self.recursive_info
     }
@@ -69,7 +295,7 @@ impl HasRecursiveInfo for SpanInfo {
 }
 
 #[cfg(feature = "trace")]
-impl HasTracableInfo for SpanInfo {
+impl<'a> HasTracableInfo for SpanInfo<'a> {
     fn get_tracable_info(&self) -> TracableInfo {
         self.tracable_info
     }
@@ -80,43 +306,77 @@ impl HasTracableInfo for SpanInfo {
     }
 }
 
-impl HasExtraState<bool> for SpanInfo {
+impl<'a> HasExtraState<bool> for SpanInfo<'a> {
     fn get_extra_state(&self) -> bool {
-        in_directive()
+        self.context.in_directive.get() > 0
     }
 }
 
 // -----------------------------------------------------------------------------
 
-nom_packrat::storage!(AnyNode, bool, 1024);
-
 pub fn sv_parser(s: Span) -> IResult<Span, SourceText> {
-    init();
+    s.extra.context.clear();
     source_text(s)
 }
 
 pub fn sv_parser_incomplete(s: Span) -> IResult<Span, SourceText> {
-    init();
+    s.extra.context.clear();
     source_text_incomplete(s)
 }
 
+pub fn sv_parser_recover(s: Span) -> IResult<Span, SourceText> {
+    s.extra.context.clear();
+    s.extra.context.recover.set(true);
+    source_text_recover(s)
+}
+
 pub fn lib_parser(s: Span) -> IResult<Span, LibraryText> {
-    init();
+    s.extra.context.clear();
     library_text(s)
 }
 
 pub fn lib_parser_incomplete(s: Span) -> IResult<Span, LibraryText> {
-    init();
+    s.extra.context.clear();
     library_text_incomplete(s)
 }
 
 pub fn pp_parser(s: Span) -> IResult<Span, PreprocessorText> {
-    init();
+    s.extra.context.clear();
     preprocessor_text(s)
 }
 
-fn init() {
-    nom_packrat::init!();
-    clear_directive();
-    clear_version();
+/// Parse a `description` of a source text, to reparse a part of it
+pub fn description_parser(s: Span) -> IResult<Span, Description> {
+    s.extra.context.clear();
+    description(s)
+}
+
+/// Parse a `module_item` of a module declaration without ANSI style ports
+pub fn module_item_parser(s: Span) -> IResult<Span, ModuleItem> {
+    s.extra.context.clear();
+    module_item(s)
+}
+
+/// Parse a `non_port_module_item` of a module declaration with ANSI style ports
+pub fn non_port_module_item_parser(s: Span) -> IResult<Span, NonPortModuleItem> {
+    s.extra.context.clear();
+    non_port_module_item(s)
+}
+
+/// Parse a `package_item` with its attributes in a package declaration
+pub fn package_item_parser(s: Span) -> IResult<Span, (Vec<AttributeInstance>, PackageItem)> {
+    s.extra.context.clear();
+    pair(many0(attribute_instance), package_item)(s)
+}
+
+/// Get the error kinds and contexts of the specified error from the innermost parser
+pub fn error_context(e: &GreedyError<Span, ErrorKind>) -> Vec<String> {
+    e.errors
+        .iter()
+        .map(|(_, kind)| match kind {
+            GreedyErrorKind::Context(x) => String::from(*x),
+            GreedyErrorKind::Char(x) => format!("'{}'", x),
+            GreedyErrorKind::Nom(x) => String::from(x.description()),
+        })
+        .collect()
 }
@@ -5,7 +5,7 @@ use crate::*;
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn class_item(s: Span) -> IResult<Span, ClassItem> {
-    alt((
+    nested(alt((
         class_item_property,
         class_item_method,
         class_item_constraint,
@@ -18,7 +18,7 @@ pub(crate) fn class_item(s: Span) -> IResult<Span, ClassItem> {
             ClassItem::ParameterDeclaration(Box::new(x))
         }),
         map(symbol(";"), |x| ClassItem::Empty(Box::new(x))),
-    ))(s)
+    )))(s)
 }
 
 #[tracable_parser]
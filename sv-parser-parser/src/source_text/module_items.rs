@@ -108,10 +108,10 @@ pub(crate) fn module_common_item(s: Span) -> IResult<Span, ModuleCommonItem> {
         map(always_construct, |x| {
             ModuleCommonItem::AlwaysConstruct(Box::new(x))
         }),
-        map(loop_generate_construct, |x| {
+        map(ieee1364_2001(loop_generate_construct), |x| {
             ModuleCommonItem::LoopGenerateConstruct(Box::new(x))
         }),
-        map(conditional_generate_construct, |x| {
+        map(ieee1364_2001(conditional_generate_construct), |x| {
             ModuleCommonItem::ConditionalGenerateConstruct(Box::new(x))
         }),
         map(elaboration_system_task, |x| {
@@ -5,11 +5,11 @@ use crate::*;
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn parameter_port_list(s: Span) -> IResult<Span, ParameterPortList> {
-    alt((
+    ieee1364_2001(alt((
         parameter_port_list_assignment,
         parameter_port_list_declaration,
         parameter_port_list_empty,
-    ))(s)
+    )))(s)
 }
 
 #[tracable_parser]
@@ -101,10 +101,10 @@ pub(crate) fn list_of_ports(s: Span) -> IResult<Span, ListOfPorts> {
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn list_of_port_declarations(s: Span) -> IResult<Span, ListOfPortDeclarations> {
-    let (s, a) = paren(opt(list(
+    let (s, a) = ieee1364_2001(paren(opt(list(
         symbol(","),
         pair(many0(attribute_instance), ansi_port_declaration),
-    )))(s)?;
+    ))))(s)?;
     Ok((s, ListOfPortDeclarations { nodes: (a,) }))
 }
 
@@ -1,4 +1,5 @@
 use crate::*;
+use nom::InputTake;
 
 // -----------------------------------------------------------------------------
 
@@ -20,6 +21,342 @@ pub(crate) fn source_text_incomplete(s: Span) -> IResult<Span, SourceText> {
     Ok((s, SourceText { nodes: (a, b, c) }))
 }
 
+#[tracable_parser]
+#[packrat_parser]
+pub(crate) fn source_text_recover(s: Span) -> IResult<Span, SourceText> {
+    let (s, a) = many0(white_space)(s)?;
+    let (s, b) = opt(timeunits_declaration)(s)?;
+    let (s, (c, _)) = many_till(description_recover, eof)(s)?;
+    Ok((s, SourceText { nodes: (a, b, c) }))
+}
+
+#[tracable_parser]
+pub(crate) fn description_recover(s: Span) -> IResult<Span, Description> {
+    let len = recovered_len(&s);
+    match description(s) {
+        Err(Err::Error(e)) | Err(Err::Failure(e)) => {
+            truncate_recovered(&s, len);
+            let position = error_position(&e).unwrap_or_else(|| s.location_offset());
+            add_recovered_error(&s, position, &e);
+
+            let (t, a) = error_node(s, position)?;
+            if t.fragment().is_empty() {
+                if let Ok(x) = description_closed_at_eof(s, len) {
+                    return Ok(x);
+                }
+            }
+            Ok((t, Description::Error(Box::new(a))))
+        }
+        x => x,
+    }
+}
+
+// Parse the description skipped to the end of the text again, closing its declaration at the
+// end of the text. The error of the description is replaced by the errors of its items.
+fn description_closed_at_eof(s: Span, len: usize) -> IResult<Span, Description> {
+    let context = s.extra.context;
+    let recovered = context.recovered.borrow()[len..].to_vec();
+    truncate_recovered(&s, len);
+    context.packrat.borrow_mut().clear();
+
+    context.close_at_eof.set(true);
+    let ret = description(s);
+    context.close_at_eof.set(false);
+    // Results closed at the end of the text aren't reused
+    context.packrat.borrow_mut().clear();
+
+    match ret {
+        Ok((s, x)) if s.fragment().is_empty() => Ok((s, x)),
+        _ => {
+            truncate_recovered(&s, len);
+            context.recovered.borrow_mut().extend(recovered);
+            Err(Err::Error(make_error(s, ErrorKind::Fix)))
+        }
+    }
+}
+
+// The error is reported at the furthest position where tokens were expected, because an
+// optional part like the initializer of `wire x = ;` fails beyond the error position.
+pub(crate) fn add_recovered_error(s: &Span, position: usize, e: &GreedyError<Span, ErrorKind>) {
+    let (expected_position, expected) = get_expected(s);
+    let (position, expected) = if expected_position >= position {
+        (expected_position, expected)
+    } else {
+        (position, vec![])
+    };
+    add_recovered(
+        s,
+        RecoveredError {
+            position,
+            context: error_context(e),
+            expected,
+        },
+    );
+    clear_expected(s);
+}
+
+// Skip the broken description to the end keyword corresponding to its first keyword,
+// or to the next ';' if the description doesn't have an end keyword.
+// The skipped region is cut before the next design unit beyond the error position,
+// or beyond the first keyword if the end keyword is missing.
+pub(crate) fn error_node(s: Span, position: usize) -> IResult<Span, ErrorNode> {
+    let text = *s.fragment();
+    let from = position.saturating_sub(s.location_offset()).min(text.len());
+
+    let mut first = tokens(text).map(|(_, x)| x);
+    let end_keyword = match (first.next(), first.next()) {
+        (Some("module"), _) | (Some("macromodule"), _) => Some("endmodule"),
+        (Some("interface"), Some("class")) => Some("endclass"),
+        (Some("interface"), _) => Some("endinterface"),
+        (Some("program"), _) => Some("endprogram"),
+        (Some("package"), _) => Some("endpackage"),
+        (Some("class"), _) | (Some("virtual"), Some("class")) => Some("endclass"),
+        (Some("function"), _) => Some("endfunction"),
+        (Some("task"), _) => Some("endtask"),
+        (Some("primitive"), _) => Some("endprimitive"),
+        (Some("config"), _) => Some("endconfig"),
+        (Some("checker"), _) => Some("endchecker"),
+        (Some("covergroup"), _) => Some("endgroup"),
+        _ => None,
+    };
+
+    let end = match end_keyword {
+        Some(end_keyword) => {
+            // Nested declarations of the same kind have their own end keywords
+            let mut depth = 0;
+            tokens(text)
+                .find(|&(i, x)| {
+                    if block_end_keyword(x, "", true) == Some(end_keyword) {
+                        depth += 1;
+                    } else if x == end_keyword {
+                        depth -= 1;
+                    }
+                    x == end_keyword && depth <= 0 && i >= from
+                })
+                .map(|(i, x)| skip_label(text, i + x.len()))
+        }
+        None => text[from..].find(';').map(|x| from + x + 1),
+    };
+    let next_from = if end.is_some() { from.max(1) } else { 1 };
+    let next = tokens(text)
+        .find(|&(i, x)| {
+            i >= next_from
+                && matches!(
+                    x,
+                    "module"
+                        | "macromodule"
+                        | "interface"
+                        | "program"
+                        | "package"
+                        | "class"
+                        | "primitive"
+                        | "config"
+                        | "checker"
+                )
+        })
+        .map(|(i, _)| i);
+
+    let len = match (end, next) {
+        (Some(end), Some(next)) => end.min(next),
+        (Some(x), None) | (None, Some(x)) => x,
+        (None, None) => text.len(),
+    };
+    // Trailing whitespace is left to `white_space`
+    let len = match text[..len].trim_end().len() {
+        0 => len,
+        x => x,
+    };
+    skipped(s, len)
+}
+
+// Skip the broken item or statement to the next ';' beyond the error position,
+// or to the end keyword of the block which contains the error position.
+// The skipped region is cut before an end keyword which isn't opened in it,
+// so that the enclosing block is kept. It fails if nothing is skipped.
+pub(crate) fn item_error_node(s: Span, position: usize) -> IResult<Span, ErrorNode> {
+    let text = *s.fragment();
+    let from = position.saturating_sub(s.location_offset()).min(text.len());
+
+    let mut blocks = vec![];
+    let mut head = true;
+    let mut prev = "";
+    let mut len = text.len();
+    for (i, x) in tokens(text) {
+        if x == ";" {
+            if blocks.is_empty() && i >= from {
+                len = i + 1;
+                break;
+            }
+        } else if let Some(end) = block_end_keyword(x, prev, head) {
+            blocks.push(end);
+        } else if is_end_keyword(x) {
+            let end = if x.starts_with("join") { "join" } else { x };
+            match blocks.iter().rposition(|y| *y == end) {
+                Some(n) => {
+                    blocks.truncate(n);
+                    if blocks.is_empty() && i + x.len() > from {
+                        len = skip_label(text, i + x.len());
+                        break;
+                    }
+                }
+                // A stray end keyword of a block is skipped alone
+                None if i == 0 && !is_design_unit_end_keyword(x) => {
+                    len = skip_label(text, x.len());
+                    break;
+                }
+                None => {
+                    len = i;
+                    break;
+                }
+            }
+        }
+        // Declarations begin with qualifiers like `virtual function`
+        head = prev.is_empty()
+            && matches!(
+                x,
+                "virtual" | "static" | "protected" | "local" | "interface"
+            );
+        prev = x;
+    }
+    let len = text[..len].trim_end().len();
+    if len == 0 {
+        return Err(Err::Error(make_error(s, ErrorKind::Fix)));
+    }
+    skipped(s, len)
+}
+
+fn skipped(s: Span, len: usize) -> IResult<Span, ErrorNode> {
+    let (s, a) = s.take_split(len);
+    let (s, b) = many0(white_space)(s)?;
+    Ok((
+        s,
+        ErrorNode {
+            nodes: (into_locate(a), b),
+        },
+    ))
+}
+
+// The end keyword of the block which begins with `x`.
+// Declarations with bodies are distinguished from others like `extern function` or
+// `assert property` by their positions.
+fn block_end_keyword(x: &str, prev: &str, head: bool) -> Option<&'static str> {
+    let end = match x {
+        "begin" => "end",
+        "fork" if !matches!(prev, "wait" | "disable") => "join",
+        "case" | "casex" | "casez" | "randcase" => "endcase",
+        "generate" => "endgenerate",
+        "specify" => "endspecify",
+        "randsequence" => "endsequence",
+        _ if !head => return None,
+        "function" => "endfunction",
+        "task" => "endtask",
+        "class" => "endclass",
+        "covergroup" => "endgroup",
+        "property" => "endproperty",
+        "sequence" => "endsequence",
+        "clocking" => "endclocking",
+        "checker" => "endchecker",
+        "module" | "macromodule" => "endmodule",
+        "interface" => "endinterface",
+        "program" => "endprogram",
+        "package" => "endpackage",
+        "primitive" => "endprimitive",
+        "config" => "endconfig",
+        _ => return None,
+    };
+    Some(end)
+}
+
+fn is_end_keyword(x: &str) -> bool {
+    matches!(
+        x,
+        "end"
+            | "join"
+            | "join_any"
+            | "join_none"
+            | "endcase"
+            | "endgenerate"
+            | "endspecify"
+            | "endfunction"
+            | "endtask"
+            | "endgroup"
+            | "endproperty"
+            | "endsequence"
+            | "endclocking"
+    ) || is_design_unit_end_keyword(x)
+}
+
+fn is_design_unit_end_keyword(x: &str) -> bool {
+    matches!(
+        x,
+        "endmodule"
+            | "endinterface"
+            | "endprogram"
+            | "endpackage"
+            | "endclass"
+            | "endchecker"
+            | "endprimitive"
+            | "endconfig"
+    )
+}
+
+// Identifier-like words and ';' with their offsets, skipping comments and strings
+fn tokens(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    let is_ident = |c: char| AZ09_DOLLAR.contains(c);
+    let mut pos = 0;
+    core::iter::from_fn(move || loop {
+        let rest = &text[pos..];
+        let c = rest.chars().next()?;
+        let len = if rest.starts_with("//") {
+            rest.find('\n').unwrap_or(rest.len())
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            comment.find("*/").map_or(rest.len(), |x| x + 4)
+        } else if c == '"' {
+            let mut chars = rest.char_indices().skip(1);
+            let mut len = rest.len();
+            while let Some((i, c)) = chars.next() {
+                match c {
+                    '\\' => {
+                        chars.next();
+                    }
+                    '"' => {
+                        len = i + 1;
+                        break;
+                    }
+                    _ => (),
+                }
+            }
+            len
+        } else if is_ident(c) || c == ';' {
+            let len = if c == ';' {
+                1
+            } else {
+                rest.find(|c| !is_ident(c)).unwrap_or(rest.len())
+            };
+            let begin = pos;
+            pos += len;
+            return Some((begin, &text[begin..pos]));
+        } else {
+            c.len_utf8()
+        };
+        pos += len;
+    })
+}
+
+fn skip_label(text: &str, end: usize) -> usize {
+    let rest = text[end..].trim_start();
+    if let Some(rest) = rest.strip_prefix(':') {
+        let rest = rest.trim_start();
+        let label = rest
+            .find(|c: char| !AZ09_DOLLAR.contains(c))
+            .unwrap_or(rest.len());
+        if label > 0 {
+            return text.len() - rest.len() + label;
+        }
+    }
+    end
+}
+
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn description(s: Span) -> IResult<Span, Description> {
@@ -129,7 +466,10 @@ pub(crate) fn module_declaration(s: Span) -> IResult<Span, ModuleDeclaration> {
 pub(crate) fn module_declaration_nonansi(s: Span) -> IResult<Span, ModuleDeclaration> {
     let (s, a) = module_nonansi_header(s)?;
     let (s, b) = opt(timeunits_declaration)(s)?;
-    let (s, (c, d)) = many_till(module_item, keyword("endmodule"))(s)?;
+    let (s, (c, d)) = many_till(
+        recover(module_item, ModuleItem::Error),
+        end_keyword("endmodule"),
+    )(s)?;
     let (s, e) = opt(pair(symbol(":"), module_identifier))(s)?;
     Ok((
         s,
@@ -144,7 +484,7 @@ pub(crate) fn module_declaration_nonansi(s: Span) -> IResult<Span, ModuleDeclara
 pub(crate) fn module_declaration_ansi(s: Span) -> IResult<Span, ModuleDeclaration> {
     let (s, a) = module_ansi_header(s)?;
     let (s, b) = opt(timeunits_declaration)(s)?;
-    let (s, (c, d)) = many_till(non_port_module_item, keyword("endmodule"))(s)?;
+    let (s, (c, d)) = many_till(ansi_module_item, end_keyword("endmodule"))(s)?;
     let (s, e) = opt(pair(symbol(":"), module_identifier))(s)?;
     Ok((
         s,
@@ -154,6 +494,16 @@ pub(crate) fn module_declaration_ansi(s: Span) -> IResult<Span, ModuleDeclaratio
     ))
 }
 
+// A port declaration isn't skipped as an error of an ANSI module,
+// so that the module is parsed as a non-ANSI module
+#[tracable_parser]
+pub(crate) fn ansi_module_item(s: Span) -> IResult<Span, NonPortModuleItem> {
+    if is_recovering(&s) && pair(port_declaration, symbol(";"))(s).is_ok() {
+        return non_port_module_item(s);
+    }
+    recover(non_port_module_item, NonPortModuleItem::Error)(s)
+}
+
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn module_declaration_wildcard(s: Span) -> IResult<Span, ModuleDeclaration> {
@@ -163,7 +513,10 @@ pub(crate) fn module_declaration_wildcard(s: Span) -> IResult<Span, ModuleDeclar
     let (s, e) = paren(symbol(".*"))(s)?;
     let (s, f) = symbol(";")(s)?;
     let (s, g) = opt(timeunits_declaration)(s)?;
-    let (s, (h, i)) = many_till(module_item, keyword("endmodule"))(s)?;
+    let (s, (h, i)) = many_till(
+        recover(module_item, ModuleItem::Error),
+        end_keyword("endmodule"),
+    )(s)?;
     let (s, j) = opt(pair(symbol(":"), module_identifier))(s)?;
     Ok((
         s,
@@ -582,8 +935,11 @@ pub(crate) fn package_declaration(s: Span) -> IResult<Span, PackageDeclaration>
     let (s, e) = symbol(";")(s)?;
     let (s, f) = opt(timeunits_declaration)(s)?;
     let (s, (g, h)) = many_till(
-        pair(many0(attribute_instance), package_item),
-        keyword("endpackage"),
+        pair(
+            many0(attribute_instance),
+            recover(package_item, PackageItem::Error),
+        ),
+        end_keyword("endpackage"),
     )(s)?;
     let (s, i) = opt(pair(symbol(":"), package_identifier))(s)?;
     Ok((
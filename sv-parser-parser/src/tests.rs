@@ -4,8 +4,8 @@ use crate::*;
 
 macro_rules! test {
     ( $x:expr, $y:expr, $z:pat ) => {
-        nom_packrat::init!();
-        let info = SpanInfo::default();
+        let context = ParserContext::new();
+        let info = SpanInfo::new(&context);
         #[cfg(feature = "trace")]
         let info = info.set_tracable_info(
             info.get_tracable_info()
@@ -19,13 +19,22 @@ macro_rules! test {
         } else {
             assert!(false, "{:?}", ret)
         }
+        // Valid source text has nothing to recover
+        if stringify!($x) == "source_text" {
+            if let Ok((_, x)) = ret {
+                let context = ParserContext::new();
+                let recovered = sv_parser_recover(Span::new_extra($y, SpanInfo::new(&context)));
+                assert_eq!(context.recovered_errors(), vec![]);
+                assert_eq!(format!("{:?}", recovered.unwrap().1), format!("{:?}", x));
+            }
+        }
     };
 }
 
 macro_rules! error_test {
     ( $x:expr, $y:expr, $p:expr ) => {
-        nom_packrat::init!();
-        let info = SpanInfo::default();
+        let context = ParserContext::new();
+        let info = SpanInfo::new(&context);
         #[cfg(feature = "trace")]
         let info = info.set_tracable_info(
             info.get_tracable_info()
@@ -324,6 +333,11 @@ mod unit {
             Ok((_, _))
         );
         test!(text_macro_definition, r##"`define a"##, Ok((_, _)));
+        test!(
+            text_macro_definition,
+            r##"`define a(x=, y=1, z="s") x y z"##,
+            Ok((_, _))
+        );
         test!(
             source_text,
             r##"module test(out);
@@ -336,6 +350,14 @@ mod unit {
                 endmodule"##,
             Ok((_, _))
         );
+        test!(
+            ifdef_directive,
+            r##"`ifdef (A && !B) || C
+                  `elsif (A -> (B <-> C))
+                  `else
+                  `endif"##,
+            Ok((_, _))
+        );
     }
 
     #[test]
@@ -15931,6 +15953,230 @@ mod error {
     }
 }
 
+mod context {
+    use super::*;
+
+    #[test]
+    fn interleaved() {
+        let context_a = ParserContext::new();
+        let context_b = ParserContext::new();
+
+        let a = Span::new_extra(
+            r##"`begin_keywords "1364-1995" module logic; endmodule"##,
+            SpanInfo::new(&context_a),
+        );
+        assert!(all_consuming(sv_parser)(a).is_ok());
+        assert_eq!(context_a.version.borrow().len(), 1);
+        let expected_a = context_a.expected_tokens();
+
+        let b = Span::new_extra(
+            r##"module A(); parameter A = 1 endmodule"##,
+            SpanInfo::new(&context_b),
+        );
+        assert!(all_consuming(sv_parser)(b).is_err());
+        assert_eq!(context_b.expected_tokens().0, 28);
+
+        // The state of a parse is not affected by the other one
+        assert_eq!(context_a.version.borrow().len(), 1);
+        assert_eq!(context_a.expected_tokens(), expected_a);
+
+        let c = Span::new_extra(
+            r##"module A; logic a; endmodule"##,
+            SpanInfo::new(&context_a),
+        );
+        assert!(all_consuming(sv_parser)(c).is_ok());
+        assert_eq!(context_a.version.borrow().len(), 0);
+    }
+
+    #[test]
+    fn version() {
+        let context = ParserContext::with_options(ParserOptions {
+            version: Some(Version::Ieee1364_2005),
+            ..ParserOptions::default()
+        });
+        let s = r##"module A; wire logic; endmodule `end_keywords module B; wire bit; endmodule"##;
+        assert!(all_consuming(sv_parser)(Span::new_extra(s, SpanInfo::new(&context))).is_ok());
+
+        let s = r##"`begin_keywords "1800-2017" module A; wire logic; endmodule `end_keywords"##;
+        assert!(all_consuming(sv_parser)(Span::new_extra(s, SpanInfo::new(&context))).is_err());
+    }
+
+    #[test]
+    fn ieee1364() {
+        let parse = |s: &str, version| {
+            let context = ParserContext::with_options(ParserOptions {
+                version,
+                ..ParserOptions::default()
+            });
+            let ret = all_consuming(sv_parser)(Span::new_extra(s, SpanInfo::new(&context)));
+            ret.is_ok()
+        };
+
+        let verilog = r##"module A #(parameter W = 8) (input clk, input [W-1:0] d, output reg [W-1:0] q);
+  wire logic, bit;
+  integer i;
+  always @(posedge clk) begin : blk
+    q <= d;
+    for (i = 0; i < W; i = i + 1) q[i] <= d[W-1-i];
+  end
+  B #(.W(W)) b (.clk(clk), .d(d));
+  generate if (W > 1) begin : g assign logic = d[0] == 1'b1; end endgenerate
+endmodule"##;
+        assert!(parse(verilog, Some(Version::Ieee1364_2005)));
+        assert!(!parse(verilog, None));
+
+        let systemverilog = [
+            r##"module A; logic a; endmodule"##,
+            r##"module A; always_comb a = b; endmodule"##,
+            r##"module A; typedef int t; endmodule"##,
+            r##"module A; t a; endmodule"##,
+            r##"module A; initial a++; endmodule"##,
+            r##"module A; initial a += 1; endmodule"##,
+            r##"module A; assign a = '0; endmodule"##,
+            r##"module A; assign a = int'(b); endmodule"##,
+            r##"module A; assign a = p::b; endmodule"##,
+            r##"module A; assign a = b ==? c; endmodule"##,
+            r##"module A; initial #1ns a = 1; endmodule"##,
+            r##"module A; B b (.*); endmodule"##,
+            r##"interface I; endinterface"##,
+            r##"package P; endpackage"##,
+        ];
+        for s in &systemverilog {
+            assert!(parse(s, None), "{}", s);
+            assert!(!parse(s, Some(Version::Ieee1364_2005)), "{}", s);
+        }
+
+        // `begin_keywords changes the keywords, but not the grammar
+        let s = r##"`begin_keywords "1364-2005"
+module A; initial a++; B b (.*); endmodule
+`end_keywords"##;
+        assert!(parse(s, None));
+
+        // Keywords added by a later version of IEEE 1800
+        let s = r##"module A; checker c; endchecker endmodule"##;
+        assert!(parse(s, Some(Version::Ieee1800_2009)));
+        assert!(!parse(s, Some(Version::Ieee1800_2005)));
+    }
+
+    #[test]
+    fn ieee1364_1995() {
+        let parse = |s: &str, version| {
+            let context = ParserContext::with_options(ParserOptions {
+                version: Some(version),
+                ..ParserOptions::default()
+            });
+            let ret = all_consuming(sv_parser)(Span::new_extra(s, SpanInfo::new(&context)));
+            ret.is_ok()
+        };
+
+        let verilog = r##"module A(clk, d, q);
+  parameter W = 8;
+  input clk;
+  input [W-1:0] d;
+  output [W-1:0] q;
+  reg [W-1:0] q;
+  wire [W-1:0] n = d;
+  always @(posedge clk or negedge n[0]) q <= d * 2;
+endmodule"##;
+        assert!(parse(verilog, Version::Ieee1364_1995));
+
+        // Constructs added by IEEE 1364-2001
+        let verilog2001 = [
+            r##"module A(input wire a); endmodule"##,
+            r##"module A #(parameter W = 8); endmodule"##,
+            r##"module A; assign a = b ** c; endmodule"##,
+            r##"module A; assign a = b <<< c; endmodule"##,
+            r##"module A; initial a[b+:2] = 1; endmodule"##,
+            r##"module A; initial a = 8'sd1; endmodule"##,
+            r##"module A; wire signed a; endmodule"##,
+            r##"module A; localparam a = 1; endmodule"##,
+            r##"module A; genvar i; endmodule"##,
+            r##"module A; if (1) assign a = b; endmodule"##,
+            r##"module A; for (i = 0; i < 2; i = i + 1) begin : g assign a = b; end endmodule"##,
+            r##"module A; (* keep *) wire a; endmodule"##,
+            r##"module A; always @* a = b; endmodule"##,
+            r##"module A; always @(*) a = b; endmodule"##,
+            r##"module A; always @(a, b) a = b; endmodule"##,
+        ];
+        for s in &verilog2001 {
+            assert!(parse(s, Version::Ieee1364_2001), "{}", s);
+            assert!(!parse(s, Version::Ieee1364_1995), "{}", s);
+        }
+    }
+}
+
+mod depth {
+    use super::*;
+
+    fn parse(s: &str, options: ParserOptions) -> (bool, Option<usize>) {
+        let context = ParserContext::with_options(options);
+        let ret = all_consuming(sv_parser)(Span::new_extra(s, SpanInfo::new(&context)));
+        (ret.is_ok(), context.exceeded_depth())
+    }
+
+    #[test]
+    fn long_chain() {
+        // Chains of operators don't nest parsers, so they can be parsed on the default stack
+        let s = format!(
+            "module a; localparam a = {}1; localparam b = {}1; assign c = {}d; endmodule",
+            "a + ".repeat(1000),
+            "a ? 1 : ".repeat(1000),
+            "d && e ? 1 : ".repeat(1000),
+        );
+        assert_eq!(parse(&s, ParserOptions::default()), (true, None));
+    }
+
+    #[test]
+    fn precedence() {
+        let context = ParserContext::new();
+        let s = Span::new_extra("a - b * c - d ? e : f ? g : h", SpanInfo::new(&context));
+        let (_, x) = all_consuming(expression)(s).unwrap();
+        let x = match x {
+            Expression::ConditionalExpression(x) => x,
+            x => panic!("{:?}", x),
+        };
+        assert!(matches!(x.nodes.5, Expression::ConditionalExpression(_)));
+        let x = match &(x.nodes.0).nodes.0.nodes.0 {
+            ExpressionOrCondPattern::Expression(x) => x,
+            x => panic!("{:?}", x),
+        };
+        // (a - (b * c)) - d
+        let x = match &**x {
+            Expression::Binary(x) => x,
+            x => panic!("{:?}", x),
+        };
+        assert!(matches!(x.nodes.3, Expression::Primary(_)));
+        let x = match &x.nodes.0 {
+            Expression::Binary(x) => x,
+            x => panic!("{:?}", x),
+        };
+        assert!(matches!(x.nodes.0, Expression::Primary(_)));
+        assert!(matches!(x.nodes.3, Expression::Binary(_)));
+    }
+
+    #[test]
+    fn max_depth() {
+        let s = |n| {
+            format!(
+                "module a; localparam a = {}1{}; endmodule",
+                "(".repeat(n),
+                ")".repeat(n)
+            )
+        };
+        let options = ParserOptions {
+            max_depth: 16,
+            ..ParserOptions::default()
+        };
+        assert_eq!(parse(&s(8), options), (true, None));
+        assert_eq!(parse(&s(32), options), (false, Some(25 + 16)));
+
+        // The depth is unlimited by default, and the stack of the parser grows beyond
+        // the stack of the thread
+        assert_eq!(parse(&s(100), ParserOptions::default()), (true, None));
+        assert_eq!(parse(&s(300), ParserOptions::default()), (true, None));
+    }
+}
+
 #[test]
 fn debug() {
     test!(
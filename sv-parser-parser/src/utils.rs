@@ -1,4 +1,5 @@
 use crate::*;
+use nom::InputTake;
 
 // -----------------------------------------------------------------------------
 
@@ -28,19 +29,23 @@ where
 }
 
 #[cfg(not(feature = "trace"))]
-pub(crate) fn symbol<'a>(t: &'a str) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, Symbol> {
+pub(crate) fn symbol<'a>(t: &'static str) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, Symbol> {
     move |s: Span<'a>| {
-        let (s, x) = map(ws(map(tag(t), into_locate)), |x| Symbol { nodes: x })(s)?;
+        let (s, x) = map(ws(map(expect(t, tag(t)), into_locate)), |x| Symbol {
+            nodes: x,
+        })(s)?;
         Ok((s, x))
     }
 }
 
 #[cfg(feature = "trace")]
-pub(crate) fn symbol<'a>(t: &'a str) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, Symbol> {
+pub(crate) fn symbol<'a>(t: &'static str) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, Symbol> {
     move |s: Span<'a>| {
         let (depth, s) = nom_tracable::forward_trace(s, &format!("symbol(\"{}\")", t));
         let body = || {
-            let (s, x) = map(ws(map(tag(t), into_locate)), |x| Symbol { nodes: x })(s)?;
+            let (s, x) = map(ws(map(expect(t, tag(t)), into_locate)), |x| Symbol {
+                nodes: x,
+            })(s)?;
             Ok((s, x))
         };
         let ret = body();
@@ -49,19 +54,27 @@ pub(crate) fn symbol<'a>(t: &'a str) -> impl FnMut(Span<'a>) -> IResult<Span<'a>
 }
 
 #[cfg(not(feature = "trace"))]
-pub(crate) fn symbol_exact<'a>(t: &'a str) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, Symbol> {
+pub(crate) fn symbol_exact<'a>(
+    t: &'static str,
+) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, Symbol> {
     move |s: Span<'a>| {
-        let (s, x) = map(no_ws(map(tag(t), into_locate)), |x| Symbol { nodes: x })(s)?;
+        let (s, x) = map(no_ws(map(expect(t, tag(t)), into_locate)), |x| Symbol {
+            nodes: x,
+        })(s)?;
         Ok((s, x))
     }
 }
 
 #[cfg(feature = "trace")]
-pub(crate) fn symbol_exact<'a>(t: &'a str) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, Symbol> {
+pub(crate) fn symbol_exact<'a>(
+    t: &'static str,
+) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, Symbol> {
     move |s: Span<'a>| {
         let (depth, s) = nom_tracable::forward_trace(s, &format!("symbol(\"{}\")", t));
         let body = || {
-            let (s, x) = map(no_ws(map(tag(t), into_locate)), |x| Symbol { nodes: x })(s)?;
+            let (s, x) = map(no_ws(map(expect(t, tag(t)), into_locate)), |x| Symbol {
+                nodes: x,
+            })(s)?;
             Ok((s, x))
         };
         let ret = body();
@@ -70,13 +83,19 @@ pub(crate) fn symbol_exact<'a>(t: &'a str) -> impl FnMut(Span<'a>) -> IResult<Sp
 }
 
 #[cfg(not(feature = "trace"))]
-pub(crate) fn keyword<'a>(t: &'a str) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, Keyword> {
+pub(crate) fn keyword<'a>(t: &'static str) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, Keyword> {
     move |s: Span<'a>| {
+        if !is_available_keyword(&s, t) {
+            return Err(Err::Error(make_error(s, ErrorKind::Fix)));
+        }
         let (s, x) = map(
-            ws(alt((
-                all_consuming(map(tag(t), into_locate)),
-                terminated(map(tag(t), into_locate), peek(none_of(AZ09_))),
-            ))),
+            ws(expect(
+                t,
+                alt((
+                    all_consuming(map(tag(t), into_locate)),
+                    terminated(map(tag(t), into_locate), peek(none_of(AZ09_))),
+                )),
+            )),
             |x| Keyword { nodes: x },
         )(s)?;
         Ok((s, x))
@@ -84,15 +103,21 @@ pub(crate) fn keyword<'a>(t: &'a str) -> impl FnMut(Span<'a>) -> IResult<Span<'a
 }
 
 #[cfg(feature = "trace")]
-pub(crate) fn keyword<'a>(t: &'a str) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, Keyword> {
+pub(crate) fn keyword<'a>(t: &'static str) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, Keyword> {
     move |s: Span<'a>| {
         let (depth, s) = nom_tracable::forward_trace(s, &format!("keyword(\"{}\")", t));
         let body = || {
+            if !is_available_keyword(&s, t) {
+                return Err(Err::Error(make_error(s, ErrorKind::Fix)));
+            }
             let (s, x) = map(
-                ws(alt((
-                    all_consuming(map(tag(t), into_locate)),
-                    terminated(map(tag(t), into_locate), peek(none_of(AZ09_))),
-                ))),
+                ws(expect(
+                    t,
+                    alt((
+                        all_consuming(map(tag(t), into_locate)),
+                        terminated(map(tag(t), into_locate), peek(none_of(AZ09_))),
+                    )),
+                )),
                 |x| Keyword { nodes: x },
             )(s)?;
             Ok((s, x))
@@ -261,6 +286,69 @@ where
     }
 }
 
+pub(crate) fn expect<'a, O, F>(
+    t: &'static str,
+    mut f: F,
+) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, O>
+where
+    F: FnMut(Span<'a>) -> IResult<Span<'a>, O>,
+{
+    move |s: Span<'a>| {
+        let ret = f(s);
+        if ret.is_err() {
+            add_expected(&s, t);
+        }
+        ret
+    }
+}
+
+// Stack left before `nested` switches to a new stack segment.
+// It must be larger than the stack used between two levels of `nested`, which are
+// put at expressions, statements, generate blocks, struct types and class items.
+const STACK_RED_ZONE: usize = 1024 * 1024;
+const STACK_SEGMENT: usize = 8 * 1024 * 1024;
+// Stack used to clone or drop a level of `nested` in a node
+const STACK_PER_LEVEL: usize = 16 * 1024;
+
+// Count the nesting depth of `f`, and fail without calling it beyond `ParserOptions::max_depth`.
+// `f` is called on a new stack segment if the stack is running out.
+pub(crate) fn nested<'a, O, F>(mut f: F) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, O>
+where
+    F: FnMut(Span<'a>) -> IResult<Span<'a>, O>,
+{
+    move |s: Span<'a>| {
+        let context = s.extra.context;
+        let depth = context.depth.get() + 1;
+        let (s, _) = reach(s, depth)?;
+        context.depth.set(depth);
+        let ret = stacker::maybe_grow(STACK_RED_ZONE, STACK_SEGMENT, || f(s));
+        context.depth.set(depth - 1);
+        ret
+    }
+}
+
+// Call `f` recursing over a node which reaches `height` levels of `nested`,
+// on a new stack segment if the stack left isn't enough for the height
+pub(crate) fn grow_for_height<R, F: FnOnce() -> R>(height: usize, f: F) -> R {
+    let size = height
+        .saturating_mul(STACK_PER_LEVEL)
+        .saturating_add(STACK_RED_ZONE);
+    stacker::maybe_grow(size, size.max(STACK_SEGMENT), f)
+}
+
+// Record that a node reaches the nesting depth `depth`, and fail beyond `ParserOptions::max_depth`
+pub(crate) fn reach(s: Span, depth: usize) -> IResult<Span, ()> {
+    let context = s.extra.context;
+    if depth > context.options.max_depth {
+        if context.exceeded_depth.get().is_none() {
+            context.exceeded_depth.set(Some(s.location_offset()));
+        }
+        return Err(Err::Failure(make_error(s, ErrorKind::TooLarge)));
+    }
+    context.reached.set(context.reached.get().max(depth));
+    Ok((s, ()))
+}
+
 pub(crate) fn list<'a, O1, O2, F, G>(
     mut f: F,
     mut g: G,
@@ -308,7 +396,7 @@ where
 #[tracable_parser]
 #[packrat_parser]
 pub(crate) fn white_space(s: Span) -> IResult<Span, WhiteSpace> {
-    if in_directive() {
+    if in_directive(&s) {
         map(multispace1, |x: Span| {
             WhiteSpace::Space(Box::new(into_locate(x)))
         })(s)
@@ -331,99 +419,153 @@ pub(crate) fn white_space(s: Span) -> IResult<Span, WhiteSpace> {
     }
 }
 
-thread_local!(
-    static IN_DIRECTIVE: core::cell::RefCell<Vec<()>> = {
-        core::cell::RefCell::new(Vec::new())
+pub(crate) fn in_directive(s: &Span) -> bool {
+    s.extra.context.in_directive.get() > 0
+}
+
+pub(crate) fn begin_directive(s: &Span) {
+    let x = &s.extra.context.in_directive;
+    x.set(x.get() + 1);
+}
+
+pub(crate) fn end_directive(s: &Span) {
+    let x = &s.extra.context.in_directive;
+    x.set(x.get().saturating_sub(1));
+}
+
+// -----------------------------------------------------------------------------
+
+// Most failures are behind the furthest one, so they are dropped by comparing the position
+// before borrowing the tokens
+pub(crate) fn add_expected(s: &Span, t: &'static str) {
+    let context = s.extra.context;
+    let pos = s.location_offset();
+    let furthest = context.expected_position.get();
+    if pos < furthest {
+        return;
+    }
+    let mut x = context.expected.borrow_mut();
+    if pos > furthest {
+        context.expected_position.set(pos);
+        x.clear();
+    }
+    if !x.contains(&t) {
+        x.push(t);
+    }
+}
+
+pub(crate) fn get_expected(s: &Span) -> (usize, Vec<&'static str>) {
+    s.extra.context.expected_tokens()
+}
+
+pub(crate) fn clear_expected(s: &Span) {
+    s.extra.context.expected_position.set(0);
+    s.extra.context.expected.borrow_mut().clear();
+}
+
+pub(crate) fn is_recovering(s: &Span) -> bool {
+    s.extra.context.recover.get()
+}
+
+// An error found again by another alternative is recorded once
+pub(crate) fn add_recovered(s: &Span, x: RecoveredError) {
+    let mut recovered = s.extra.context.recovered.borrow_mut();
+    if recovered.iter().all(|y| y.position != x.position) {
+        recovered.push(x);
     }
-);
+}
 
-pub(crate) fn in_directive() -> bool {
-    IN_DIRECTIVE.with(|x| x.borrow().last().is_some())
+pub(crate) fn recovered_len(s: &Span) -> usize {
+    s.extra.context.recovered.borrow().len()
 }
 
-pub(crate) fn begin_directive() {
-    IN_DIRECTIVE.with(|x| x.borrow_mut().push(()));
+// Discard the errors recovered by a parse which failed after all.
+// Packrat results may contain their error nodes, so they are discarded too.
+pub(crate) fn truncate_recovered(s: &Span, len: usize) {
+    let mut recovered = s.extra.context.recovered.borrow_mut();
+    if recovered.len() > len {
+        recovered.truncate(len);
+        s.extra.context.packrat.borrow_mut().clear();
+    }
 }
 
-pub(crate) fn end_directive() {
-    IN_DIRECTIVE.with(|x| x.borrow_mut().pop());
+// Record the error of `f` and skip the broken item or statement to `ErrorNode` made into `O` by `g`,
+// if the parse is error-recovering
+pub(crate) fn recover<'a, O, F, G>(mut f: F, g: G) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, O>
+where
+    F: FnMut(Span<'a>) -> IResult<Span<'a>, O>,
+    G: Fn(Box<ErrorNode>) -> O,
+{
+    move |s: Span<'a>| {
+        if !is_recovering(&s) {
+            return f(s);
+        }
+        let len = recovered_len(&s);
+        let ret = f(s);
+        let e = match &ret {
+            Err(Err::Error(e)) | Err(Err::Failure(e)) => e,
+            _ => return ret,
+        };
+        truncate_recovered(&s, len);
+        let position = error_position(e).unwrap_or_else(|| s.location_offset());
+        match item_error_node(s, position) {
+            Ok((t, a)) => {
+                add_recovered_error(&s, position, e);
+                Ok((t, g(Box::new(a))))
+            }
+            Err(_) => ret,
+        }
+    }
 }
 
-pub(crate) fn clear_directive() {
-    IN_DIRECTIVE.with(|x| x.borrow_mut().clear());
+// The end keyword of a declaration whose items are recovered.
+// While a description reaching the end of the text is parsed again, the declaration is
+// closed at the end of the text by an empty end keyword, so that a declaration being typed
+// is kept.
+pub(crate) fn end_keyword<'a>(
+    t: &'static str,
+) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, Keyword> {
+    move |s: Span<'a>| {
+        if !s.extra.context.close_at_eof.get() || !s.fragment().is_empty() {
+            return keyword(t)(s);
+        }
+        add_recovered(
+            &s,
+            RecoveredError {
+                position: s.location_offset(),
+                context: vec![],
+                expected: vec![t],
+            },
+        );
+        let (s, a) = s.take_split(0);
+        Ok((
+            s,
+            Keyword {
+                nodes: (into_locate(a), vec![]),
+            },
+        ))
+    }
 }
 
 // -----------------------------------------------------------------------------
 
-#[derive(Clone, Copy, Debug)]
-pub(crate) enum VersionSpecifier {
-    Ieee1364_1995,
-    Ieee1364_2001,
-    Ieee1364_2001Noconfig,
-    Ieee1364_2005,
-    Ieee1800_2005,
-    Ieee1800_2009,
-    Ieee1800_2012,
-    Ieee1800_2017,
-    Directive,
-}
-
-thread_local!(
-    static CURRENT_VERSION: core::cell::RefCell<Vec<VersionSpecifier>> = {
-        core::cell::RefCell::new(Vec::new())
-    }
-);
-
-pub(crate) fn begin_keywords(version: &str) {
-    CURRENT_VERSION.with(|current_version| match version {
-        "1364-1995" => current_version
-            .borrow_mut()
-            .push(VersionSpecifier::Ieee1364_1995),
-        "1364-2001" => current_version
-            .borrow_mut()
-            .push(VersionSpecifier::Ieee1364_2001),
-        "1364-2001-noconfig" => current_version
-            .borrow_mut()
-            .push(VersionSpecifier::Ieee1364_2001Noconfig),
-        "1364-2005" => current_version
-            .borrow_mut()
-            .push(VersionSpecifier::Ieee1364_2005),
-        "1800-2005" => current_version
-            .borrow_mut()
-            .push(VersionSpecifier::Ieee1800_2005),
-        "1800-2009" => current_version
-            .borrow_mut()
-            .push(VersionSpecifier::Ieee1800_2009),
-        "1800-2012" => current_version
-            .borrow_mut()
-            .push(VersionSpecifier::Ieee1800_2012),
-        "1800-2017" => current_version
-            .borrow_mut()
-            .push(VersionSpecifier::Ieee1800_2017),
-        "directive" => current_version
-            .borrow_mut()
-            .push(VersionSpecifier::Directive),
-        _ => (),
-    });
-}
-
-pub(crate) fn end_keywords() {
-    CURRENT_VERSION.with(|current_version| {
-        current_version.borrow_mut().pop();
-    });
-}
-
-pub(crate) fn current_version() -> Option<VersionSpecifier> {
-    CURRENT_VERSION.with(|current_version| match current_version.borrow().last() {
-        Some(x) => Some(*x),
-        None => None,
-    })
-}
-
-pub(crate) fn clear_version() {
-    CURRENT_VERSION.with(|current_version| {
-        current_version.borrow_mut().clear();
-    });
+pub(crate) fn begin_keywords(s: &Span, version: &str) {
+    if let Some(version) = Version::from_specifier(version) {
+        s.extra.context.version.borrow_mut().push(version);
+    }
+}
+
+// The version of `ParserOptions` isn't ended by `end_keywords
+pub(crate) fn end_keywords(s: &Span) {
+    let context = &s.extra.context;
+    let mut version = context.version.borrow_mut();
+    if version.len() > context.options.version.iter().count() {
+        version.pop();
+    }
+}
+
+pub(crate) fn current_version(s: &Span) -> Option<Version> {
+    s.extra.context.version.borrow().last().copied()
 }
 
 // -----------------------------------------------------------------------------
@@ -440,20 +582,23 @@ pub(crate) fn concat<'a>(a: Span<'a>, b: Span<'a>) -> Option<Span<'a>> {
     }
 }
 
-pub(crate) fn is_keyword(s: &Span) -> bool {
-    let keywords = match current_version() {
-        Some(VersionSpecifier::Ieee1364_1995) => KEYWORDS_1364_1995,
-        Some(VersionSpecifier::Ieee1364_2001) => KEYWORDS_1364_2001,
-        Some(VersionSpecifier::Ieee1364_2001Noconfig) => KEYWORDS_1364_2001_NOCONFIG,
-        Some(VersionSpecifier::Ieee1364_2005) => KEYWORDS_1364_2005,
-        Some(VersionSpecifier::Ieee1800_2005) => KEYWORDS_1800_2005,
-        Some(VersionSpecifier::Ieee1800_2009) => KEYWORDS_1800_2009,
-        Some(VersionSpecifier::Ieee1800_2012) => KEYWORDS_1800_2012,
-        Some(VersionSpecifier::Ieee1800_2017) => KEYWORDS_1800_2017,
-        Some(VersionSpecifier::Directive) => KEYWORDS_DIRECTIVE,
+fn keywords(version: Option<Version>) -> &'static [&'static str] {
+    match version {
+        Some(Version::Ieee1364_1995) => KEYWORDS_1364_1995,
+        Some(Version::Ieee1364_2001) => KEYWORDS_1364_2001,
+        Some(Version::Ieee1364_2001Noconfig) => KEYWORDS_1364_2001_NOCONFIG,
+        Some(Version::Ieee1364_2005) => KEYWORDS_1364_2005,
+        Some(Version::Ieee1800_2005) => KEYWORDS_1800_2005,
+        Some(Version::Ieee1800_2009) => KEYWORDS_1800_2009,
+        Some(Version::Ieee1800_2012) => KEYWORDS_1800_2012,
+        Some(Version::Ieee1800_2017) => KEYWORDS_1800_2017,
+        Some(Version::Directive) => KEYWORDS_DIRECTIVE,
         None => KEYWORDS_1800_2017,
-    };
-    for k in keywords {
+    }
+}
+
+pub(crate) fn is_keyword(s: &Span) -> bool {
+    for k in keywords(current_version(s)) {
         if s.fragment() == k {
             return true;
         }
@@ -461,6 +606,53 @@ pub(crate) fn is_keyword(s: &Span) -> bool {
     false
 }
 
+// Keywords added by a later version than the current one can't start a construct
+pub(crate) fn is_available_keyword(s: &Span, t: &str) -> bool {
+    match current_version(s) {
+        None | Some(Version::Directive) | Some(Version::Ieee1800_2017) => true,
+        x => keywords(x).contains(&t) || !KEYWORDS_1800_2017.contains(&t),
+    }
+}
+
+// `begin_keywords only changes the reserved keywords, so the grammar follows `ParserOptions`
+fn is_ieee1364(s: &Span) -> bool {
+    matches!(
+        s.extra.context.options.version,
+        Some(Version::Ieee1364_1995)
+            | Some(Version::Ieee1364_2001)
+            | Some(Version::Ieee1364_2001Noconfig)
+            | Some(Version::Ieee1364_2005)
+    )
+}
+
+// Constructs added by IEEE 1800 fail in IEEE 1364
+pub(crate) fn ieee1800<'a, O, F>(mut f: F) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, O>
+where
+    F: FnMut(Span<'a>) -> IResult<Span<'a>, O>,
+{
+    move |s: Span<'a>| {
+        if is_ieee1364(&s) {
+            Err(Err::Error(make_error(s, ErrorKind::Fix)))
+        } else {
+            f(s)
+        }
+    }
+}
+
+// Constructs added by IEEE 1364-2001 fail in IEEE 1364-1995
+pub(crate) fn ieee1364_2001<'a, O, F>(mut f: F) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, O>
+where
+    F: FnMut(Span<'a>) -> IResult<Span<'a>, O>,
+{
+    move |s: Span<'a>| {
+        if s.extra.context.options.version == Some(Version::Ieee1364_1995) {
+            Err(Err::Error(make_error(s, ErrorKind::Fix)))
+        } else {
+            f(s)
+        }
+    }
+}
+
 pub(crate) fn into_locate(s: Span) -> Locate {
     Locate {
         offset: s.location_offset(),
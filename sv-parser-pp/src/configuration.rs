@@ -0,0 +1,204 @@
+use crate::preprocess::{identifier, parse_pp_text};
+use crate::source_provider::SourceProvider;
+use std::collections::{BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+use sv_parser_error::Error;
+use sv_parser_syntaxtree::{IncludeCompilerDirective, RefNode};
+
+/// The maximum number of macros whose combinations are enumerated exhaustively
+pub const EXHAUSTIVE_LIMIT: usize = 8;
+
+/// Macro names and whether each macro is defined
+pub type DefineAssignment = Vec<(String, bool)>;
+
+/// List macros tested by `` `ifdef ``, `` `ifndef `` and `` `elsif `` in the specified file
+///
+/// Files included by string literals are searched regardless of the conditions around them.
+/// Macros defined by `` `define `` in the searched files are excluded because they aren't
+/// configurable from the outside.
+pub fn conditional_macros<T: AsRef<Path>, U: AsRef<Path>>(
+    path: T,
+    include_paths: &[U],
+    provider: &dyn SourceProvider,
+) -> Result<Vec<String>, Error> {
+    let include_paths: Vec<&Path> = include_paths.iter().map(|x| x.as_ref()).collect();
+    let mut tested = BTreeSet::new();
+    let mut defined = HashSet::new();
+    let mut visited = HashSet::new();
+    let mut files = vec![PathBuf::from(path.as_ref())];
+
+    while let Some(path) = files.pop() {
+        if !visited.insert(provider.canonicalize(&path)) {
+            continue;
+        }
+        let s = provider.read(&path).map_err(|x| Error::File {
+            source: x,
+            path: path.clone(),
+        })?;
+        let pp_text = parse_pp_text(&s, &path)?;
+
+        let mut includes = Vec::new();
+        for node in &pp_text {
+            match node {
+                RefNode::IfdefCondition(x) => {
+                    for x in x {
+                        if let RefNode::TextMacroIdentifier(x) = x {
+                            tested.insert(identifier(x.into(), &s).unwrap());
+                        }
+                    }
+                }
+                RefNode::TextMacroDefinition(x) => {
+                    let (_, _, ref proto, _) = x.nodes;
+                    defined.insert(identifier((&proto.nodes.0).into(), &s).unwrap());
+                }
+                RefNode::IncludeCompilerDirective(IncludeCompilerDirective::DoubleQuote(x)) => {
+                    let include = x.nodes.2.nodes.0.str(&s).trim_matches('"');
+                    includes.push(PathBuf::from(include));
+                }
+                RefNode::IncludeCompilerDirective(IncludeCompilerDirective::AngleBracket(x)) => {
+                    let include = x.nodes.2.nodes.0.str(&s);
+                    let include = include.trim_start_matches('<').trim_end_matches('>');
+                    includes.push(PathBuf::from(include));
+                }
+                _ => (),
+            }
+        }
+
+        // Includes in inactive branches may not exist
+        for include in includes.into_iter().rev() {
+            let include = provider.resolve_include(&include, &path, &include_paths);
+            if provider.exists(&include) {
+                files.push(include);
+            }
+        }
+    }
+
+    Ok(tested
+        .into_iter()
+        .filter(|x| !defined.contains(x))
+        .collect())
+}
+
+/// Combinations of defining and undefining the specified macros
+///
+/// All combinations are returned if the number of macros is at most `EXHAUSTIVE_LIMIT`.
+/// Otherwise the combinations cover every pair of assignments of two macros.
+pub fn define_combinations(macros: &[String]) -> Vec<DefineAssignment> {
+    let rows = if macros.len() <= EXHAUSTIVE_LIMIT {
+        exhaustive(macros.len())
+    } else {
+        pairwise(macros.len())
+    };
+    rows.into_iter()
+        .map(|row| macros.iter().cloned().zip(row).collect())
+        .collect()
+}
+
+fn exhaustive(n: usize) -> Vec<Vec<bool>> {
+    (0..1usize << n)
+        .map(|i| (0..n).map(|j| i & (1 << j) != 0).collect())
+        .collect()
+}
+
+// Greedy construction: each row starts from an uncovered pair,
+// and the other values are chosen to cover as many uncovered pairs as possible.
+fn pairwise(n: usize) -> Vec<Vec<bool>> {
+    let mut uncovered = BTreeSet::new();
+    for i in 0..n {
+        for j in i + 1..n {
+            for &x in &[false, true] {
+                for &y in &[false, true] {
+                    uncovered.insert((i, x, j, y));
+                }
+            }
+        }
+    }
+
+    let mut ret = Vec::new();
+    while let Some(&(i, x, j, y)) = uncovered.iter().next() {
+        let mut row = vec![None; n];
+        row[i] = Some(x);
+        row[j] = Some(y);
+        for k in 0..n {
+            if row[k].is_some() {
+                continue;
+            }
+            let count = |v: bool| {
+                row.iter()
+                    .enumerate()
+                    .filter_map(|(l, w)| w.map(|w| (l, w)))
+                    .filter(|&(l, w)| {
+                        let pair = if l < k { (l, w, k, v) } else { (k, v, l, w) };
+                        uncovered.contains(&pair)
+                    })
+                    .count()
+            };
+            row[k] = Some(count(true) > count(false));
+        }
+
+        let row: Vec<bool> = row.into_iter().map(|x| x.unwrap()).collect();
+        for i in 0..n {
+            for j in i + 1..n {
+                uncovered.remove(&(i, row[i], j, row[j]));
+            }
+        }
+        ret.push(row);
+    }
+
+    if ret.is_empty() {
+        ret.push(vec![false; n]);
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_conditional_macros() {
+        let mut files = HashMap::new();
+        files.insert(
+            PathBuf::from("top.sv"),
+            String::from(
+                "`include \"defs.svh\"\n`ifdef A\n`elsif (B && !C)\n`endif\n`ifndef GUARD\n`define GUARD\n`endif\n",
+            ),
+        );
+        files.insert(
+            PathBuf::from("defs.svh"),
+            String::from("`ifdef D\n`include \"missing.svh\"\n`endif\n"),
+        );
+
+        let macros = conditional_macros("top.sv", &[] as &[String], &files).unwrap();
+        assert_eq!(macros, vec!["A", "B", "C", "D"]);
+    }
+
+    #[test]
+    fn test_define_combinations() {
+        let macros: Vec<_> = (0..3).map(|x| format!("M{}", x)).collect();
+        let combinations = define_combinations(&macros);
+        assert_eq!(combinations.len(), 8);
+        assert_eq!(
+            combinations[5],
+            vec![
+                (String::from("M0"), true),
+                (String::from("M1"), false),
+                (String::from("M2"), true)
+            ]
+        );
+
+        let n = 20;
+        let rows = pairwise(n);
+        assert!(rows.len() < 20);
+        for i in 0..n {
+            for j in i + 1..n {
+                for &x in &[false, true] {
+                    for &y in &[false, true] {
+                        assert!(rows.iter().any(|row| row[i] == x && row[j] == y));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::preprocess::PreprocessedText;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+/// Preprocessed text with `` `line `` markers
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Emitted {
+    pub text: String,
+    /// JSON source map of `text`
+    pub source_map: Option<String>,
+}
+
+/// Write preprocessed text with `` `line `` markers where the source location doesn't continue
+/// from the previous line
+///
+/// If `source_map` is true, a JSON source map is also written in the following format.
+/// Each mapping is `[begin, end, source, source_begin, source_end]`, which maps the byte range
+/// of `text` to the byte range of `sources[source]`.
+///
+/// ```json
+/// {"version":1,"sources":["top.sv"],"mappings":[[0,10,0,0,10]]}
+/// ```
+pub fn emit(text: &PreprocessedText, source_map: bool) -> Emitted {
+    let s = text.text();
+    let mut ret = String::new();
+    // positions of markers in `s` and the total length of the markers until them
+    let mut shifts = Vec::new();
+    let mut current: Option<(PathBuf, usize)> = None;
+
+    let mut pos = 0;
+    while pos < s.len() {
+        let end = s[pos..].find('\n').map(|x| pos + x + 1).unwrap_or(s.len());
+        // Whitespace is located after itself, so the first token of the line is used
+        let token = s[pos..end].find(|c: char| !c.is_whitespace());
+        if let Some(location) = token.and_then(|x| text.location(pos + x)) {
+            let continued = match current {
+                Some((ref path, line)) => path == &location.path && line == location.line,
+                None => false,
+            };
+            if !continued {
+                let marker = format!(
+                    "`line {} \"{}\" 0\n",
+                    location.line,
+                    location.path.to_string_lossy()
+                );
+                let total = shifts.last().map(|(_, x)| *x).unwrap_or(0);
+                shifts.push((pos, total + marker.len()));
+                ret.push_str(&marker);
+            }
+            current = Some((location.path, location.line));
+        }
+        ret.push_str(&s[pos..end]);
+        if let Some((_, ref mut line)) = current {
+            *line += 1;
+        }
+        pos = end;
+    }
+
+    let source_map = if source_map {
+        Some(write_source_map(text, &shifts))
+    } else {
+        None
+    };
+    Emitted {
+        text: ret,
+        source_map,
+    }
+}
+
+fn write_source_map(text: &PreprocessedText, shifts: &[(usize, usize)]) -> String {
+    // A range beginning at a marker begins after it, and a range ending at a marker ends before it
+    let shift = |pos: usize, inclusive: bool| {
+        let i = shifts.partition_point(|(x, _)| if inclusive { *x <= pos } else { *x < pos });
+        if i == 0 {
+            pos
+        } else {
+            pos + shifts[i - 1].1
+        }
+    };
+
+    let mut sources = Vec::new();
+    let mut indexes = HashMap::new();
+    let mut mappings = Vec::new();
+    for (range, path, origin) in text.origin_ranges() {
+        if range.begin == range.end {
+            continue;
+        }
+        let index = *indexes.entry(path).or_insert_with(|| {
+            sources.push(json_string(&path.to_string_lossy()));
+            sources.len() - 1
+        });
+        mappings.push(format!(
+            "[{},{},{},{},{}]",
+            shift(range.begin, true),
+            shift(range.end, false),
+            index,
+            origin.begin,
+            origin.end
+        ));
+    }
+
+    format!(
+        "{{\"version\":1,\"sources\":[{}],\"mappings\":[{}]}}",
+        sources.join(","),
+        mappings.join(",")
+    )
+}
+
+fn json_string(s: &str) -> String {
+    let mut ret = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => ret.push_str("\\\""),
+            '\\' => ret.push_str("\\\\"),
+            '\n' => ret.push_str("\\n"),
+            '\r' => ret.push_str("\\r"),
+            '\t' => ret.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(ret, "\\u{:04x}", c as u32).unwrap(),
+            c => ret.push(c),
+        }
+    }
+    ret.push('"');
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::preprocess::{preprocess_str, preprocess_with, PreprocessOptions};
+    use std::path::Path;
+
+    #[test]
+    fn test_emit() {
+        let mut files = HashMap::new();
+        files.insert(
+            PathBuf::from("top.sv"),
+            String::from("module a;\n`include \"b.svh\"\nendmodule\n"),
+        );
+        files.insert(PathBuf::from("b.svh"), String::from("wire b;\nwire c;\n"));
+
+        let options = PreprocessOptions::new().provider(&files);
+        let (text, _) =
+            preprocess_with("top.sv", &HashMap::new(), &[] as &[&Path], &options).unwrap();
+        let emitted = emit(&text, true);
+        assert_eq!(
+            emitted.text,
+            r##"`line 1 "top.sv" 0
+module a;
+`line 1 "b.svh" 0
+wire b;
+wire c;
+
+`line 3 "top.sv" 0
+endmodule
+"##
+        );
+        assert_eq!(
+            emitted.source_map.unwrap(),
+            r##"{"version":1,"sources":["top.sv","b.svh"],"mappings":[[19,29,0,0,10],[47,63,1,0,16],[63,64,0,27,27],[83,93,0,27,37]]}"##
+        );
+
+        // Locations in the emitted text follow the markers
+        let (text, _) = preprocess_str(
+            &emitted.text,
+            "top.i",
+            &HashMap::new(),
+            &[] as &[&Path],
+            false,
+            false,
+            0,
+        )
+        .unwrap();
+        let location = text.location(text.text().find("wire c").unwrap()).unwrap();
+        assert_eq!((location.path, location.line), (PathBuf::from("b.svh"), 2));
+        let location = text
+            .location(text.text().find("endmodule").unwrap())
+            .unwrap();
+        assert_eq!((location.path, location.line), (PathBuf::from("top.sv"), 3));
+    }
+}
@@ -1,5 +1,9 @@
 #![allow(clippy::type_complexity)]
 #![recursion_limit = "256"]
 
+pub mod configuration;
+pub mod emit;
+pub mod line_index;
 pub mod preprocess;
 pub mod range;
+pub mod source_provider;
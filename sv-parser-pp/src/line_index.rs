@@ -0,0 +1,126 @@
+use crate::range::Range;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LineIndex {
+    lines: Vec<usize>,
+    multibyte_chars: Vec<(usize, usize)>,
+}
+
+impl LineIndex {
+    pub fn new(s: &str) -> Self {
+        let mut lines = vec![0];
+        let mut multibyte_chars = Vec::new();
+        for (i, c) in s.char_indices() {
+            if c == '\n' {
+                lines.push(i + 1);
+            } else if c.len_utf8() > 1 {
+                multibyte_chars.push((i, c.len_utf8()));
+            }
+        }
+        LineIndex {
+            lines,
+            multibyte_chars,
+        }
+    }
+
+    /// Update the index after replacing the specified range of the source with `s`
+    pub fn edit(&mut self, range: Range, s: &str) {
+        let delta = s.len() as isize - (range.end - range.begin) as isize;
+        let shift = |x: usize| (x as isize + delta) as usize;
+        let inserted = LineIndex::new(s);
+
+        let lines = self.lines.iter().filter(|x| **x <= range.begin).copied();
+        let new_lines = inserted.lines[1..].iter().map(|x| range.begin + x);
+        let rest = self
+            .lines
+            .iter()
+            .filter(|x| **x > range.end)
+            .map(|x| shift(*x));
+        self.lines = lines.chain(new_lines).chain(rest).collect();
+
+        let chars = self
+            .multibyte_chars
+            .iter()
+            .filter(|(x, _)| *x < range.begin);
+        let new_chars = inserted
+            .multibyte_chars
+            .iter()
+            .map(|(x, len)| (range.begin + x, *len));
+        let rest = self
+            .multibyte_chars
+            .iter()
+            .filter(|(x, _)| *x >= range.end)
+            .map(|(x, len)| (shift(*x), *len));
+        self.multibyte_chars = chars.copied().chain(new_chars).chain(rest).collect();
+    }
+
+    /// Get 1-based line and column of the specified byte offset.
+    /// The column is counted by characters.
+    pub fn line_column(&self, offset: usize) -> (usize, usize) {
+        let line = match self.lines.binary_search(&offset) {
+            Ok(x) => x,
+            Err(x) => x - 1,
+        };
+        let line_begin = self.lines[line];
+
+        let begin = self
+            .multibyte_chars
+            .partition_point(|(x, _)| *x < line_begin);
+        let end = self.multibyte_chars.partition_point(|(x, _)| *x < offset);
+        let extra_bytes: usize = self.multibyte_chars[begin..end]
+            .iter()
+            .map(|(_, len)| len - 1)
+            .sum();
+
+        (line + 1, offset - line_begin - extra_bytes + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_column() {
+        let index = LineIndex::new("module a;\nendmodule\n\nx");
+        assert_eq!(index.line_column(0), (1, 1));
+        assert_eq!(index.line_column(7), (1, 8));
+        assert_eq!(index.line_column(9), (1, 10));
+        assert_eq!(index.line_column(10), (2, 1));
+        assert_eq!(index.line_column(20), (3, 1));
+        assert_eq!(index.line_column(21), (4, 1));
+    }
+
+    #[test]
+    fn test_line_column_utf8() {
+        // 'あ' is 3 bytes, 'é' is 2 bytes
+        let index = LineIndex::new("// あé\nwire a; // あ\nx");
+        assert_eq!(index.line_column(3), (1, 4));
+        assert_eq!(index.line_column(6), (1, 5));
+        assert_eq!(index.line_column(8), (1, 6));
+        assert_eq!(index.line_column(9), (2, 1));
+        assert_eq!(index.line_column(14), (2, 6));
+        assert_eq!(index.line_column(20), (2, 12));
+        assert_eq!(index.line_column(23), (2, 13));
+        assert_eq!(index.line_column(24), (3, 1));
+    }
+
+    #[test]
+    fn test_edit() {
+        let before = "module a;\nwire b;\nendmodule // あ\n";
+        let mut index = LineIndex::new(before);
+        index.edit(Range::new(10, 17), "wire é;\nwire c;");
+        let after = "module a;\nwire é;\nwire c;\nendmodule // あ\n";
+        assert_eq!(index, LineIndex::new(after));
+
+        let mut index = LineIndex::new(after);
+        index.edit(Range::new(9, 26), "");
+        assert_eq!(
+            index,
+            LineIndex::new(before.replace("\nwire b;", "").as_str())
+        );
+    }
+}
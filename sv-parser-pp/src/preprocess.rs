@@ -1,32 +1,190 @@
+use crate::line_index::LineIndex;
 use crate::range::Range;
+use crate::source_provider::{FileSystem, SourceProvider};
 use nom::combinator::all_consuming;
-use nom_greedyerror::error_position;
+use nom::error::ErrorKind;
+use nom_greedyerror::{error_position, GreedyError};
 use std::collections::{BTreeMap, HashMap};
 use std::convert::TryInto;
-use std::fs::File;
+use std::fmt;
 use std::hash::BuildHasher;
-use std::io::{BufReader, Read};
 use std::path::{Path, PathBuf};
-use sv_parser_error::Error;
-use sv_parser_parser::{pp_parser, Span, SpanInfo};
+use sv_parser_error::{Error, ParseDiagnostic};
+use sv_parser_parser::{error_context, pp_parser, ParserContext, Span, SpanInfo};
 use sv_parser_syntaxtree::{
-    IncludeCompilerDirective, Locate, NodeEvent, RefNode, SourceDescription, TextMacroUsage,
-    WhiteSpace,
+    CompilerDirective, ConditionalCompilerDirective, ElseGroupOfLines, ElsifGroupOfLines,
+    IfdefCondition, IfdefMacroExpression, IncludeCompilerDirective, Keyword, Locate, NodeEvent,
+    PreprocessorText, RefNode, SourceDescription, Symbol, TextMacroUsage, WhiteSpace,
 };
 use std::collections::hash_map::RandomState;
 
-const RECURSIVE_LIMIT: usize = 64;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+/// The default limit of nested macro usages
+pub const RECURSIVE_LIMIT: usize = 64;
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PreprocessedText {
     text: String,
+    #[cfg_attr(feature = "serde", serde(with = "serde_origins"))]
     origins: BTreeMap<Range, Origin>,
+    line_indexes: BTreeMap<PathBuf, LineIndex>,
+    line_directives: BTreeMap<PathBuf, Vec<LineDirective>>,
+    dependencies: Vec<Dependency>,
+    skipped_includes: Vec<SkippedInclude>,
+    inactive_regions: Vec<InactiveRegion>,
+}
+
+/// Location in a source file
+///
+/// `path` and `line` follow `` `line `` directives. `column` and `byte_offset` are always
+/// physical because `` `line `` doesn't change them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SourceLocation {
+    pub path: PathBuf,
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column number counted by characters
+    pub column: usize,
+    pub byte_offset: usize,
+    /// Physical path and line if a `` `line `` directive changed `path` and `line`
+    pub physical: Option<(PathBuf, usize)>,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}",
+            self.path.to_string_lossy(),
+            self.line,
+            self.column
+        )
+    }
+}
+
+/// Macro expansion or file inclusion which produced a text
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Expansion {
+    Macro {
+        name: String,
+        definition: Option<SourceLocation>,
+        call_site: Option<SourceLocation>,
+    },
+    Include {
+        include_site: Option<SourceLocation>,
+    },
+}
+
+impl fmt::Display for Expansion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expansion::Macro {
+                name, call_site, ..
+            } => {
+                write!(f, "in expansion of macro `{}`", name)?;
+                if let Some(x) = call_site {
+                    write!(f, " at {}", x)?;
+                }
+                Ok(())
+            }
+            Expansion::Include { include_site } => {
+                write!(f, "included")?;
+                if let Some(x) = include_site {
+                    write!(f, " from {}", x)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Source file read by the preprocessor
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Dependency {
+    pub path: PathBuf,
+    /// Location of the first `` `include `` of the file, or `None` for the preprocessed file
+    pub include_site: Option<SourceLocation>,
+    /// Macros defined by `` `define `` in the file
+    pub defined: Vec<String>,
+    /// Macros removed by `` `undef `` or `` `undefineall `` in the file
+    pub undefined: Vec<String>,
+}
+
+/// `` `include `` which wasn't read because it is in an inactive `` `ifdef `` branch
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SkippedInclude {
+    pub path: PathBuf,
+    pub include_site: Option<SourceLocation>,
+}
+
+/// Block of `` `ifdef `` or `` `ifndef `` which was dropped because its condition was false
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InactiveRegion {
+    pub path: PathBuf,
+    /// Byte range of the block in the source file
+    pub range: Range,
+    /// Directive controlling the block: `ifdef`, `ifndef`, `elsif` or `else`
+    pub directive: String,
+    /// Condition of the directive as written, or `None` for `else`
+    pub condition: Option<String>,
+    pub begin: SourceLocation,
+    pub end: SourceLocation,
+}
+
+// Directive, condition and byte range of a block of `` `ifdef `` or `` `ifndef ``
+struct ConditionalBlock {
+    directive: String,
+    condition: Option<String>,
+    range: Range,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Origin {
     range: Range,
     origin: Option<(PathBuf, Range)>,
+    // from the innermost to the outermost
+    expansions: Vec<Frame>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+enum Frame {
+    Macro {
+        name: String,
+        definition: Option<(PathBuf, usize)>,
+        call_site: Option<(PathBuf, usize)>,
+    },
+    Include {
+        include_site: Option<(PathBuf, usize)>,
+    },
+}
+
+// Lines after `offset` of the physical file are `presumed_line` and later of `presumed_path`
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct LineDirective {
+    offset: usize,
+    line: usize,
+    presumed_path: PathBuf,
+    presumed_line: usize,
+}
+
+impl Frame {
+    fn site_mut(&mut self) -> &mut Option<(PathBuf, usize)> {
+        match self {
+            Frame::Macro { call_site, .. } => call_site,
+            Frame::Include { include_site } => include_site,
+        }
+    }
 }
 
 impl PreprocessedText {
@@ -34,7 +192,86 @@ impl PreprocessedText {
         PreprocessedText {
             text: String::new(),
             origins: BTreeMap::new(),
+            line_indexes: BTreeMap::new(),
+            line_directives: BTreeMap::new(),
+            dependencies: Vec::new(),
+            skipped_includes: Vec::new(),
+            inactive_regions: Vec::new(),
+        }
+    }
+
+    fn add_inactive_region<T: AsRef<Path>>(&mut self, path: T, block: &ConditionalBlock) {
+        let path = path.as_ref();
+        let begin = self.resolve(path, block.range.begin);
+        let end = self.resolve(path, block.range.end);
+        if let (Some(begin), Some(end)) = (begin, end) {
+            self.inactive_regions.push(InactiveRegion {
+                path: PathBuf::from(path),
+                range: block.range,
+                directive: block.directive.clone(),
+                condition: block.condition.clone(),
+                begin,
+                end,
+            });
+        }
+    }
+
+    fn dependency_mut<T: AsRef<Path>>(&mut self, path: T) -> &mut Dependency {
+        let path = path.as_ref();
+        let i = match self.dependencies.iter().position(|x| x.path == path) {
+            Some(i) => i,
+            None => {
+                self.dependencies.push(Dependency {
+                    path: PathBuf::from(path),
+                    include_site: None,
+                    defined: Vec::new(),
+                    undefined: Vec::new(),
+                });
+                self.dependencies.len() - 1
+            }
+        };
+        &mut self.dependencies[i]
+    }
+
+    fn merge_dependencies(&mut self, other: Vec<Dependency>, skipped: Vec<SkippedInclude>) {
+        for x in other {
+            let dependency = self.dependency_mut(&x.path);
+            if dependency.include_site.is_none() {
+                dependency.include_site = x.include_site;
+            }
+            dependency.defined.extend(x.defined);
+            dependency.undefined.extend(x.undefined);
         }
+        self.skipped_includes.extend(skipped);
+    }
+
+    fn add_source<T: AsRef<Path>>(&mut self, path: T, s: &str) {
+        self.line_indexes
+            .insert(PathBuf::from(path.as_ref()), LineIndex::new(s));
+    }
+
+    // `offset` is the beginning of the line following the directive
+    fn add_line_directive<T: AsRef<Path>>(
+        &mut self,
+        path: T,
+        offset: usize,
+        presumed_path: PathBuf,
+        presumed_line: usize,
+    ) {
+        let path = path.as_ref();
+        let line = match self.line_indexes.get(path) {
+            Some(x) => x.line_column(offset).0,
+            None => return,
+        };
+        self.line_directives
+            .entry(PathBuf::from(path))
+            .or_default()
+            .push(LineDirective {
+                offset,
+                line,
+                presumed_path,
+                presumed_line,
+            });
     }
 
     fn push<T: AsRef<Path>>(&mut self, s: &str, origin: Option<(T, Range)>) {
@@ -49,16 +286,88 @@ impl PreprocessedText {
         };
 
         let range = Range::new(base, base + s.len());
-        let origin = Origin { range, origin };
+        let origin = Origin {
+            range,
+            origin,
+            expansions: Vec::new(),
+        };
         self.origins.insert(range, origin);
     }
 
-    fn merge(&mut self, other: PreprocessedText) {
+    fn merge<T: AsRef<Path>>(&mut self, other: PreprocessedText, include_site: (T, usize)) {
+        let base = self.text.len();
+        self.text.push_str(&other.text);
+        let include_site = Some((PathBuf::from(include_site.0.as_ref()), include_site.1));
+        for (mut range, mut origin) in other.origins {
+            range.offset(base);
+            origin.range.offset(base);
+            origin.expansions.push(Frame::Include {
+                include_site: include_site.clone(),
+            });
+            self.origins.insert(range, origin);
+        }
+        self.line_indexes.extend(other.line_indexes);
+        // A file included again may have other `line in its active branches
+        for (path, directives) in other.line_directives {
+            let x = self.line_directives.entry(path).or_default();
+            x.extend(directives);
+            x.sort_by_key(|x| x.offset);
+            x.dedup();
+        }
+        self.merge_dependencies(other.dependencies, other.skipped_includes);
+        self.inactive_regions.extend(other.inactive_regions);
+    }
+
+    // `other` is the result of preprocessing the body of the macro,
+    // so its positions are relative to the body
+    fn merge_macro<T: AsRef<Path>>(
+        &mut self,
+        other: PreprocessedText,
+        name: &str,
+        definition: Option<(PathBuf, Range)>,
+        call_site: (T, usize),
+    ) {
         let base = self.text.len();
         self.text.push_str(&other.text);
+        self.merge_dependencies(other.dependencies, other.skipped_includes);
+        // The body is indexed under the path of the call site,
+        // so only files included by the body are taken
+        let call_path = call_site.0.as_ref();
+        for (path, index) in other.line_indexes {
+            self.line_indexes.entry(path).or_insert(index);
+        }
+        for (path, directives) in other.line_directives {
+            if path == call_path {
+                continue;
+            }
+            let x = self.line_directives.entry(path).or_default();
+            x.extend(directives);
+            x.sort_by_key(|x| x.offset);
+            x.dedup();
+        }
+        let to_definition = |pos: usize| {
+            definition.as_ref().map(|(path, body)| {
+                let max = body.end.saturating_sub(1).max(body.begin);
+                (path.clone(), (body.begin + pos).min(max))
+            })
+        };
         for (mut range, mut origin) in other.origins {
             range.offset(base);
             origin.range.offset(base);
+            if let Some(frame) = origin.expansions.last_mut() {
+                let site = frame.site_mut();
+                *site = site.as_ref().and_then(|(_, pos)| to_definition(*pos));
+            } else if let Some((_, ref x)) = origin.origin {
+                origin.origin = to_definition(x.begin).map(|(path, begin)| {
+                    let (_, end) = to_definition(x.end).unwrap();
+                    (path, Range::new(begin, end.max(begin)))
+                });
+            }
+            origin.expansions.push(Frame::Macro {
+                name: String::from(name),
+                definition: to_definition(0),
+                call_site: Some((PathBuf::from(call_site.0.as_ref()), call_site.1)),
+            });
             self.origins.insert(range, origin);
         }
     }
@@ -67,6 +376,179 @@ impl PreprocessedText {
         &self.text
     }
 
+    /// Files read by the preprocessor in the order of their first inclusion
+    pub fn dependencies(&self) -> &[Dependency] {
+        &self.dependencies
+    }
+
+    /// `` `include `` in inactive `` `ifdef `` branches
+    pub fn skipped_includes(&self) -> &[SkippedInclude] {
+        &self.skipped_includes
+    }
+
+    /// Blocks of `` `ifdef `` and `` `ifndef `` dropped in source files
+    pub fn inactive_regions(&self) -> &[InactiveRegion] {
+        &self.inactive_regions
+    }
+
+    // Ranges of the text which come from source files, and their ranges in the files
+    pub(crate) fn origin_ranges(&self) -> impl Iterator<Item = (Range, &PathBuf, Range)> {
+        self.origins.values().filter_map(|x| {
+            x.origin
+                .as_ref()
+                .map(|(path, range)| (x.range, path, *range))
+        })
+    }
+
+    /// Replace the specified byte range of the source file `path` with `s`,
+    /// and return the replaced range of the preprocessed text
+    ///
+    /// `None` is returned without any change if the edit may change the result of the
+    /// preprocessor. The range must be in source text copied verbatim, and the replaced and
+    /// inserted text must not contain directives, macros, string literals or comments.
+    pub fn edit_source<T: AsRef<Path>>(&mut self, path: T, range: Range, s: &str) -> Option<Range> {
+        let path = path.as_ref();
+        let in_range = |x: &Option<(PathBuf, Range)>| match x {
+            Some((p, r)) => p == path && r.begin <= range.end && range.begin <= r.end,
+            None => false,
+        };
+        let chunks: Vec<Range> = self
+            .origins
+            .values()
+            .filter(|x| in_range(&x.origin) && x.range.begin < x.range.end)
+            .map(|x| x.range)
+            .collect();
+
+        let first = self.origins.get(chunks.first()?)?;
+        let last = self.origins.get(chunks.last()?)?;
+        let source_begin = first.origin.as_ref()?.1.begin;
+        let source_end = last.origin.as_ref()?.1.end;
+        if source_begin >= range.begin || range.end >= source_end {
+            return None;
+        }
+        let mut expected = (chunks[0].begin, source_begin);
+        for x in &chunks {
+            let origin = &self.origins.get(x)?;
+            let (_, ref r) = origin.origin.as_ref()?;
+            let verbatim = r.end - r.begin == x.end - x.begin;
+            if !origin.expansions.is_empty() || !verbatim || (x.begin, r.begin) != expected {
+                return None;
+            }
+            expected = (x.end, r.end);
+        }
+        let pp_range = Range::new(
+            chunks[0].begin + range.begin - source_begin,
+            chunks[0].begin + range.end - source_begin,
+        );
+
+        if !self.text.is_char_boundary(pp_range.begin) || !self.text.is_char_boundary(pp_range.end)
+        {
+            return None;
+        }
+        let old = &self.text[pp_range.begin..pp_range.end];
+        let before = self.text[..pp_range.begin].chars().next_back();
+        let after = self.text[pp_range.end..].chars().next();
+        let special = |c: char| "`\"\\/*".contains(c);
+        let special_around = before.into_iter().chain(after).any(special);
+        if old.contains(special) || s.contains(special) || special_around {
+            return None;
+        }
+        // A newline may end a line comment or a string literal
+        let line_begin = self.text[..pp_range.begin].rfind('\n').map_or(0, |x| x + 1);
+        let newline = old.contains('\n') || s.contains('\n');
+        if newline && self.text[line_begin..pp_range.begin].contains(special) {
+            return None;
+        }
+
+        let delta = s.len() as isize - old.len() as isize;
+        let line_delta = s.matches('\n').count() as isize - old.matches('\n').count() as isize;
+        let shift = |x: usize| (x as isize + delta) as usize;
+        let shift_site = |x: &mut Option<(PathBuf, usize)>| {
+            if let Some((ref p, ref mut pos)) = x {
+                if p == path && *pos >= range.end {
+                    *pos = shift(*pos);
+                }
+            }
+        };
+
+        self.text.replace_range(pp_range.begin..pp_range.end, s);
+
+        let mut origins = BTreeMap::new();
+        let merged = Range::new(chunks[0].begin, shift(expected.0));
+        origins.insert(
+            merged,
+            Origin {
+                range: merged,
+                origin: Some((
+                    PathBuf::from(path),
+                    Range::new(source_begin, shift(source_end)),
+                )),
+                expansions: Vec::new(),
+            },
+        );
+        for (_, mut origin) in std::mem::take(&mut self.origins) {
+            if chunks.contains(&origin.range) && origin.range.begin < origin.range.end {
+                continue;
+            }
+            if origin.range.begin >= pp_range.end {
+                origin.range = Range::new(shift(origin.range.begin), shift(origin.range.end));
+            }
+            if let Some((ref p, ref mut r)) = origin.origin {
+                if p == path && r.begin >= range.end {
+                    *r = Range::new(shift(r.begin), shift(r.end));
+                }
+            }
+            for frame in &mut origin.expansions {
+                if let Frame::Macro { definition, .. } = frame {
+                    shift_site(definition);
+                }
+                shift_site(frame.site_mut());
+            }
+            origins.insert(origin.range, origin);
+        }
+        self.origins = origins;
+
+        if let Some(x) = self.line_indexes.get_mut(path) {
+            x.edit(range, s);
+        }
+        for x in self.line_directives.get_mut(path).into_iter().flatten() {
+            if x.offset >= range.end {
+                x.offset = shift(x.offset);
+                x.line = (x.line as isize + line_delta) as usize;
+            }
+        }
+
+        let relocate = |this: &Self, x: &SourceLocation| {
+            let physical = x.physical.as_ref().map_or(&x.path, |(p, _)| p);
+            if physical == path && x.byte_offset >= range.end {
+                this.resolve(path, shift(x.byte_offset))
+            } else {
+                Some(x.clone())
+            }
+        };
+        let mut dependencies = std::mem::take(&mut self.dependencies);
+        for x in &mut dependencies {
+            x.include_site = x.include_site.as_ref().and_then(|x| relocate(self, x));
+        }
+        self.dependencies = dependencies;
+        let mut skipped_includes = std::mem::take(&mut self.skipped_includes);
+        for x in &mut skipped_includes {
+            x.include_site = x.include_site.as_ref().and_then(|x| relocate(self, x));
+        }
+        self.skipped_includes = skipped_includes;
+        let mut inactive_regions = std::mem::take(&mut self.inactive_regions);
+        for x in &mut inactive_regions {
+            if x.path == path && x.range.begin >= range.end {
+                x.range = Range::new(shift(x.range.begin), shift(x.range.end));
+                x.begin = relocate(self, &x.begin).unwrap_or_else(|| x.begin.clone());
+                x.end = relocate(self, &x.end).unwrap_or_else(|| x.end.clone());
+            }
+        }
+        self.inactive_regions = inactive_regions;
+
+        Some(pp_range)
+    }
+
     pub fn origin(&self, pos: usize) -> Option<(&PathBuf, usize)> {
         let origin = self.origins.get(&Range::new(pos, pos + 1));
         if let Some(origin) = origin {
@@ -80,6 +562,179 @@ impl PreprocessedText {
             None
         }
     }
+
+    // Expanded text of a macro may be longer than its definition,
+    // so the position is clamped into the definition to get a location in it
+    fn clamped_origin(&self, pos: usize) -> Option<(&PathBuf, usize)> {
+        let origin = self.origins.get(&Range::new(pos, pos + 1))?;
+        let (_, ref origin_range) = origin.origin.as_ref()?;
+        let (path, ret_pos) = self.origin(pos)?;
+        if origin_range.begin < origin_range.end {
+            Some((path, ret_pos.min(origin_range.end - 1)))
+        } else {
+            Some((path, ret_pos))
+        }
+    }
+
+    /// Get the location in the source file of the specified position
+    pub fn location(&self, pos: usize) -> Option<SourceLocation> {
+        let (path, byte_offset) = self.clamped_origin(pos)?;
+        self.resolve(path, byte_offset)
+    }
+
+    /// Get the location in the source file of the specified position ignoring `` `line ``
+    pub fn physical_location(&self, pos: usize) -> Option<SourceLocation> {
+        let (path, byte_offset) = self.clamped_origin(pos)?;
+        self.resolve_physical(path, byte_offset)
+    }
+
+    /// Get macro expansions and file inclusions which produced the specified position
+    /// from the innermost to the outermost
+    pub fn expansions(&self, pos: usize) -> Vec<Expansion> {
+        let origin = match self.origins.get(&Range::new(pos, pos + 1)) {
+            Some(x) => x,
+            None => return Vec::new(),
+        };
+        let resolve = |x: &Option<(PathBuf, usize)>| {
+            x.as_ref()
+                .and_then(|(path, offset)| self.resolve(path, *offset))
+        };
+        origin
+            .expansions
+            .iter()
+            .map(|x| match x {
+                Frame::Macro {
+                    name,
+                    definition,
+                    call_site,
+                } => Expansion::Macro {
+                    name: name.clone(),
+                    definition: resolve(definition),
+                    call_site: resolve(call_site),
+                },
+                Frame::Include { include_site } => Expansion::Include {
+                    include_site: resolve(include_site),
+                },
+            })
+            .collect()
+    }
+
+    fn resolve(&self, path: &Path, byte_offset: usize) -> Option<SourceLocation> {
+        let mut ret = self.resolve_physical(path, byte_offset)?;
+        let directive = self
+            .line_directives
+            .get(path)
+            .and_then(|x| x.iter().take_while(|x| x.offset <= byte_offset).last());
+        if let Some(x) = directive {
+            let physical = (ret.path, ret.line);
+            ret.path = x.presumed_path.clone();
+            ret.line = x.presumed_line + (physical.1 - x.line);
+            ret.physical = Some(physical);
+        }
+        Some(ret)
+    }
+
+    fn resolve_physical(&self, path: &Path, byte_offset: usize) -> Option<SourceLocation> {
+        let (line, column) = self.line_indexes.get(path)?.line_column(byte_offset);
+        Some(SourceLocation {
+            path: PathBuf::from(path),
+            line,
+            column,
+            byte_offset,
+            physical: None,
+        })
+    }
+
+    /// Convert the error of a parser which consumed this text to `Error::Parse`
+    pub fn parse_error(&self, x: nom::Err<GreedyError<Span, ErrorKind>>) -> Error {
+        let e = match x {
+            nom::Err::Incomplete(_) => return Error::Parse(None),
+            nom::Err::Error(e) => e,
+            nom::Err::Failure(e) => e,
+        };
+        let pos = match error_position(&e) {
+            Some(x) => x,
+            None => return Error::Parse(None),
+        };
+
+        let (expected_pos, expected) = match e.errors.first() {
+            Some((s, _)) => s.extra.context.expected_tokens(),
+            None => (0, vec![]),
+        };
+        let expected = if expected_pos == pos {
+            expected
+        } else {
+            vec![]
+        };
+
+        Error::Parse(Some(Box::new(self.diagnostic(
+            pos,
+            error_context(&e),
+            expected,
+        ))))
+    }
+
+    /// Build `ParseDiagnostic` of the specified position
+    pub fn diagnostic(
+        &self,
+        pos: usize,
+        context: Vec<String>,
+        expected: Vec<&str>,
+    ) -> ParseDiagnostic {
+        let (path, offset, line, column, physical) = match self.location(pos) {
+            Some(x) => (Some(x.path), x.byte_offset, x.line, x.column, x.physical),
+            None => {
+                let (line, column) = LineIndex::new(&self.text).line_column(pos);
+                (None, pos, line, column, None)
+            }
+        };
+
+        ParseDiagnostic {
+            path,
+            offset,
+            line,
+            column,
+            physical,
+            context,
+            expected: expected.into_iter().map(String::from).collect(),
+            found: found_token(&self.text[pos..]),
+        }
+    }
+}
+
+// Range of BTreeMap key can't be a key of JSON object
+#[cfg(feature = "serde")]
+mod serde_origins {
+    use super::*;
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        origins: &BTreeMap<Range, Origin>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(origins.values())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BTreeMap<Range, Origin>, D::Error> {
+        let origins = Vec::<Origin>::deserialize(deserializer)?;
+        Ok(origins.into_iter().map(|x| (x.range, x)).collect())
+    }
+}
+
+fn found_token(s: &str) -> Option<String> {
+    let mut chars = s.char_indices();
+    let (_, c) = chars.next()?;
+    let is_word = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+    if is_word(c) {
+        let end = chars
+            .find(|(_, c)| !is_word(*c))
+            .map_or(s.len(), |(i, _)| i);
+        Some(String::from(&s[..end]))
+    } else {
+        Some(c.to_string())
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -117,6 +772,77 @@ impl DefineText {
 
 pub type Defines<V=RandomState> = HashMap<String, Option<Define>, V>;
 
+/// Options of the preprocessor other than the predefined macros and the include paths
+#[derive(Clone)]
+pub struct PreprocessOptions<'a> {
+    strip_comments: bool,
+    ignore_include: bool,
+    skip_guarded_include: bool,
+    recursive_limit: usize,
+    provider: &'a dyn SourceProvider,
+}
+
+impl<'a> PreprocessOptions<'a> {
+    pub fn new() -> Self {
+        PreprocessOptions {
+            strip_comments: false,
+            ignore_include: false,
+            skip_guarded_include: false,
+            recursive_limit: RECURSIVE_LIMIT,
+            provider: &FileSystem,
+        }
+    }
+
+    /// Remove comments from the preprocessed text
+    pub fn strip_comments(mut self, strip_comments: bool) -> Self {
+        self.strip_comments = strip_comments;
+        self
+    }
+
+    /// Leave `` `include `` without reading the included files
+    pub fn ignore_include(mut self, ignore_include: bool) -> Self {
+        self.ignore_include = ignore_include;
+        self
+    }
+
+    /// Skip a file included again if its whole content is guarded by `` `ifndef X `define X ``
+    /// and `X` is still defined
+    pub fn skip_guarded_include(mut self, skip_guarded_include: bool) -> Self {
+        self.skip_guarded_include = skip_guarded_include;
+        self
+    }
+
+    /// Maximum nesting depth of macro usages
+    pub fn recursive_limit(mut self, recursive_limit: usize) -> Self {
+        self.recursive_limit = recursive_limit;
+        self
+    }
+
+    /// Source of the file and the included files
+    pub fn provider(mut self, provider: &'a dyn SourceProvider) -> Self {
+        self.provider = provider;
+        self
+    }
+}
+
+impl<'a> Default for PreprocessOptions<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> fmt::Debug for PreprocessOptions<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PreprocessOptions")
+            .field("strip_comments", &self.strip_comments)
+            .field("ignore_include", &self.ignore_include)
+            .field("skip_guarded_include", &self.skip_guarded_include)
+            .field("recursive_limit", &self.recursive_limit)
+            .finish()
+    }
+}
+
+/// Preprocess the specified file
 pub fn preprocess<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
     path: T,
     pre_defines: &Defines<V>,
@@ -124,15 +850,50 @@ pub fn preprocess<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
     strip_comments: bool,
     ignore_include: bool,
 ) -> Result<(PreprocessedText, Defines), Error> {
-    let f = File::open(path.as_ref()).map_err(|x| Error::File {
-        source: x,
-        path: PathBuf::from(path.as_ref()),
-    })?;
-    let mut reader = BufReader::new(f);
-    let mut s = String::new();
-    reader.read_to_string(&mut s)?;
+    let options = PreprocessOptions::new()
+        .strip_comments(strip_comments)
+        .ignore_include(ignore_include);
+    preprocess_with(path, pre_defines, include_paths, &options)
+}
+
+/// Preprocess the specified file with `PreprocessOptions`
+pub fn preprocess_with<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
+    path: T,
+    pre_defines: &Defines<V>,
+    include_paths: &[U],
+    options: &PreprocessOptions<'_>,
+) -> Result<(PreprocessedText, Defines), Error> {
+    let mut state = IncludeState::new(options);
+    preprocess_file(
+        path,
+        pre_defines,
+        include_paths,
+        options.strip_comments,
+        options.ignore_include,
+        &mut state,
+    )
+}
 
-    preprocess_str(
+fn preprocess_file<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
+    path: T,
+    pre_defines: &Defines<V>,
+    include_paths: &[U],
+    strip_comments: bool,
+    ignore_include: bool,
+    state: &mut IncludeState<'_>,
+) -> Result<(PreprocessedText, Defines), Error> {
+    let s = state
+        .provider
+        .read(path.as_ref())
+        .map_err(|x| Error::File {
+            source: x,
+            path: PathBuf::from(path.as_ref()),
+        })?;
+
+    state
+        .include_stack
+        .push(state.provider.canonicalize(path.as_ref()));
+    let ret = preprocess_str_impl(
         &s,
         path,
         pre_defines,
@@ -140,7 +901,46 @@ pub fn preprocess<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
         ignore_include,
         strip_comments,
         0,
-    )
+        None,
+        state,
+    );
+    state.include_stack.pop();
+    ret
+}
+
+// State shared by the nested preprocessing of included files
+struct IncludeState<'a> {
+    // canonical paths of the files being preprocessed from the outermost
+    include_stack: Vec<PathBuf>,
+    // macro names of the include guards of the preprocessed files
+    include_guards: HashMap<PathBuf, String>,
+    skip_guarded_include: bool,
+    recursive_limit: usize,
+    provider: &'a dyn SourceProvider,
+}
+
+impl<'a> IncludeState<'a> {
+    fn new(options: &PreprocessOptions<'a>) -> Self {
+        IncludeState {
+            include_stack: Vec::new(),
+            include_guards: HashMap::new(),
+            skip_guarded_include: options.skip_guarded_include,
+            recursive_limit: options.recursive_limit,
+            provider: options.provider,
+        }
+    }
+}
+
+pub(crate) fn parse_pp_text<T: AsRef<Path>>(s: &str, path: T) -> Result<PreprocessorText, Error> {
+    let context = ParserContext::new();
+    let span = Span::new_extra(s, SpanInfo::new(&context));
+    let (_, pp_text) = all_consuming(pp_parser)(span).map_err(|x| {
+        let mut text = PreprocessedText::new();
+        text.add_source(path.as_ref(), s);
+        text.push(s, Some((path.as_ref(), Range::new(0, s.len()))));
+        text.parse_error(x)
+    })?;
+    Ok(pp_text)
 }
 
 struct SkipNodes<'a> {
@@ -179,6 +979,61 @@ pub fn preprocess_str<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
     ignore_include: bool,
     strip_comments: bool,
     resolve_depth: usize,
+) -> Result<(PreprocessedText, Defines), Error> {
+    let mut state = IncludeState::new(&PreprocessOptions::new());
+    state
+        .include_stack
+        .push(state.provider.canonicalize(path.as_ref()));
+    preprocess_str_impl(
+        s,
+        path,
+        pre_defines,
+        include_paths,
+        ignore_include,
+        strip_comments,
+        resolve_depth,
+        None,
+        &mut state,
+    )
+}
+
+/// Preprocess the specified string as the content of `path` with `PreprocessOptions`
+pub fn preprocess_str_with<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
+    s: &str,
+    path: T,
+    pre_defines: &Defines<V>,
+    include_paths: &[U],
+    options: &PreprocessOptions<'_>,
+) -> Result<(PreprocessedText, Defines), Error> {
+    let mut state = IncludeState::new(options);
+    state
+        .include_stack
+        .push(state.provider.canonicalize(path.as_ref()));
+    preprocess_str_impl(
+        s,
+        path,
+        pre_defines,
+        include_paths,
+        options.ignore_include,
+        options.strip_comments,
+        0,
+        None,
+        &mut state,
+    )
+}
+
+// `expansion_site` is the path and line of the outermost macro usage if `s` is a macro body
+#[allow(clippy::too_many_arguments)]
+fn preprocess_str_impl<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
+    s: &str,
+    path: T,
+    pre_defines: &Defines<V>,
+    include_paths: &[U],
+    ignore_include: bool,
+    strip_comments: bool,
+    resolve_depth: usize,
+    expansion_site: Option<&(PathBuf, usize)>,
+    state: &mut IncludeState<'_>,
 ) -> Result<(PreprocessedText, Defines), Error> {
     let mut skip = false;
     let mut skip_nodes = SkipNodes::new();
@@ -191,26 +1046,30 @@ pub fn preprocess_str<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
         defines.insert(k.clone(), (*v).clone());
     }
 
-    let span = Span::new_extra(&s, SpanInfo::default());
-    let (_, pp_text) = all_consuming(pp_parser)(span).map_err(|x| match x {
-        nom::Err::Incomplete(_) => Error::Parse(None),
-        nom::Err::Error(e) => {
-            if let Some(pos) = error_position(&e) {
-                Error::Parse(Some((PathBuf::from(path.as_ref()), pos)))
-            } else {
-                Error::Parse(None)
-            }
-        }
-        nom::Err::Failure(e) => {
-            if let Some(pos) = error_position(&e) {
-                Error::Parse(Some((PathBuf::from(path.as_ref()), pos)))
-            } else {
-                Error::Parse(None)
-            }
+    let mut ret = PreprocessedText::new();
+    ret.add_source(path.as_ref(), s);
+    if expansion_site.is_none() {
+        ret.dependency_mut(path.as_ref());
+    }
+
+    // `__FILE__` and `__LINE__` in macro bodies are the location of the macro usage
+    let site = |ret: &PreprocessedText, offset: usize| match expansion_site {
+        Some(x) => x.clone(),
+        None => {
+            let x = ret.resolve(path.as_ref(), offset).unwrap();
+            (x.path, x.line)
         }
-    })?;
+    };
 
-    let mut ret = PreprocessedText::new();
+    let pp_text = parse_pp_text(s, path.as_ref())?;
+
+    if expansion_site.is_none() {
+        if let Some(x) = include_guard(&pp_text, s) {
+            state
+                .include_guards
+                .insert(state.provider.canonicalize(path.as_ref()), x);
+        }
+    }
 
     for n in pp_text.into_iter().event() {
         match n.clone() {
@@ -226,6 +1085,39 @@ pub fn preprocess_str<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
             }
         }
         if skip {
+            if let NodeEvent::Enter(RefNode::IncludeCompilerDirective(x)) = n {
+                let include = match x {
+                    IncludeCompilerDirective::DoubleQuote(x) => {
+                        Some(x.nodes.2.nodes.0.str(s).trim_matches('"'))
+                    }
+                    IncludeCompilerDirective::AngleBracket(x) => Some(
+                        x.nodes
+                            .2
+                            .nodes
+                            .0
+                            .str(s)
+                            .trim_start_matches('<')
+                            .trim_end_matches('>'),
+                    ),
+                    IncludeCompilerDirective::TextMacroUsage(_) => None,
+                };
+                if let Some(include) = include {
+                    let locate: Locate = x.try_into().unwrap();
+                    let dirs: Vec<&Path> = include_paths.iter().map(|x| x.as_ref()).collect();
+                    let include_site = match expansion_site {
+                        Some(_) => None,
+                        None => ret.resolve(path.as_ref(), locate.offset),
+                    };
+                    ret.skipped_includes.push(SkippedInclude {
+                        path: state.provider.resolve_include(
+                            Path::new(include),
+                            path.as_ref(),
+                            &dirs,
+                        ),
+                        include_site,
+                    });
+                }
+            }
             continue;
         }
 
@@ -321,6 +1213,22 @@ pub fn preprocess_str<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
                 let locate: Locate = x.try_into().unwrap();
                 let range = Range::new(locate.offset, locate.offset + locate.len);
                 ret.push(locate.str(&s), Some((path.as_ref(), range)));
+
+                let (_, _, ref number, ref filename, ref level) = x.nodes;
+                let number: Locate = number.try_into().unwrap();
+                let presumed_line = number.str(&s).trim().parse::<usize>();
+                let filename = filename.nodes.0.str(&s);
+                let presumed_path = PathBuf::from(&filename[1..filename.len() - 1]);
+                // The level only marks entering and exiting includes, which don't change
+                // locations of the following lines
+                let level = level.nodes.0.nodes.0;
+                let next_line = s[level.offset..].find('\n').map(|x| level.offset + x + 1);
+                // Offsets in a macro body are relative to the body, so `line in it is ignored
+                if let (Ok(presumed_line), Some(next_line), None) =
+                    (presumed_line, next_line, expansion_site)
+                {
+                    ret.add_line_directive(path.as_ref(), next_line, presumed_path, presumed_line);
+                }
             }
             NodeEvent::Enter(RefNode::KeywordsDirective(x)) => {
                 let locate: Locate = x.try_into().unwrap();
@@ -339,38 +1247,46 @@ pub fn preprocess_str<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
                 let (_, _, ref name) = x.nodes;
                 let id = identifier((&name.nodes.0).into(), &s).unwrap();
                 defines.remove(&id);
+                ret.dependency_mut(path.as_ref()).undefined.push(id);
             }
             NodeEvent::Enter(RefNode::UndefineallCompilerDirective(x)) => {
                 skip_nodes.push(x.into());
                 skip = true;
 
-                defines.clear();
+                let mut ids: Vec<_> = defines.drain().map(|(k, _)| k).collect();
+                ids.sort();
+                ret.dependency_mut(path.as_ref()).undefined.extend(ids);
             }
             NodeEvent::Enter(RefNode::IfdefDirective(x)) => {
-                let (_, ref keyword, ref ifid, ref ifbody, ref elsif, ref elsebody, _, _) = x.nodes;
+                let (_, ref keyword, ref ifid, ref ifbody, ref elsif, ref elsebody, ref endif, _) =
+                    x.nodes;
                 skip_nodes.push(keyword.into());
                 skip_nodes.push(ifid.into());
 
-                let ifid = identifier(ifid.into(), &s).unwrap();
+                let blocks = conditional_blocks(keyword, ifid, elsif, elsebody, endif, s);
+                let mut inactive = Vec::new();
+
                 let mut hit = false;
-                if defines.contains_key(&ifid) {
+                if ifdef_condition(ifid, &s, &defines) {
                     hit = true;
                 } else {
                     skip_nodes.push(ifbody.into());
+                    inactive.push(0);
                 }
 
-                for x in elsif {
+                for (i, x) in elsif.iter().enumerate() {
                     let (_, ref keyword, ref elsifid, ref elsifbody) = x;
                     skip_nodes.push(keyword.into());
                     skip_nodes.push(elsifid.into());
 
-                    let elsifid = identifier(elsifid.into(), &s).unwrap();
                     if hit {
                         skip_nodes.push(elsifbody.into());
-                    } else if defines.contains_key(&elsifid) {
+                        inactive.push(i + 1);
+                    } else if ifdef_condition(elsifid, &s, &defines) {
                         hit = true;
                     } else {
                         skip_nodes.push(elsifbody.into());
+                        inactive.push(i + 1);
                     }
                 }
 
@@ -379,6 +1295,14 @@ pub fn preprocess_str<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
                     skip_nodes.push(keyword.into());
                     if hit {
                         skip_nodes.push(elsebody.into());
+                        inactive.push(elsif.len() + 1);
+                    }
+                }
+
+                // Blocks in macro bodies have no range in source files
+                if expansion_site.is_none() {
+                    for i in inactive {
+                        ret.add_inactive_region(path.as_ref(), &blocks[i]);
                     }
                 }
             }
@@ -395,30 +1319,35 @@ pub fn preprocess_str<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
                 ret.push(locate.str(&s), Some((path.as_ref(), range)));
             }
             NodeEvent::Enter(RefNode::IfndefDirective(x)) => {
-                let (_, ref keyword, ref ifid, ref ifbody, ref elsif, ref elsebody, _, _) = x.nodes;
+                let (_, ref keyword, ref ifid, ref ifbody, ref elsif, ref elsebody, ref endif, _) =
+                    x.nodes;
                 skip_nodes.push(keyword.into());
                 skip_nodes.push(ifid.into());
 
-                let ifid = identifier(ifid.into(), &s).unwrap();
+                let blocks = conditional_blocks(keyword, ifid, elsif, elsebody, endif, s);
+                let mut inactive = Vec::new();
+
                 let mut hit = false;
-                if !defines.contains_key(&ifid) {
+                if !ifdef_condition(ifid, &s, &defines) {
                     hit = true;
                 } else {
                     skip_nodes.push(ifbody.into());
+                    inactive.push(0);
                 }
 
-                for x in elsif {
+                for (i, x) in elsif.iter().enumerate() {
                     let (_, ref keyword, ref elsifid, ref elsifbody) = x;
                     skip_nodes.push(keyword.into());
                     skip_nodes.push(elsifid.into());
 
-                    let elsifid = identifier(elsifid.into(), &s).unwrap();
                     if hit {
                         skip_nodes.push(elsifbody.into());
-                    } else if defines.contains_key(&elsifid) {
+                        inactive.push(i + 1);
+                    } else if ifdef_condition(elsifid, &s, &defines) {
                         hit = true;
                     } else {
                         skip_nodes.push(elsifbody.into());
+                        inactive.push(i + 1);
                     }
                 }
 
@@ -427,6 +1356,14 @@ pub fn preprocess_str<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
                     skip_nodes.push(keyword.into());
                     if hit {
                         skip_nodes.push(elsebody.into());
+                        inactive.push(elsif.len() + 1);
+                    }
+                }
+
+                // Blocks in macro bodies have no range in source files
+                if expansion_site.is_none() {
+                    for i in inactive {
+                        ret.add_inactive_region(path.as_ref(), &blocks[i]);
                     }
                 }
             }
@@ -477,7 +1414,8 @@ pub fn preprocess_str<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
                     text: define_text,
                 };
 
-                defines.insert(id, Some(define));
+                defines.insert(id.clone(), Some(define));
+                ret.dependency_mut(path.as_ref()).defined.push(id);
 
                 // Keep TextMacroDefinition after preprocess
                 let locate: Locate = x.try_into().unwrap();
@@ -490,6 +1428,7 @@ pub fn preprocess_str<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
 
                 let locate: Locate = x.try_into().unwrap();
                 last_include_line = Some(locate.line);
+                let include_site = (PathBuf::from(path.as_ref()), locate.offset);
 
                 if let Some(last_item_line) = last_item_line {
                     if last_item_line == locate.line {
@@ -497,7 +1436,7 @@ pub fn preprocess_str<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
                     }
                 }
 
-                let mut path = match x {
+                let path = match x {
                     IncludeCompilerDirective::DoubleQuote(x) => {
                         let (_, ref keyword, ref literal) = x.nodes;
                         skip_nodes.push(keyword.into());
@@ -519,7 +1458,9 @@ pub fn preprocess_str<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
                         skip_nodes.push(keyword.into());
                         skip_nodes.push(x.into());
 
-                        if let Some((p, _, _)) = resolve_text_macro_usage(
+                        let locate: Locate = x.try_into().unwrap();
+                        let site = site(&ret, locate.offset);
+                        if let Some((x, _, _)) = resolve_text_macro_usage(
                             x,
                             s,
                             path.as_ref(),
@@ -527,36 +1468,68 @@ pub fn preprocess_str<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
                             include_paths,
                             strip_comments,
                             resolve_depth + 1,
+                            &site,
+                            state,
                         )? {
-                            let p = p.trim().trim_matches('"');
+                            let p = x.text().trim().trim_matches('"');
                             PathBuf::from(p)
                         } else {
                             PathBuf::from("")
                         }
                     }
                 };
-                if path.is_relative() && !path.exists() {
-                    for include_path in include_paths {
-                        let new_path = include_path.as_ref().join(&path);
-                        if new_path.exists() {
-                            path = new_path;
-                            break;
-                        }
-                    }
+                let dirs: Vec<&Path> = include_paths.iter().map(|x| x.as_ref()).collect();
+                let path = state
+                    .provider
+                    .resolve_include(&path, &include_site.0, &dirs);
+                let canonical = state.provider.canonicalize(&path);
+                let guarded = match state.include_guards.get(&canonical) {
+                    Some(x) => defines.contains_key(x),
+                    None => false,
+                };
+                // Re-entering a file is a cycle only if the file produces its content again,
+                // and a guarded file being preprocessed is always skipped.
+                let reentered = state.include_stack.iter().position(|x| x == &canonical);
+                if let (Some(i), false) = (reentered, guarded) {
+                    let mut chain = state.include_stack[i..].to_vec();
+                    chain.push(canonical);
+                    return Err(Error::IncludeCycle { chain });
                 }
-                let (include, new_defines) =
-                    preprocess(path, &defines, include_paths, strip_comments, false).map_err(
-                        |x| Error::Include {
+
+                let skip_include = guarded && (state.skip_guarded_include || reentered.is_some());
+                if !skip_include {
+                    let (mut include, new_defines) = preprocess_file(
+                        path,
+                        &defines,
+                        include_paths,
+                        strip_comments,
+                        false,
+                        state,
+                    )
+                    .map_err(|x| match x {
+                        Error::IncludeCycle { .. } => x,
+                        x => Error::Include {
                             source: Box::new(x),
                         },
-                    )?;
-                defines = new_defines;
-                ret.merge(include);
+                    })?;
+                    defines = new_defines;
+                    if let Some(x) = include.dependencies.first_mut() {
+                        if expansion_site.is_none() {
+                            x.include_site = ret.resolve(&include_site.0, include_site.1);
+                        }
+                    }
+                    ret.merge(include, include_site);
+                }
             }
             NodeEvent::Enter(RefNode::TextMacroUsage(x)) => {
                 skip_nodes.push(x.into());
                 skip = true;
 
+                let (_, ref name, _) = x.nodes;
+                let name = identifier((&name.nodes.0).into(), s).unwrap();
+                let locate: Locate = x.try_into().unwrap();
+                let site = site(&ret, locate.offset);
+
                 if let Some((text, origin, new_defines)) = resolve_text_macro_usage(
                     x,
                     s,
@@ -565,8 +1538,10 @@ pub fn preprocess_str<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
                     include_paths,
                     strip_comments,
                     resolve_depth + 1,
+                    &site,
+                    state,
                 )? {
-                    ret.push(&text, origin);
+                    ret.merge_macro(text, &name, origin, (path.as_ref(), locate.offset));
                     defines = new_defines;
                 }
             }
@@ -574,19 +1549,21 @@ pub fn preprocess_str<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
                 skip_nodes.push(x.into());
                 skip = true;
 
-                let (_, ref x) = x.nodes;
+                let (ref symbol, ref x) = x.nodes;
                 let locate: Locate = x.try_into().unwrap();
+                let range = Range::new(symbol.nodes.0.offset, locate.offset + locate.len);
+                let (file, line) = site(&ret, range.begin);
                 let x = locate.str(s);
                 if x.starts_with("__FILE__") {
-                    ret.push::<PathBuf>(
-                        &x.replace(
-                            "__FILE__",
-                            &format!("\"{}\"", path.as_ref().to_string_lossy()),
-                        ),
-                        None,
+                    ret.push(
+                        &x.replace("__FILE__", &format!("\"{}\"", file.to_string_lossy())),
+                        Some((path.as_ref(), range)),
                     );
                 } else if x.starts_with("__LINE__") {
-                    ret.push::<PathBuf>(&x.replace("__LINE__", &format!("{}", locate.line)), None);
+                    ret.push(
+                        &x.replace("__LINE__", &format!("{}", line)),
+                        Some((path.as_ref(), range)),
+                    );
                 }
             }
             _ => (),
@@ -596,15 +1573,140 @@ pub fn preprocess_str<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
     Ok((ret, defines))
 }
 
-fn identifier(node: RefNode, s: &str) -> Option<String> {
+// The block of each directive begins after the condition and ends before the next directive
+fn conditional_blocks(
+    keyword: &Keyword,
+    condition: &IfdefCondition,
+    elsif: &[(Symbol, Keyword, IfdefCondition, ElsifGroupOfLines)],
+    elsebody: &Option<(Symbol, Keyword, ElseGroupOfLines)>,
+    endif: &Symbol,
+    s: &str,
+) -> Vec<ConditionalBlock> {
+    let mut directives = vec![(keyword, Some(condition))];
+    let mut ends = Vec::new();
+    for (symbol, keyword, condition, _) in elsif {
+        directives.push((keyword, Some(condition)));
+        ends.push(symbol.nodes.0.offset);
+    }
+    if let Some((symbol, keyword, _)) = elsebody {
+        directives.push((keyword, None));
+        ends.push(symbol.nodes.0.offset);
+    }
+    ends.push(endif.nodes.0.offset);
+
+    let mut ret = Vec::new();
+    for ((keyword, condition), end) in directives.into_iter().zip(ends) {
+        let header: Locate = match condition {
+            Some(x) => x.try_into().unwrap(),
+            None => keyword.try_into().unwrap(),
+        };
+        let begin = (header.offset + header.len).min(end);
+        ret.push(ConditionalBlock {
+            directive: String::from(keyword.nodes.0.str(s)),
+            condition: condition.map(|x| get_str(x.into(), s).trim().to_string()),
+            range: Range::new(begin, end),
+        });
+    }
+    ret
+}
+
+fn ifdef_condition<V: BuildHasher>(node: &IfdefCondition, s: &str, defines: &Defines<V>) -> bool {
+    match node {
+        IfdefCondition::TextMacroIdentifier(x) => {
+            let id = identifier((&**x).into(), s).unwrap();
+            defines.contains_key(&id)
+        }
+        IfdefCondition::IfdefMacroExpression(x) => ifdef_macro_expression(x, s, defines),
+    }
+}
+
+fn ifdef_macro_expression<V: BuildHasher>(
+    node: &IfdefMacroExpression,
+    s: &str,
+    defines: &Defines<V>,
+) -> bool {
+    match node {
+        IfdefMacroExpression::TextMacroIdentifier(x) => {
+            let id = identifier((&**x).into(), s).unwrap();
+            defines.contains_key(&id)
+        }
+        IfdefMacroExpression::Paren(x) => {
+            let (_, ref x, _) = x.nodes.0.nodes;
+            ifdef_macro_expression(x, s, defines)
+        }
+        IfdefMacroExpression::Not(x) => !ifdef_macro_expression(&x.nodes.1, s, defines),
+        IfdefMacroExpression::Binary(x) => {
+            let (ref a, ref op, ref b) = x.nodes;
+            let a = ifdef_macro_expression(a, s, defines);
+            let b = ifdef_macro_expression(b, s, defines);
+            let op: Locate = op.nodes.0.nodes.0;
+            match op.str(s) {
+                "&&" => a && b,
+                "||" => a || b,
+                "->" => !a || b,
+                "<->" => a == b,
+                _ => unreachable!(),
+            }
+        }
+    }
+}
+
+// Get the macro name of `` `ifndef X `define X ... `endif `` enclosing the whole text
+fn include_guard(pp_text: &PreprocessorText, s: &str) -> Option<String> {
+    let is_blank = |x: &SourceDescription| match x {
+        SourceDescription::Comment(_) => true,
+        SourceDescription::NotDirective(x) => x.nodes.0.str(s).trim().is_empty(),
+        _ => false,
+    };
+
+    let mut items = pp_text.nodes.0.iter().filter(|x| !is_blank(x));
+    let x = match (items.next(), items.next()) {
+        (Some(SourceDescription::CompilerDirective(x)), None) => x,
+        _ => return None,
+    };
+    let x = match &**x {
+        CompilerDirective::ConditionalCompilerDirective(x) => x,
+        _ => return None,
+    };
+    let x = match &**x {
+        ConditionalCompilerDirective::IfndefDirective(x) => x,
+        _ => return None,
+    };
+    let (_, _, ref cond, ref body, ref elsif, ref else_, _, _) = x.nodes;
+    let name = match cond {
+        IfdefCondition::TextMacroIdentifier(x) => identifier((&**x).into(), s)?,
+        _ => return None,
+    };
+    if !elsif.is_empty() || else_.is_some() {
+        return None;
+    }
+
+    match body.nodes.0.iter().find(|x| !is_blank(x)) {
+        Some(SourceDescription::CompilerDirective(x)) => match &**x {
+            CompilerDirective::TextMacroDefinition(x) => {
+                let (_, _, ref proto, _) = x.nodes;
+                let define = identifier((&proto.nodes.0).into(), s)?;
+                if define == name {
+                    Some(name)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+pub(crate) fn identifier(node: RefNode, s: &str) -> Option<String> {
     for x in node {
         match x {
             RefNode::SimpleIdentifier(x) => {
-                let x: Locate = x.nodes.0.try_into().unwrap();
+                let x = x.nodes.0;
                 return Some(String::from(x.str(s)));
             }
             RefNode::EscapedIdentifier(x) => {
-                let x: Locate = x.nodes.0.try_into().unwrap();
+                let x = x.nodes.0;
                 let x = x.str(s);
                 let x = &x[1..]; // remove \
                 return Some(String::from(x));
@@ -628,57 +1730,97 @@ fn get_str(node: RefNode, s: &str) -> String {
     ret
 }
 
-fn split_text(s: &str) -> Vec<String> {
-    let mut is_string = false;
-    let mut is_ident = false;
-    let mut is_backquote_prev = false;
-    let mut is_comment = false;
-    let mut is_ident_prev;
-    let mut x = String::from("");
-    let mut ret = vec![];
-
-    let mut iter = s.chars().peekable();
-    while let Some(c) = iter.next() {
-        is_ident_prev = is_ident;
-        is_ident = c.is_ascii_alphanumeric() | (c == '_');
-
-        if c == '\n' && is_comment {
-            is_comment = false;
-            x.push(c);
-        } else if is_comment {
-            continue;
-        } else if c == '"' && is_backquote_prev {
-            x.push(c);
-            ret.push(x);
-            x = String::from("");
-        } else if c == '"' && !is_string {
-            ret.push(x);
-            x = String::from("");
-            x.push(c);
-            is_string = true;
-        } else if c == '"' && is_string {
-            x.push(c);
-            ret.push(x);
-            x = String::from("");
-            is_string = false;
-        } else if c == '/' && iter.peek() == Some(&'/') && !is_string {
-            is_comment = true;
-        } else if !is_string {
-            if is_ident != is_ident_prev {
-                ret.push(x);
-                x = String::from("");
+// Token of the text of a macro
+#[derive(Debug, PartialEq)]
+enum MacroToken {
+    Text(String),
+    Identifier(String),
+    // `"
+    Quote,
+    // `\`"
+    EscapedQuote,
+    // ``
+    Concat,
+}
+
+fn split_text(s: &str) -> Vec<MacroToken> {
+    let s = s
+        .replace("\\\r\n", "\r\n")
+        .replace("\\\n", "\n")
+        .replace("\\\r", "\r");
+    let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    // `$` continues an identifier, but doesn't start it
+    let is_ident_continue = |c: char| is_ident(c) || c == '$';
+
+    let mut ret = Vec::new();
+    let mut text = String::new();
+    let flush = |text: &mut String, ret: &mut Vec<MacroToken>| {
+        if !text.is_empty() {
+            ret.push(MacroToken::Text(std::mem::take(text)));
+        }
+    };
+
+    let mut i = 0;
+    while let Some(c) = s[i..].chars().next() {
+        let rest = &s[i..];
+        let (token, len) = if rest.starts_with("`\\`\"") {
+            (Some(MacroToken::EscapedQuote), 4)
+        } else if rest.starts_with("`\"") {
+            (Some(MacroToken::Quote), 2)
+        } else if rest.starts_with("``") {
+            (Some(MacroToken::Concat), 2)
+        } else if rest.starts_with("//") {
+            // the newline is kept
+            (None, rest.find('\n').unwrap_or(rest.len()))
+        } else if c == '"' {
+            // arguments are not substituted in string literals
+            let mut escaped = false;
+            let mut len = rest.len();
+            for (j, c) in rest.char_indices().skip(1) {
+                if c == '"' && !escaped {
+                    len = j + 1;
+                    break;
+                }
+                escaped = c == '\\' && !escaped;
             }
-            x.push(c);
+            text.push_str(&rest[..len]);
+            (None, len)
+        } else if let Some(len) = based_number_len(rest) {
+            // the value of a based number like `8'hFF` isn't an identifier
+            text.push_str(&rest[..len]);
+            (None, len)
+        } else if is_ident(c) {
+            let len = rest.find(|c| !is_ident_continue(c)).unwrap_or(rest.len());
+            (
+                Some(MacroToken::Identifier(String::from(&rest[..len]))),
+                len,
+            )
         } else {
-            x.push(c);
+            text.push(c);
+            (None, c.len_utf8())
+        };
+        if let Some(token) = token {
+            flush(&mut text, &mut ret);
+            ret.push(token);
         }
-
-        is_backquote_prev = c == '`';
+        i += len;
     }
-    ret.push(x);
+    flush(&mut text, &mut ret);
     ret
 }
 
+// Length of `'` with the base and the value of a based number, if `s` starts with them
+fn based_number_len(s: &str) -> Option<usize> {
+    let base = s.strip_prefix('\'')?;
+    let base = base.strip_prefix(|c| c == 's' || c == 'S').unwrap_or(base);
+    let value = base.strip_prefix(|c| "bBoOdDhH".contains(c))?;
+    let len = value
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '?'))
+        .unwrap_or(value.len());
+    Some(s.len() - value.len() + len)
+}
+
+#[allow(clippy::too_many_arguments)]
 fn resolve_text_macro_usage<T: AsRef<Path>, U: AsRef<Path>>(
     x: &TextMacroUsage,
     s: &str,
@@ -687,11 +1829,13 @@ fn resolve_text_macro_usage<T: AsRef<Path>, U: AsRef<Path>>(
     include_paths: &[U],
     strip_comments: bool,
     resolve_depth: usize,
-) -> Result<Option<(String, Option<(PathBuf, Range)>, Defines)>, Error> {
+    expansion_site: &(PathBuf, usize),
+    state: &mut IncludeState<'_>,
+) -> Result<Option<(PreprocessedText, Option<(PathBuf, Range)>, Defines)>, Error> {
     let (_, ref name, ref args) = x.nodes;
     let id = identifier((&name.nodes.0).into(), &s).unwrap();
 
-    if resolve_depth > RECURSIVE_LIMIT {
+    if resolve_depth > state.recursive_limit {
         return Err(Error::ExceedRecursiveLimit);
     }
 
@@ -754,20 +1898,55 @@ fn resolve_text_macro_usage<T: AsRef<Path>, U: AsRef<Path>>(
 
         if let Some(ref text) = define.text {
             let mut replaced = String::from("");
-            for text in split_text(&text.text) {
-                if let Some(value) = arg_map.get(&text) {
-                    replaced.push_str(*value);
-                } else {
-                    replaced.push_str(
-                        &text
-                            .replace("``", "")
-                            .replace("`\\`\"", "\\\"")
-                            .replace("`\"", "\"")
-                            .replace("\\\n", "\n")
-                            .replace("\\\r\n", "\r\n")
-                            .replace("\\\r", "\r"),
-                    );
+            // text between `" and `"
+            let mut quoted: Option<String> = None;
+            for token in split_text(&text.text) {
+                if token == MacroToken::Quote {
+                    if let Some(x) = quoted.take() {
+                        // macros in the string are expanded
+                        let expanded = if x.contains('`') {
+                            Some(preprocess_str_impl(
+                                &x,
+                                path.as_ref(),
+                                &defines,
+                                include_paths,
+                                false,
+                                strip_comments,
+                                resolve_depth,
+                                Some(expansion_site),
+                                state,
+                            )?)
+                        } else {
+                            None
+                        };
+                        let x = match expanded {
+                            Some((expanded, _)) if x.ends_with(char::is_whitespace) => {
+                                String::from(expanded.text())
+                            }
+                            Some((expanded, _)) => String::from(expanded.text().trim_end()),
+                            None => x,
+                        };
+                        replaced.push_str(&format!("\"{}\"", x));
+                    } else {
+                        quoted = Some(String::new());
+                    }
+                    continue;
                 }
+
+                let out = quoted.as_mut().unwrap_or(&mut replaced);
+                match token {
+                    MacroToken::Text(x) => out.push_str(&x),
+                    MacroToken::Identifier(x) => match arg_map.get(&x) {
+                        Some(value) => out.push_str(value),
+                        None => out.push_str(&x),
+                    },
+                    MacroToken::EscapedQuote => out.push_str("\\\""),
+                    MacroToken::Concat | MacroToken::Quote => (),
+                }
+            }
+            if let Some(x) = quoted {
+                replaced.push('"');
+                replaced.push_str(&x);
             }
 
             if let Some(paren) = paren {
@@ -777,8 +1956,13 @@ fn resolve_text_macro_usage<T: AsRef<Path>, U: AsRef<Path>>(
             // separator is required
             replaced.push_str(" ");
             // remove leading whitespace
+            let trimmed = replaced.len() - replaced.trim_start().len();
             replaced = String::from(replaced.trim_start());
-            let (replaced, new_defines) = preprocess_str(
+            let origin = text.origin.clone().map(|(path, range)| {
+                let begin = (range.begin + trimmed).min(range.end);
+                (path, Range::new(begin, range.end))
+            });
+            let (replaced, new_defines) = preprocess_str_impl(
                 &replaced,
                 path.as_ref(),
                 &defines,
@@ -786,12 +1970,10 @@ fn resolve_text_macro_usage<T: AsRef<Path>, U: AsRef<Path>>(
                 false,
                 strip_comments,
                 resolve_depth,
+                Some(expansion_site),
+                state,
             )?;
-            Ok(Some((
-                String::from(replaced.text()),
-                text.origin.clone(),
-                new_defines,
-            )))
+            Ok(Some((replaced, origin, new_defines)))
         } else {
             Ok(None)
         }
@@ -1339,4 +2521,678 @@ endmodule
         );
         assert_eq!(ret.origin(80).unwrap().1, 60);
     }
+
+    #[test]
+    fn test21() {
+        let include_paths = [get_testcase("")];
+        let (ret, _) = preprocess(
+            get_testcase("test21.sv"),
+            &HashMap::new(),
+            &include_paths,
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            ret.text(),
+            r##"module and_op (a, b);
+`define INNER(x) x + 1
+`define OUTER(x) assign a = `INNER(x);
+
+  assign a = b + 1 ; endmodule
+"##
+        );
+
+        let location = |x: &Option<SourceLocation>| {
+            let x = x.as_ref().unwrap();
+            (
+                x.path.file_name().unwrap().to_string_lossy().into_owned(),
+                x.line,
+                x.column,
+            )
+        };
+        let frames = |pos: usize| {
+            ret.expansions(pos)
+                .iter()
+                .map(|x| match x {
+                    Expansion::Macro {
+                        name,
+                        definition,
+                        call_site,
+                    } => (name.clone(), location(definition), location(call_site)),
+                    Expansion::Include { include_site } => (
+                        String::from(""),
+                        location(include_site),
+                        location(include_site),
+                    ),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // `define in test21.svh
+        let pos = ret.text().find("`define INNER").unwrap();
+        assert_eq!(
+            frames(pos),
+            vec![(
+                String::from(""),
+                (String::from("test21.sv"), 2, 1),
+                (String::from("test21.sv"), 2, 1)
+            )]
+        );
+
+        // assign from `OUTER
+        let pos = ret.text().rfind("assign a").unwrap();
+        assert_eq!(
+            frames(pos),
+            vec![(
+                String::from("OUTER"),
+                (String::from("test21.svh"), 2, 18),
+                (String::from("test21.sv"), 3, 3)
+            )]
+        );
+        assert_eq!(ret.location(pos).unwrap().line, 2);
+
+        // + from `INNER in `OUTER
+        let pos = ret.text().rfind("+ 1").unwrap();
+        assert_eq!(
+            frames(pos),
+            vec![
+                (
+                    String::from("INNER"),
+                    (String::from("test21.svh"), 1, 18),
+                    (String::from("test21.svh"), 2, 29)
+                ),
+                (
+                    String::from("OUTER"),
+                    (String::from("test21.svh"), 2, 18),
+                    (String::from("test21.sv"), 3, 3)
+                ),
+            ]
+        );
+        assert_eq!(ret.location(pos).unwrap().line, 1);
+
+        let pos = ret.text().find("endmodule").unwrap();
+        assert_eq!(frames(pos), vec![]);
+    }
+
+    #[test]
+    fn test22() {
+        let (ret, _) = preprocess(
+            get_testcase("test22.sv"),
+            &HashMap::new(),
+            &[] as &[String],
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            ret.text(),
+            r##"module and_op (a, b, c);
+
+wire x1;
+
+wire x3;
+
+endmodule
+"##
+        );
+
+        let mut defines = HashMap::new();
+        defines.insert(String::from("A"), None);
+        let (ret, _) = preprocess(
+            get_testcase("test22.sv"),
+            &defines,
+            &[] as &[String],
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            ret.text(),
+            r##"module and_op (a, b, c);
+wire x0;
+
+wire x2;
+
+wire x3;
+
+endmodule
+"##
+        );
+    }
+
+    #[test]
+    fn test23() {
+        let (ret, _) = preprocess(
+            get_testcase("test23.sv"),
+            &HashMap::new(),
+            &[] as &[String],
+            false,
+            false,
+        )
+        .unwrap();
+        let location = |x: &str| {
+            let x = ret.location(ret.text().find(x).unwrap()).unwrap();
+            let physical = x
+                .physical
+                .map(|(path, line)| (path.file_name().unwrap().to_owned(), line));
+            (x.path, x.line, x.column, physical)
+        };
+
+        assert_eq!(
+            location("module"),
+            (PathBuf::from(get_testcase("test23.sv")), 1, 1, None)
+        );
+        assert_eq!(
+            location("output"),
+            (
+                PathBuf::from("generated.v"),
+                100,
+                1,
+                Some(("test23.sv".into(), 3))
+            )
+        );
+        assert_eq!(
+            location("c;"),
+            (
+                PathBuf::from("generated.v"),
+                101,
+                10,
+                Some(("test23.sv".into(), 4))
+            )
+        );
+        assert_eq!(
+            location("and a1"),
+            (PathBuf::from("regs.v"), 8, 1, Some(("test23.sv".into(), 7)))
+        );
+
+        let pos = ret.text().find("and a1").unwrap();
+        let physical = ret.physical_location(pos).unwrap();
+        assert_eq!(physical.line, 7);
+        assert_eq!(physical.physical, None);
+    }
+
+    #[test]
+    fn test24() {
+        let include_paths = [get_testcase("")];
+        let (ret, _) = preprocess(
+            get_testcase("test24.sv"),
+            &HashMap::new(),
+            &include_paths,
+            false,
+            false,
+        )
+        .unwrap();
+        let sv = get_testcase("test24.sv");
+        let svh = get_testcase("test24.svh");
+        assert_eq!(
+            ret.text(),
+            format!(
+                r##"`define HERE `__FILE__, `__LINE__
+`define OUTER `HERE
+module test;
+initial $display("{0}", 4);
+initial $display("{0}", 5 );
+initial $display(
+  "{0}", 7 );
+initial $display("{1}", 1);
+initial $display("{1}", 2 );
+
+endmodule
+"##,
+                sv, svh
+            )
+        );
+
+        let pos = ret.text().find(&format!("\"{}\", 4", sv)).unwrap();
+        let x = ret.location(pos).unwrap();
+        assert_eq!((x.path, x.line, x.column), (PathBuf::from(&sv), 4, 18));
+        let pos = ret.text().find(&format!("\"{}\", 1", svh)).unwrap();
+        let x = ret.location(pos).unwrap();
+        assert_eq!((x.path, x.line, x.column), (PathBuf::from(&svh), 1, 18));
+    }
+
+    #[test]
+    fn test25() {
+        let include_paths = [get_testcase("")];
+        let ret = preprocess(
+            get_testcase("test25.sv"),
+            &HashMap::new(),
+            &include_paths,
+            false,
+            false,
+        );
+        match ret {
+            Err(Error::IncludeCycle { chain }) => {
+                let chain: Vec<_> = chain
+                    .iter()
+                    .map(|x| x.file_name().unwrap().to_string_lossy().into_owned())
+                    .collect();
+                assert_eq!(chain, vec!["test25a.svh", "test25b.svh", "test25a.svh"]);
+            }
+            x => panic!("{:?}", x),
+        }
+    }
+
+    #[test]
+    fn test26() {
+        let include_paths = [get_testcase("")];
+        let (ret, _) = preprocess(
+            get_testcase("test26.sv"),
+            &HashMap::new(),
+            &include_paths,
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            ret.text(),
+            r##"module and_op (a, b);
+// test26.svh
+`define TEST26
+assign a = b;
+
+
+// test26.svh
+
+
+endmodule
+"##
+        );
+
+        let options = PreprocessOptions::new().skip_guarded_include(true);
+        let (ret, _) = preprocess_with(
+            get_testcase("test26.sv"),
+            &HashMap::new(),
+            &include_paths,
+            &options,
+        )
+        .unwrap();
+        assert_eq!(
+            ret.text(),
+            r##"module and_op (a, b);
+// test26.svh
+`define TEST26
+assign a = b;
+
+
+
+endmodule
+"##
+        );
+
+        // Mutually including headers are guarded against the cycle
+        for skip_guarded_include in [false, true] {
+            let options = PreprocessOptions::new().skip_guarded_include(skip_guarded_include);
+            let (ret, _) = preprocess_with(
+                get_testcase("test26a.sv"),
+                &HashMap::new(),
+                &include_paths,
+                &options,
+            )
+            .unwrap();
+            assert_eq!(
+                ret.text(),
+                r##"module and_op (a, b);
+`define TEST26A
+`define TEST26B
+
+assign b = a;
+
+
+assign a = b;
+
+
+endmodule
+"##
+            );
+        }
+    }
+
+    #[test]
+    fn test27() {
+        let (ret, _) = preprocess(
+            get_testcase("test27.sv"),
+            &HashMap::new(),
+            &[] as &[String],
+            false,
+            false,
+        )
+        .unwrap();
+        let dependencies = ret.dependencies();
+        assert_eq!(dependencies.len(), 2);
+        assert_eq!(
+            dependencies[0].path,
+            PathBuf::from(get_testcase("test27.sv"))
+        );
+        assert_eq!(dependencies[0].include_site, None);
+        assert_eq!(dependencies[0].defined, vec![String::from("TEST27_A")]);
+        assert_eq!(dependencies[0].undefined, vec![String::from("TEST27_A")]);
+
+        assert_eq!(
+            dependencies[1].path,
+            PathBuf::from(get_testcase("test27.svh"))
+        );
+        let include_site = dependencies[1].include_site.as_ref().unwrap();
+        assert_eq!(include_site.path, PathBuf::from(get_testcase("test27.sv")));
+        assert_eq!(include_site.line, 3);
+        assert_eq!(dependencies[1].defined, vec![String::from("TEST27_C")]);
+        assert_eq!(dependencies[1].undefined, vec![String::from("TEST27_A")]);
+
+        let skipped = ret.skipped_includes();
+        assert_eq!(skipped.len(), 1);
+        // test27b.svh doesn't exist, so the path is kept as written
+        assert_eq!(skipped[0].path, PathBuf::from("test27b.svh"));
+        assert_eq!(skipped[0].include_site.as_ref().unwrap().line, 5);
+    }
+
+    #[test]
+    fn test28() {
+        let (ret, _) = preprocess(
+            get_testcase("test28.sv"),
+            &HashMap::new(),
+            &[] as &[String],
+            false,
+            false,
+        )
+        .unwrap();
+        let s = std::fs::read_to_string(get_testcase("test28.sv")).unwrap();
+        let regions: Vec<_> = ret
+            .inactive_regions()
+            .iter()
+            .map(|x| {
+                (
+                    x.directive.as_str(),
+                    x.condition.as_deref(),
+                    &s[x.range.begin..x.range.end],
+                    x.begin.line,
+                    x.end.line,
+                )
+            })
+            .collect();
+        assert_eq!(
+            regions,
+            vec![
+                ("ifdef", Some("TEST28_A"), "assign a = 0;\n", 4, 5),
+                ("else", None, "assign a = 2;\n", 8, 9),
+                ("ifndef", Some("TEST28_B"), "", 11, 11),
+            ]
+        );
+        assert_eq!(
+            ret.inactive_regions()[0].path,
+            PathBuf::from(get_testcase("test28.sv"))
+        );
+    }
+
+    #[test]
+    fn test29() {
+        let (ret, _) = preprocess(
+            get_testcase("test29.sv"),
+            &HashMap::new(),
+            &[] as &[String],
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            ret.text(),
+            r##"`define uvm_analysis_imp_decl(SFX) \
+class uvm_analysis_imp``SFX #(type T=int, type IMP=int); \
+  `UVM_IMP_COMMON(`"uvm_analysis_imp``SFX`",IMP) \
+  function void write(input T t); \
+    m_imp.write``SFX(t); \
+  endfunction \
+endclass
+`define UVM_IMP_COMMON(TYPE_NAME,IMP) localparam string type_name = TYPE_NAME;
+`define m_uvm_get_type_name_func(T) \
+  const static string type_name = `"T`"; \
+  virtual function string get_type_name(); \
+    return type_name; \
+  endfunction
+`define uvm_object_utils(T) \
+  typedef uvm_object_registry#(T,`"T`") type_id; \
+  `m_uvm_get_type_name_func(T)
+`define uvm_field_int(ARG,FLAG=UVM_DEFAULT) \
+  m_field_int(`"ARG`", ARG, FLAG);
+`define uvm_info(ID,MSG,VERBOSITY=UVM_MEDIUM) \
+  if (uvm_report_enabled(VERBOSITY,UVM_INFO,ID)) \
+    uvm_report_info(ID, MSG, VERBOSITY, "", 1);
+class uvm_analysis_imp_rsp #(type T=int, type IMP=int); 
+  localparam string type_name = "uvm_analysis_imp_rsp"; function void write(input T t); 
+    m_imp.write_rsp(t); 
+  endfunction 
+endclass class my_item;
+  typedef uvm_object_registry#(my_item,"my_item") type_id; 
+  const static string type_name = "my_item"; 
+  virtual function string get_type_name(); 
+    return type_name; 
+  endfunction m_field_int("addr", addr, UVM_DEFAULT); m_field_int("data", data, UVM_NOCOMPARE); function void report();
+    if (uvm_report_enabled(UVM_MEDIUM,UVM_INFO,"ITEM")) 
+    uvm_report_info("ITEM", $sformatf("addr=%0h", addr), UVM_MEDIUM, "", 1); endfunction
+endclass
+"##
+        );
+    }
+
+    #[test]
+    fn test30() {
+        let include_paths = [get_testcase("")];
+        let (ret, _) = preprocess(
+            get_testcase("test30.sv"),
+            &HashMap::new(),
+            &include_paths,
+            false,
+            false,
+        )
+        .unwrap();
+        let location = |pos: usize| {
+            let x = ret.location(pos).unwrap();
+            (x.path, x.line)
+        };
+
+        // `` `line `` of both inclusions are kept
+        let text = ret.text();
+        assert_eq!(location(text.find("wire a").unwrap()), ("x.v".into(), 100));
+        assert_eq!(location(text.rfind("wire a").unwrap()), ("x.v".into(), 100));
+        assert_eq!(location(text.find("wire c").unwrap()), ("y.v".into(), 200));
+
+        // `` `line `` in a macro body doesn't change locations
+        assert_eq!(
+            location(text.find("wire d").unwrap()),
+            (PathBuf::from(get_testcase("test30.sv")), 9)
+        );
+    }
+
+    #[test]
+    fn test31() {
+        let include_paths = [get_testcase("")];
+        let (ret, _) = preprocess(
+            get_testcase("test31.sv"),
+            &HashMap::new(),
+            &include_paths,
+            false,
+            false,
+        )
+        .unwrap();
+        let location = |pos: usize| {
+            let x = ret.location(pos).unwrap();
+            (x.path, x.line)
+        };
+
+        // A file included by a macro body is located in itself
+        let text = ret.text();
+        assert_eq!(
+            location(text.find("wire b").unwrap()),
+            (PathBuf::from(get_testcase("test31.svh")), 2)
+        );
+        assert_eq!(location(text.find("wire e").unwrap()), ("z.v".into(), 300));
+        assert_eq!(
+            location(text.find("wire c").unwrap()),
+            (PathBuf::from(get_testcase("test31.sv")), 4)
+        );
+    }
+
+    // IEEE 1800-2017 22.5.1 `", `\`" and `` with UVM-style patterns
+    #[test]
+    fn test_macro_operators() {
+        let cases = [
+            // stringification
+            (
+                r##"`define STR(x) `"x`""##,
+                r##"`STR(hello world)"##,
+                r##""hello world" "##,
+            ),
+            (r##"`define S(x) "x""##, r##"`S(abc)"##, r##""x" "##),
+            (
+                r##"`define PRINT(v) $display(`"v = %0d`", v)"##,
+                r##"`PRINT(count)"##,
+                r##"$display("count = %0d", count) "##,
+            ),
+            // escaped quote
+            (
+                r##"`define MSG(x) `"He said `\`"x`\`"`""##,
+                r##"`MSG(hi)"##,
+                r##""He said \"hi\"" "##,
+            ),
+            // token concatenation
+            (
+                r##"`define CAT3(a,b,c) a``b``c"##,
+                r##"`CAT3(x,y,z)"##,
+                r##"xyz "##,
+            ),
+            (
+                r##"`define SFX(n) `"imp``n`""##,
+                r##"`SFX(_rsp)"##,
+                r##""imp_rsp" "##,
+            ),
+            // nested macros building identifiers and strings
+            (
+                r##"`define PREFIX(n) my_``n
+`define DECL(n) int `PREFIX(n);"##,
+                r##"`DECL(foo)"##,
+                r##"int my_foo ; "##,
+            ),
+            (
+                r##"`define PREFIX(n) my_``n
+`define STR(x) `"x`""##,
+                r##"`STR(`PREFIX(bar))"##,
+                r##""my_bar" "##,
+            ),
+            (
+                r##"`define NAME(T) `"T`"
+`define TYPE_NAME(T) string type_name = `NAME(T);"##,
+                r##"`TYPE_NAME(pkg::cls)"##,
+                r##"string type_name = "pkg::cls" ; "##,
+            ),
+            (
+                r##"`define uvm_field_int int
+`define uvm_field(T) `uvm_field_``T"##,
+                r##"`uvm_field(int) x;"##,
+                r##"int x;"##,
+            ),
+            // argument defaults
+            (
+                r##"`define D(a, b=2, c=3) a+b+c"##,
+                r##"`D(1) `D(1,,4) `D(1, 5)"##,
+                r##"1+2+3 1+2+4 1+5+3 "##,
+            ),
+            (r##"`define E(a=) [a]"##, r##"`E()"##, r##"[] "##),
+            // `$` in identifiers
+            (r##"`define F(a) a$b a"##, r##"`F(x)"##, r##"a$b x "##),
+            // values of based numbers
+            (
+                r##"`define G(hFF, b1) 8'hFF + 8'sb1 + hFF"##,
+                r##"`G(x, y)"##,
+                r##"8'hFF + 8'sb1 + x "##,
+            ),
+            (r##"`define Q(s="abc") s"##, r##"`Q()"##, r##""abc" "##),
+            (
+                r##"`define FIRST(a, b) a"##,
+                r##"`FIRST((1, 2), 3)"##,
+                r##"(1, 2) "##,
+            ),
+        ];
+        for (define, usage, expected) in &cases {
+            let s = format!("{}\n{}\n", define, usage);
+            let (ret, _) = preprocess_str(
+                &s,
+                "test.sv",
+                &HashMap::new(),
+                &[] as &[String],
+                false,
+                true,
+                0,
+            )
+            .unwrap();
+            let text = ret.text().lines().last().unwrap();
+            assert_eq!(text, *expected, "{}", usage);
+        }
+    }
+
+    // Errors of macros in `" and `" are reported as other expansions
+    #[test]
+    fn test_macro_error_in_string() {
+        let preprocess = |s: &str| {
+            let options = PreprocessOptions::new().recursive_limit(8);
+            preprocess_str_with(s, "test.sv", &HashMap::new(), &[] as &[String], &options)
+        };
+        let ret = preprocess(
+            r##"`define STR(x) `"x `UNDEFINED`"
+`STR(a)
+"##,
+        );
+        assert_eq!(format!("{:?}", ret), r#"Err(DefineNotFound("UNDEFINED"))"#);
+        let ret = preprocess(
+            r##"`define LOOP `"`LOOP`"
+`LOOP
+"##,
+        );
+        assert_eq!(format!("{:?}", ret), "Err(ExceedRecursiveLimit)");
+    }
+
+    #[test]
+    fn test_recursive_limit() {
+        let s = r##"`define A1 1
+`define A2 `A1
+`define A3 `A2
+`define A4 `A3
+`A4
+"##;
+        let preprocess = |limit| {
+            let options = PreprocessOptions::new().recursive_limit(limit);
+            preprocess_str_with(s, "test.sv", &HashMap::new(), &[] as &[String], &options)
+        };
+        let ret = preprocess(2);
+        assert_eq!(format!("{:?}", ret), "Err(ExceedRecursiveLimit)");
+        let (ret, _) = preprocess(8).unwrap();
+        assert_eq!(ret.text().lines().last(), Some("1 "));
+    }
+
+    #[test]
+    fn test_origin_of_long_expansion() {
+        let s = r##"`define STR(x) `"x`"
+`STR(a long argument)
+"##;
+        let (ret, _) = preprocess_str(
+            s,
+            "test.sv",
+            &HashMap::new(),
+            &[] as &[String],
+            false,
+            false,
+            0,
+        )
+        .unwrap();
+        let pos = ret.text().find("argument").unwrap();
+        let definition = s.find("`\"x`\"").unwrap();
+
+        // `origin` maps the position as is
+        let (_, offset) = ret.origin(pos).unwrap();
+        assert!(offset > s.find('\n').unwrap());
+
+        // `location` is clamped into the definition
+        let x = ret.location(pos).unwrap();
+        assert_eq!(x.line, 1);
+        assert!(x.byte_offset >= definition && x.byte_offset < definition + 5);
+    }
 }
@@ -1,6 +1,9 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 
 #[derive(Copy, Clone, Debug, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Range {
     pub begin: usize,
     pub end: usize,
@@ -20,7 +23,10 @@ impl Range {
 
 impl PartialEq for Range {
     fn eq(&self, other: &Self) -> bool {
-        if self.begin <= other.begin {
+        // empty ranges don't overlap but equal to itself
+        if self.begin == other.begin && self.end == other.end {
+            true
+        } else if self.begin <= other.begin {
             other.begin < self.end
         } else {
             self.begin < other.end
@@ -59,4 +65,13 @@ mod tests {
         assert_eq!(map.get(&Range::new(10, 11)), Some(&String::from("10-15")));
         assert_eq!(map.get(&Range::new(15, 16)), None);
     }
+
+    #[test]
+    fn test_empty() {
+        // empty ranges overlap nothing, but are equal to themselves
+        assert_eq!(Range::new(3, 3), Range::new(3, 3));
+        assert_ne!(Range::new(3, 3), Range::new(3, 4));
+        assert_ne!(Range::new(3, 3), Range::new(2, 3));
+        assert_eq!(vec![Range::new(0, 0)], vec![Range::new(0, 0)]);
+    }
 }
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::hash::BuildHasher;
+use std::io::{self, BufReader, Read};
+use std::path::{Component, Path, PathBuf};
+
+/// Source files read by the preprocessor
+pub trait SourceProvider {
+    /// Read the whole content of the specified file
+    fn read(&self, path: &Path) -> io::Result<String>;
+
+    /// Check whether the specified file exists
+    fn exists(&self, path: &Path) -> bool;
+
+    /// Canonical form of `path` to identify a file included through different paths
+    ///
+    /// The default implementation removes `.` and resolves `..` lexically.
+    fn canonicalize(&self, path: &Path) -> PathBuf {
+        normalize_path(path)
+    }
+
+    /// Resolve the path of `` `include `` in `including`
+    ///
+    /// A relative path is searched as is (i.e. in the current directory for `FileSystem`),
+    /// in the directory of `including`, and in `include_paths` in this order.
+    /// If it isn't found, `path` is returned as is.
+    fn resolve_include(&self, path: &Path, including: &Path, include_paths: &[&Path]) -> PathBuf {
+        if path.is_absolute() || self.exists(path) {
+            return PathBuf::from(path);
+        }
+        let dir = including.parent().into_iter();
+        for dir in dir.chain(include_paths.iter().copied()) {
+            let new_path = dir.join(path);
+            if self.exists(&new_path) {
+                return new_path;
+            }
+        }
+        PathBuf::from(path)
+    }
+}
+
+/// `SourceProvider` reading files from the disk
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FileSystem;
+
+impl SourceProvider for FileSystem {
+    fn read(&self, path: &Path) -> io::Result<String> {
+        let f = File::open(path)?;
+        let mut reader = BufReader::new(f);
+        let mut s = String::new();
+        reader.read_to_string(&mut s)?;
+        Ok(s)
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+
+    /// Resolve symbolic links if the file exists
+    fn canonicalize(&self, path: &Path) -> PathBuf {
+        path.canonicalize().unwrap_or_else(|_| normalize_path(path))
+    }
+}
+
+/// `SourceProvider` serving files from memory by their paths
+impl<S: BuildHasher> SourceProvider for HashMap<PathBuf, String, S> {
+    fn read(&self, path: &Path) -> io::Result<String> {
+        self.get(path).cloned().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{} is not found", path.to_string_lossy()),
+            )
+        })
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.contains_key(path)
+    }
+}
+
+// Remove `.` and resolve `..` without accessing the file system
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut ret = PathBuf::new();
+    for x in path.components() {
+        match x {
+            Component::CurDir => {}
+            Component::ParentDir => match ret.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    ret.pop();
+                }
+                Some(Component::RootDir) | Some(Component::Prefix(_)) => {}
+                _ => ret.push(x),
+            },
+            x => ret.push(x),
+        }
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_include() {
+        let mut files = HashMap::new();
+        files.insert(PathBuf::from("src/a.svh"), String::new());
+        files.insert(PathBuf::from("inc/a.svh"), String::new());
+        files.insert(PathBuf::from("inc/b.svh"), String::new());
+
+        let including = Path::new("src/top.sv");
+        let include_paths = [Path::new("inc")];
+        let resolve = |x: &str| files.resolve_include(Path::new(x), including, &include_paths);
+
+        assert_eq!(resolve("a.svh"), PathBuf::from("src/a.svh"));
+        assert_eq!(resolve("b.svh"), PathBuf::from("inc/b.svh"));
+        assert_eq!(resolve("inc/a.svh"), PathBuf::from("inc/a.svh"));
+        assert_eq!(resolve("c.svh"), PathBuf::from("c.svh"));
+    }
+
+    #[test]
+    fn test_canonicalize() {
+        let files: HashMap<PathBuf, String> = HashMap::new();
+        let canonicalize = |x: &str| files.canonicalize(Path::new(x));
+
+        assert_eq!(canonicalize("src/./a.svh"), PathBuf::from("src/a.svh"));
+        assert_eq!(canonicalize("src/inc/../a.svh"), PathBuf::from("src/a.svh"));
+        assert_eq!(canonicalize("../src/a.svh"), PathBuf::from("../src/a.svh"));
+        assert_eq!(canonicalize("/../a.svh"), PathBuf::from("/a.svh"));
+    }
+}
@@ -0,0 +1,4 @@
+module and_op (a, b);
+`include "test21.svh"
+  `OUTER(b)
+endmodule
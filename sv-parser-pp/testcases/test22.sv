@@ -0,0 +1,15 @@
+module and_op (a, b, c);
+`ifdef (A && !B) || C
+  wire x0;
+`endif
+`ifdef (A -> B)
+  wire x1;
+`elsif (!(A <-> B))
+  wire x2;
+`endif
+`ifndef (B || C && A)
+  wire x3;
+`else
+  wire x4;
+`endif
+endmodule
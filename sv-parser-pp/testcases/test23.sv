@@ -0,0 +1,8 @@
+module and_op (a, b, c);
+`line 100 "generated.v" 0
+output a;
+input b, c;
+`line 7 "regs.v" 1
+
+and a1 (a,b,c);
+endmodule
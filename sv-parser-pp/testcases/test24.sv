@@ -0,0 +1,9 @@
+`define HERE `__FILE__, `__LINE__
+`define OUTER `HERE
+module test;
+initial $display(`__FILE__, `__LINE__);
+initial $display(`HERE);
+initial $display(
+  `OUTER);
+`include "test24.svh"
+endmodule
@@ -0,0 +1,4 @@
+module and_op (a, b);
+`include "test26.svh"
+`include "test26.svh"
+endmodule
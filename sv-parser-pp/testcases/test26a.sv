@@ -0,0 +1,3 @@
+module and_op (a, b);
+`include "test26a.svh"
+endmodule
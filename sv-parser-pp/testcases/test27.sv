@@ -0,0 +1,8 @@
+module and_op (a, b);
+`define TEST27_A
+`include "test27.svh"
+`ifdef TEST27_B
+`include "test27b.svh"
+`endif
+`undef TEST27_A
+endmodule
@@ -0,0 +1,12 @@
+module and_op (a, b);
+`define TEST28_B
+`ifdef TEST28_A
+assign a = 0;
+`elsif TEST28_B
+assign a = 1;
+`else
+assign a = 2;
+`endif
+`ifndef TEST28_B
+`endif
+endmodule
@@ -0,0 +1,30 @@
+`define uvm_analysis_imp_decl(SFX) \
+class uvm_analysis_imp``SFX #(type T=int, type IMP=int); \
+  `UVM_IMP_COMMON(`"uvm_analysis_imp``SFX`",IMP) \
+  function void write(input T t); \
+    m_imp.write``SFX(t); \
+  endfunction \
+endclass
+`define UVM_IMP_COMMON(TYPE_NAME,IMP) localparam string type_name = TYPE_NAME;
+`define m_uvm_get_type_name_func(T) \
+  const static string type_name = `"T`"; \
+  virtual function string get_type_name(); \
+    return type_name; \
+  endfunction
+`define uvm_object_utils(T) \
+  typedef uvm_object_registry#(T,`"T`") type_id; \
+  `m_uvm_get_type_name_func(T)
+`define uvm_field_int(ARG,FLAG=UVM_DEFAULT) \
+  m_field_int(`"ARG`", ARG, FLAG);
+`define uvm_info(ID,MSG,VERBOSITY=UVM_MEDIUM) \
+  if (uvm_report_enabled(VERBOSITY,UVM_INFO,ID)) \
+    uvm_report_info(ID, MSG, VERBOSITY, "", 1);
+`uvm_analysis_imp_decl(_rsp)
+class my_item;
+  `uvm_object_utils(my_item)
+  `uvm_field_int(addr)
+  `uvm_field_int(data, UVM_NOCOMPARE)
+  function void report();
+    `uvm_info("ITEM", $sformatf("addr=%0h", addr))
+  endfunction
+endclass
@@ -0,0 +1,10 @@
+module test;
+`include "test30.svh"
+`define DONE
+`include "test30.svh"
+`define M \
+`line 10 "m.v" 0 \
+wire m;
+`M
+wire d;
+endmodule
@@ -0,0 +1,5 @@
+module test;
+`define INC `include "test31.svh"
+`INC
+wire c;
+endmodule
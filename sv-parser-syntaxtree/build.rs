@@ -17,6 +17,7 @@ static REF_NODE_FOOTER: &str = r##"
 
 static ANY_NODE_HEADER: &str = r##"
 #[derive(Clone, Debug, PartialEq, AnyNode)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AnyNode {
     Locate(Locate),
 "##;
@@ -51,15 +52,121 @@ static ANY_NODE_DISPLAY_FOOTER: &str = r##"
 }
 "##;
 
+static REF_NODE_MUT_HEADER: &str = r##"
+#[derive(Debug)]
+pub enum RefNodeMut<'a> {
+    Locate(&'a mut Locate),
+"##;
+
+static REF_NODE_MUT_FOOTER: &str = r##"
+}
+"##;
+
+static REF_NODE_MUT_ACCEPT_HEADER: &str = r##"
+impl<'a> RefNodeMut<'a> {
+    /// Call the hook of `visitor` corresponding to this node
+    pub fn accept_mut<V: VisitMut + ?Sized>(self, visitor: &mut V) {
+        match self {
+            RefNodeMut::Locate(x) => visitor.visit_locate_mut(x),
+"##;
+
+static REF_NODE_MUT_ACCEPT_FOOTER: &str = r##"
+        }
+    }
+}
+"##;
+
+static REF_NODE_MUT_NEXT_HEADER: &str = r##"
+impl<'a> RefNodeMut<'a> {
+    fn next_mut(self) -> RefNodesMut<'a> {
+        match self {
+            RefNodeMut::Locate(x) => x.next_mut(),
+"##;
+
+static REF_NODE_MUT_NEXT_FOOTER: &str = r##"
+        }
+    }
+}
+"##;
+
+static VISIT_MUT_HEADER: &str = r##"
+/// Visitor which can modify nodes in place.
+///
+/// Each hook visits the children by `walk_mut` by default.
+/// An overridden hook should call `walk_mut` to continue visiting the children.
+pub trait VisitMut {
+    fn visit_locate_mut(&mut self, _node: &mut Locate) {}
+"##;
+
+static VISIT_MUT_FOOTER: &str = r##"
+}
+"##;
+
+static REF_NODE_ACCEPT_HEADER: &str = r##"
+impl<'a> RefNode<'a> {
+    /// Call the hook of `visitor` corresponding to this node
+    pub fn accept<V: Visitor<'a> + ?Sized>(self, visitor: &mut V) -> VisitControl {
+        match self {
+            RefNode::Locate(x) => visitor.visit_locate(x),
+"##;
+
+static REF_NODE_ACCEPT_FOOTER: &str = r##"
+        }
+    }
+}
+"##;
+
+static VISITOR_HEADER: &str = r##"
+/// Visitor of nodes.
+///
+/// Each hook visits the children by the corresponding `walk_*` by default.
+/// An overridden hook can skip the children by returning `VisitControl::SkipChildren`
+/// instead of calling `walk_*`.
+pub trait Visitor<'a> {
+    fn visit_locate(&mut self, _node: &'a Locate) -> VisitControl {
+        VisitControl::Continue
+    }
+"##;
+
+static VISITOR_FOOTER: &str = r##"
+}
+"##;
+
+fn snake_case(s: &str) -> String {
+    let mut ret = String::new();
+    for (i, c) in s.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i != 0 {
+                ret.push('_');
+            }
+            ret.push(c.to_ascii_lowercase());
+        } else {
+            ret.push(c);
+        }
+    }
+    ret
+}
+
 fn main() {
     let out_dir = env::var("OUT_DIR").unwrap();
     let dest = Path::new(&out_dir).join("any_node.rs");
     let mut out = File::create(&dest).unwrap();
+    let dest_mut = Path::new(&out_dir).join("any_node_mut.rs");
+    let mut out_mut = File::create(&dest_mut).unwrap();
+    let dest_visit = Path::new(&out_dir).join("visit.rs");
+    let mut out_visit = File::create(&dest_visit).unwrap();
 
     let mut ref_node = String::from(REF_NODE_HEADER);
     let mut any_node = String::from(ANY_NODE_HEADER);
     let mut ref_node_display = String::from(REF_NODE_DISPLAY_HEADER);
     let mut any_node_display = String::from(ANY_NODE_DISPLAY_HEADER);
+    let mut ref_node_mut = String::from(REF_NODE_MUT_HEADER);
+    let mut ref_node_mut_accept = String::from(REF_NODE_MUT_ACCEPT_HEADER);
+    let mut ref_node_mut_next = String::from(REF_NODE_MUT_NEXT_HEADER);
+    let mut visit_mut = String::from(VISIT_MUT_HEADER);
+    let mut ref_node_accept = String::from(REF_NODE_ACCEPT_HEADER);
+    let mut visitor = String::from(VISITOR_HEADER);
+    let mut walks = String::new();
 
     let re_node = Regex::new(r"#\[derive.*Node.*\]").unwrap();
 
@@ -71,7 +178,7 @@ fn main() {
             let mut hit_node = false;
             for line in f.lines() {
                 let line = line.unwrap();
-                if hit_node {
+                if hit_node && !line.starts_with("#[") {
                     let name = line.split_whitespace().nth(2).unwrap().replace("<'a>", "");
                     ref_node = format!("{}    {}(&'a {}),\n", ref_node, name, name);
                     any_node = format!("{}    {}({}),\n", any_node, name, name);
@@ -83,6 +190,42 @@ fn main() {
                         "{}            AnyNode::{}(_) => write!(f, \"{}\"),\n",
                         any_node_display, name, name
                     );
+                    ref_node_mut = format!("{}    {}(&'a mut {}),\n", ref_node_mut, name, name);
+                    ref_node_mut_accept = format!(
+                        "{}            RefNodeMut::{}(x) => visitor.visit_{}_mut(x),\n",
+                        ref_node_mut_accept,
+                        name,
+                        snake_case(&name)
+                    );
+                    ref_node_mut_next = format!(
+                        "{}            RefNodeMut::{}(x) => x.next_mut(),\n",
+                        ref_node_mut_next, name
+                    );
+                    ref_node_accept = format!(
+                        "{}            RefNode::{}(x) => visitor.visit_{}(x),\n",
+                        ref_node_accept,
+                        name,
+                        snake_case(&name)
+                    );
+                    visitor = format!(
+                        "{}\n    fn visit_{}(&mut self, node: &'a {}) -> VisitControl {{\n        walk_{}(self, node)\n    }}\n",
+                        visitor,
+                        snake_case(&name),
+                        name,
+                        snake_case(&name)
+                    );
+                    walks = format!(
+                        "{}\npub fn walk_{}<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, node: &'a {}) -> VisitControl {{\n    walk(visitor, node)\n}}\n",
+                        walks,
+                        snake_case(&name),
+                        name
+                    );
+                    visit_mut = format!(
+                        "{}\n    fn visit_{}_mut(&mut self, node: &mut {}) {{\n        walk_mut(self, node)\n    }}\n",
+                        visit_mut,
+                        snake_case(&name),
+                        name
+                    );
                     hit_node = false;
                 }
                 if re_node.is_match(&line) {
@@ -100,4 +243,19 @@ fn main() {
     let _ = write!(out, "{}", any_node);
     let _ = write!(out, "{}", ref_node_display);
     let _ = write!(out, "{}", any_node_display);
+
+    ref_node_mut = format!("{}{}\n", ref_node_mut, REF_NODE_MUT_FOOTER);
+    ref_node_mut_accept = format!("{}{}\n", ref_node_mut_accept, REF_NODE_MUT_ACCEPT_FOOTER);
+    ref_node_mut_next = format!("{}{}\n", ref_node_mut_next, REF_NODE_MUT_NEXT_FOOTER);
+    visit_mut = format!("{}{}\n", visit_mut, VISIT_MUT_FOOTER);
+    let _ = write!(out_mut, "{}", ref_node_mut);
+    let _ = write!(out_mut, "{}", ref_node_mut_accept);
+    let _ = write!(out_mut, "{}", ref_node_mut_next);
+    let _ = write!(out_mut, "{}", visit_mut);
+
+    ref_node_accept = format!("{}{}\n", ref_node_accept, REF_NODE_ACCEPT_FOOTER);
+    visitor = format!("{}{}\n", visitor, VISITOR_FOOTER);
+    let _ = write!(out_visit, "{}", ref_node_accept);
+    let _ = write!(out_visit, "{}", visitor);
+    let _ = write!(out_visit, "{}", walks);
 }
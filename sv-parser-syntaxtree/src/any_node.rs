@@ -100,6 +100,12 @@ impl<'a> From<Vec<RefNode<'a>>> for RefNodes<'a> {
     }
 }
 
+impl<'a> From<&'a AnyNode> for RefNodes<'a> {
+    fn from(x: &'a AnyNode) -> Self {
+        vec![x.into()].into()
+    }
+}
+
 impl<'a> From<&'a Locate> for RefNodes<'a> {
     fn from(x: &'a Locate) -> Self {
         vec![RefNode::Locate(x)].into()
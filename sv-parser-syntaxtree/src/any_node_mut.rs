@@ -0,0 +1,454 @@
+use crate::*;
+
+// -----------------------------------------------------------------------------
+
+include!(concat!(env!("OUT_DIR"), "/any_node_mut.rs"));
+
+// -----------------------------------------------------------------------------
+
+pub struct RefNodesMut<'a>(pub Vec<RefNodeMut<'a>>);
+
+pub trait NodeMut<'a> {
+    fn next_mut(&'a mut self) -> RefNodesMut<'a>;
+}
+
+impl<'a> NodeMut<'a> for Locate {
+    fn next_mut(&'a mut self) -> RefNodesMut<'a> {
+        vec![].into()
+    }
+}
+
+/// Iterator of all `Locate`s under a node in the order of the source text
+///
+/// Only `Locate`s are returned because a node and its children can't be borrowed mutably
+/// at the same time. `VisitMut` can modify other nodes.
+pub struct IterMut<'a> {
+    next: RefNodesMut<'a>,
+}
+
+impl<'a> IterMut<'a> {
+    pub fn new(mut next: RefNodesMut<'a>) -> Self {
+        next.0.reverse();
+        IterMut { next }
+    }
+}
+
+impl<'a> Iterator for IterMut<'a> {
+    type Item = &'a mut Locate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(x) = self.next.0.pop() {
+            match x {
+                RefNodeMut::Locate(x) => return Some(x),
+                x => {
+                    let mut x = x.next_mut();
+                    x.0.reverse();
+                    self.next.0.append(&mut x.0);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Visit the children of `node` by `visitor`
+pub fn walk_mut<'a, V: VisitMut + ?Sized, T: NodeMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &'a mut T,
+) {
+    for x in node.next_mut().0 {
+        x.accept_mut(visitor);
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+impl<'a> From<Vec<RefNodeMut<'a>>> for RefNodesMut<'a> {
+    fn from(x: Vec<RefNodeMut<'a>>) -> Self {
+        RefNodesMut(x)
+    }
+}
+
+impl<'a> From<&'a mut Locate> for RefNodesMut<'a> {
+    fn from(x: &'a mut Locate) -> Self {
+        vec![RefNodeMut::Locate(x)].into()
+    }
+}
+
+impl<'a, T: 'a> From<&'a mut Vec<T>> for RefNodesMut<'a>
+where
+    &'a mut T: Into<RefNodesMut<'a>>,
+{
+    fn from(x: &'a mut Vec<T>) -> Self {
+        let mut ret = Vec::new();
+        for x in x {
+            ret.append(&mut x.into().0);
+        }
+        ret.into()
+    }
+}
+
+impl<'a, T: 'a> From<&'a mut Option<T>> for RefNodesMut<'a>
+where
+    &'a mut T: Into<RefNodesMut<'a>>,
+{
+    fn from(x: &'a mut Option<T>) -> Self {
+        let mut ret = Vec::new();
+        if let Some(x) = x {
+            ret.append(&mut x.into().0);
+        }
+        ret.into()
+    }
+}
+
+impl<'a, T0: 'a> From<&'a mut (T0,)> for RefNodesMut<'a>
+where
+    &'a mut T0: Into<RefNodesMut<'a>>,
+{
+    fn from(x: &'a mut (T0,)) -> Self {
+        let mut ret = Vec::new();
+        let (t0,) = x;
+        ret.append(&mut t0.into().0);
+        ret.into()
+    }
+}
+
+impl<'a, T0: 'a, T1: 'a> From<&'a mut (T0, T1)> for RefNodesMut<'a>
+where
+    &'a mut T0: Into<RefNodesMut<'a>>,
+    &'a mut T1: Into<RefNodesMut<'a>>,
+{
+    fn from(x: &'a mut (T0, T1)) -> Self {
+        let mut ret = Vec::new();
+        let (t0, t1) = x;
+        ret.append(&mut t0.into().0);
+        ret.append(&mut t1.into().0);
+        ret.into()
+    }
+}
+
+impl<'a, T0: 'a, T1: 'a, T2: 'a> From<&'a mut (T0, T1, T2)> for RefNodesMut<'a>
+where
+    &'a mut T0: Into<RefNodesMut<'a>>,
+    &'a mut T1: Into<RefNodesMut<'a>>,
+    &'a mut T2: Into<RefNodesMut<'a>>,
+{
+    fn from(x: &'a mut (T0, T1, T2)) -> Self {
+        let mut ret = Vec::new();
+        let (t0, t1, t2) = x;
+        ret.append(&mut t0.into().0);
+        ret.append(&mut t1.into().0);
+        ret.append(&mut t2.into().0);
+        ret.into()
+    }
+}
+
+impl<'a, T0: 'a, T1: 'a, T2: 'a, T3: 'a> From<&'a mut (T0, T1, T2, T3)> for RefNodesMut<'a>
+where
+    &'a mut T0: Into<RefNodesMut<'a>>,
+    &'a mut T1: Into<RefNodesMut<'a>>,
+    &'a mut T2: Into<RefNodesMut<'a>>,
+    &'a mut T3: Into<RefNodesMut<'a>>,
+{
+    fn from(x: &'a mut (T0, T1, T2, T3)) -> Self {
+        let mut ret = Vec::new();
+        let (t0, t1, t2, t3) = x;
+        ret.append(&mut t0.into().0);
+        ret.append(&mut t1.into().0);
+        ret.append(&mut t2.into().0);
+        ret.append(&mut t3.into().0);
+        ret.into()
+    }
+}
+
+impl<'a, T0: 'a, T1: 'a, T2: 'a, T3: 'a, T4: 'a> From<&'a mut (T0, T1, T2, T3, T4)>
+    for RefNodesMut<'a>
+where
+    &'a mut T0: Into<RefNodesMut<'a>>,
+    &'a mut T1: Into<RefNodesMut<'a>>,
+    &'a mut T2: Into<RefNodesMut<'a>>,
+    &'a mut T3: Into<RefNodesMut<'a>>,
+    &'a mut T4: Into<RefNodesMut<'a>>,
+{
+    fn from(x: &'a mut (T0, T1, T2, T3, T4)) -> Self {
+        let mut ret = Vec::new();
+        let (t0, t1, t2, t3, t4) = x;
+        ret.append(&mut t0.into().0);
+        ret.append(&mut t1.into().0);
+        ret.append(&mut t2.into().0);
+        ret.append(&mut t3.into().0);
+        ret.append(&mut t4.into().0);
+        ret.into()
+    }
+}
+
+impl<'a, T0: 'a, T1: 'a, T2: 'a, T3: 'a, T4: 'a, T5: 'a> From<&'a mut (T0, T1, T2, T3, T4, T5)>
+    for RefNodesMut<'a>
+where
+    &'a mut T0: Into<RefNodesMut<'a>>,
+    &'a mut T1: Into<RefNodesMut<'a>>,
+    &'a mut T2: Into<RefNodesMut<'a>>,
+    &'a mut T3: Into<RefNodesMut<'a>>,
+    &'a mut T4: Into<RefNodesMut<'a>>,
+    &'a mut T5: Into<RefNodesMut<'a>>,
+{
+    fn from(x: &'a mut (T0, T1, T2, T3, T4, T5)) -> Self {
+        let mut ret = Vec::new();
+        let (t0, t1, t2, t3, t4, t5) = x;
+        ret.append(&mut t0.into().0);
+        ret.append(&mut t1.into().0);
+        ret.append(&mut t2.into().0);
+        ret.append(&mut t3.into().0);
+        ret.append(&mut t4.into().0);
+        ret.append(&mut t5.into().0);
+        ret.into()
+    }
+}
+
+impl<'a, T0: 'a, T1: 'a, T2: 'a, T3: 'a, T4: 'a, T5: 'a, T6: 'a>
+    From<&'a mut (T0, T1, T2, T3, T4, T5, T6)> for RefNodesMut<'a>
+where
+    &'a mut T0: Into<RefNodesMut<'a>>,
+    &'a mut T1: Into<RefNodesMut<'a>>,
+    &'a mut T2: Into<RefNodesMut<'a>>,
+    &'a mut T3: Into<RefNodesMut<'a>>,
+    &'a mut T4: Into<RefNodesMut<'a>>,
+    &'a mut T5: Into<RefNodesMut<'a>>,
+    &'a mut T6: Into<RefNodesMut<'a>>,
+{
+    fn from(x: &'a mut (T0, T1, T2, T3, T4, T5, T6)) -> Self {
+        let mut ret = Vec::new();
+        let (t0, t1, t2, t3, t4, t5, t6) = x;
+        ret.append(&mut t0.into().0);
+        ret.append(&mut t1.into().0);
+        ret.append(&mut t2.into().0);
+        ret.append(&mut t3.into().0);
+        ret.append(&mut t4.into().0);
+        ret.append(&mut t5.into().0);
+        ret.append(&mut t6.into().0);
+        ret.into()
+    }
+}
+
+impl<'a, T0: 'a, T1: 'a, T2: 'a, T3: 'a, T4: 'a, T5: 'a, T6: 'a, T7: 'a>
+    From<&'a mut (T0, T1, T2, T3, T4, T5, T6, T7)> for RefNodesMut<'a>
+where
+    &'a mut T0: Into<RefNodesMut<'a>>,
+    &'a mut T1: Into<RefNodesMut<'a>>,
+    &'a mut T2: Into<RefNodesMut<'a>>,
+    &'a mut T3: Into<RefNodesMut<'a>>,
+    &'a mut T4: Into<RefNodesMut<'a>>,
+    &'a mut T5: Into<RefNodesMut<'a>>,
+    &'a mut T6: Into<RefNodesMut<'a>>,
+    &'a mut T7: Into<RefNodesMut<'a>>,
+{
+    fn from(x: &'a mut (T0, T1, T2, T3, T4, T5, T6, T7)) -> Self {
+        let mut ret = Vec::new();
+        let (t0, t1, t2, t3, t4, t5, t6, t7) = x;
+        ret.append(&mut t0.into().0);
+        ret.append(&mut t1.into().0);
+        ret.append(&mut t2.into().0);
+        ret.append(&mut t3.into().0);
+        ret.append(&mut t4.into().0);
+        ret.append(&mut t5.into().0);
+        ret.append(&mut t6.into().0);
+        ret.append(&mut t7.into().0);
+        ret.into()
+    }
+}
+
+impl<'a, T0: 'a, T1: 'a, T2: 'a, T3: 'a, T4: 'a, T5: 'a, T6: 'a, T7: 'a, T8: 'a>
+    From<&'a mut (T0, T1, T2, T3, T4, T5, T6, T7, T8)> for RefNodesMut<'a>
+where
+    &'a mut T0: Into<RefNodesMut<'a>>,
+    &'a mut T1: Into<RefNodesMut<'a>>,
+    &'a mut T2: Into<RefNodesMut<'a>>,
+    &'a mut T3: Into<RefNodesMut<'a>>,
+    &'a mut T4: Into<RefNodesMut<'a>>,
+    &'a mut T5: Into<RefNodesMut<'a>>,
+    &'a mut T6: Into<RefNodesMut<'a>>,
+    &'a mut T7: Into<RefNodesMut<'a>>,
+    &'a mut T8: Into<RefNodesMut<'a>>,
+{
+    fn from(x: &'a mut (T0, T1, T2, T3, T4, T5, T6, T7, T8)) -> Self {
+        let mut ret = Vec::new();
+        let (t0, t1, t2, t3, t4, t5, t6, t7, t8) = x;
+        ret.append(&mut t0.into().0);
+        ret.append(&mut t1.into().0);
+        ret.append(&mut t2.into().0);
+        ret.append(&mut t3.into().0);
+        ret.append(&mut t4.into().0);
+        ret.append(&mut t5.into().0);
+        ret.append(&mut t6.into().0);
+        ret.append(&mut t7.into().0);
+        ret.append(&mut t8.into().0);
+        ret.into()
+    }
+}
+
+impl<'a, T0: 'a, T1: 'a, T2: 'a, T3: 'a, T4: 'a, T5: 'a, T6: 'a, T7: 'a, T8: 'a, T9: 'a>
+    From<&'a mut (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9)> for RefNodesMut<'a>
+where
+    &'a mut T0: Into<RefNodesMut<'a>>,
+    &'a mut T1: Into<RefNodesMut<'a>>,
+    &'a mut T2: Into<RefNodesMut<'a>>,
+    &'a mut T3: Into<RefNodesMut<'a>>,
+    &'a mut T4: Into<RefNodesMut<'a>>,
+    &'a mut T5: Into<RefNodesMut<'a>>,
+    &'a mut T6: Into<RefNodesMut<'a>>,
+    &'a mut T7: Into<RefNodesMut<'a>>,
+    &'a mut T8: Into<RefNodesMut<'a>>,
+    &'a mut T9: Into<RefNodesMut<'a>>,
+{
+    fn from(x: &'a mut (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9)) -> Self {
+        let mut ret = Vec::new();
+        let (t0, t1, t2, t3, t4, t5, t6, t7, t8, t9) = x;
+        ret.append(&mut t0.into().0);
+        ret.append(&mut t1.into().0);
+        ret.append(&mut t2.into().0);
+        ret.append(&mut t3.into().0);
+        ret.append(&mut t4.into().0);
+        ret.append(&mut t5.into().0);
+        ret.append(&mut t6.into().0);
+        ret.append(&mut t7.into().0);
+        ret.append(&mut t8.into().0);
+        ret.append(&mut t9.into().0);
+        ret.into()
+    }
+}
+
+impl<
+        'a,
+        T0: 'a,
+        T1: 'a,
+        T2: 'a,
+        T3: 'a,
+        T4: 'a,
+        T5: 'a,
+        T6: 'a,
+        T7: 'a,
+        T8: 'a,
+        T9: 'a,
+        T10: 'a,
+    > From<&'a mut (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10)> for RefNodesMut<'a>
+where
+    &'a mut T0: Into<RefNodesMut<'a>>,
+    &'a mut T1: Into<RefNodesMut<'a>>,
+    &'a mut T2: Into<RefNodesMut<'a>>,
+    &'a mut T3: Into<RefNodesMut<'a>>,
+    &'a mut T4: Into<RefNodesMut<'a>>,
+    &'a mut T5: Into<RefNodesMut<'a>>,
+    &'a mut T6: Into<RefNodesMut<'a>>,
+    &'a mut T7: Into<RefNodesMut<'a>>,
+    &'a mut T8: Into<RefNodesMut<'a>>,
+    &'a mut T9: Into<RefNodesMut<'a>>,
+    &'a mut T10: Into<RefNodesMut<'a>>,
+{
+    fn from(x: &'a mut (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10)) -> Self {
+        let mut ret = Vec::new();
+        let (t0, t1, t2, t3, t4, t5, t6, t7, t8, t9, t10) = x;
+        ret.append(&mut t0.into().0);
+        ret.append(&mut t1.into().0);
+        ret.append(&mut t2.into().0);
+        ret.append(&mut t3.into().0);
+        ret.append(&mut t4.into().0);
+        ret.append(&mut t5.into().0);
+        ret.append(&mut t6.into().0);
+        ret.append(&mut t7.into().0);
+        ret.append(&mut t8.into().0);
+        ret.append(&mut t9.into().0);
+        ret.append(&mut t10.into().0);
+        ret.into()
+    }
+}
+
+impl<'a, T> From<&'a mut Paren<T>> for RefNodesMut<'a>
+where
+    &'a mut T: Into<RefNodesMut<'a>>,
+{
+    fn from(x: &'a mut Paren<T>) -> Self {
+        let mut ret = Vec::new();
+        let (a, b, c) = &mut x.nodes;
+        let mut a: RefNodesMut<'a> = a.into();
+        let mut c: RefNodesMut<'a> = c.into();
+        ret.append(&mut a.0);
+        ret.append(&mut b.into().0);
+        ret.append(&mut c.0);
+        ret.into()
+    }
+}
+
+impl<'a, T> From<&'a mut Brace<T>> for RefNodesMut<'a>
+where
+    &'a mut T: Into<RefNodesMut<'a>>,
+{
+    fn from(x: &'a mut Brace<T>) -> Self {
+        let mut ret = Vec::new();
+        let (a, b, c) = &mut x.nodes;
+        let mut a: RefNodesMut<'a> = a.into();
+        let mut c: RefNodesMut<'a> = c.into();
+        ret.append(&mut a.0);
+        ret.append(&mut b.into().0);
+        ret.append(&mut c.0);
+        ret.into()
+    }
+}
+
+impl<'a, T> From<&'a mut Bracket<T>> for RefNodesMut<'a>
+where
+    &'a mut T: Into<RefNodesMut<'a>>,
+{
+    fn from(x: &'a mut Bracket<T>) -> Self {
+        let mut ret = Vec::new();
+        let (a, b, c) = &mut x.nodes;
+        let mut a: RefNodesMut<'a> = a.into();
+        let mut c: RefNodesMut<'a> = c.into();
+        ret.append(&mut a.0);
+        ret.append(&mut b.into().0);
+        ret.append(&mut c.0);
+        ret.into()
+    }
+}
+
+impl<'a, T> From<&'a mut ApostropheBrace<T>> for RefNodesMut<'a>
+where
+    &'a mut T: Into<RefNodesMut<'a>>,
+{
+    fn from(x: &'a mut ApostropheBrace<T>) -> Self {
+        let mut ret = Vec::new();
+        let (a, b, c) = &mut x.nodes;
+        let mut a: RefNodesMut<'a> = a.into();
+        let mut c: RefNodesMut<'a> = c.into();
+        ret.append(&mut a.0);
+        ret.append(&mut b.into().0);
+        ret.append(&mut c.0);
+        ret.into()
+    }
+}
+
+impl<'a, T, U> From<&'a mut List<T, U>> for RefNodesMut<'a>
+where
+    &'a mut T: Into<RefNodesMut<'a>>,
+    &'a mut U: Into<RefNodesMut<'a>>,
+{
+    fn from(x: &'a mut List<T, U>) -> Self {
+        let mut ret = Vec::new();
+        let (t, u) = &mut x.nodes;
+        let mut u: RefNodesMut<'a> = u.into();
+        ret.append(&mut t.into().0);
+        ret.append(&mut u.0);
+        ret.into()
+    }
+}
+
+impl<'a, T: 'a> From<&'a mut Box<T>> for RefNodesMut<'a>
+where
+    &'a mut T: Into<RefNodesMut<'a>>,
+{
+    fn from(x: &'a mut Box<T>) -> Self {
+        let mut ret = Vec::new();
+        let mut x: RefNodesMut<'a> = (&mut **x).into();
+        ret.append(&mut x.0);
+        ret.into()
+    }
+}
@@ -3,12 +3,14 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AssertionItem {
     Concurrent(Box<ConcurrentAssertionItem>),
     Immediate(Box<DeferredImmediateAssetionItem>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DeferredImmediateAssetionItem {
     pub nodes: (
         Option<(BlockIdentifier, Symbol)>,
@@ -17,6 +19,7 @@ pub struct DeferredImmediateAssetionItem {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ProceduralAssertionStatement {
     Concurrent(Box<ConcurrentAssertionStatement>),
     Immediate(Box<ImmediateAssetionStatement>),
@@ -24,12 +27,14 @@ pub enum ProceduralAssertionStatement {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ImmediateAssetionStatement {
     Simple(Box<SimpleImmediateAssertionStatement>),
     Deferred(Box<DeferredImmediateAssertionStatement>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SimpleImmediateAssertionStatement {
     Assert(Box<SimpleImmediateAssertStatement>),
     Assume(Box<SimpleImmediateAssumeStatement>),
@@ -37,21 +42,25 @@ pub enum SimpleImmediateAssertionStatement {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SimpleImmediateAssertStatement {
     pub nodes: (Keyword, Paren<Expression>, ActionBlock),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SimpleImmediateAssumeStatement {
     pub nodes: (Keyword, Paren<Expression>, ActionBlock),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SimpleImmediateCoverStatement {
     pub nodes: (Keyword, Paren<Expression>, StatementOrNull),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DeferredImmediateAssertionStatement {
     Assert(Box<DeferredImmediateAssertStatement>),
     Assume(Box<DeferredImmediateAssumeStatement>),
@@ -59,21 +68,25 @@ pub enum DeferredImmediateAssertionStatement {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DeferredImmediateAssertStatement {
     pub nodes: (Keyword, AssertTiming, Paren<Expression>, ActionBlock),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DeferredImmediateAssumeStatement {
     pub nodes: (Keyword, AssertTiming, Paren<Expression>, ActionBlock),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DeferredImmediateCoverStatement {
     pub nodes: (Keyword, AssertTiming, Paren<Expression>, StatementOrNull),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AssertTiming {
     Zero(Box<Symbol>),
     Final(Box<Keyword>),
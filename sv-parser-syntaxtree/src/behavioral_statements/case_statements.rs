@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CaseStatement {
     Normal(Box<CaseStatementNormal>),
     Matches(Box<CaseStatementMatches>),
@@ -10,6 +11,7 @@ pub enum CaseStatement {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CaseStatementNormal {
     pub nodes: (
         Option<UniquePriority>,
@@ -22,6 +24,7 @@ pub struct CaseStatementNormal {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CaseStatementMatches {
     pub nodes: (
         Option<UniquePriority>,
@@ -35,6 +38,7 @@ pub struct CaseStatementMatches {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CaseStatementInside {
     pub nodes: (
         Option<UniquePriority>,
@@ -48,6 +52,7 @@ pub struct CaseStatementInside {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CaseKeyword {
     Case(Box<Keyword>),
     Casez(Box<Keyword>),
@@ -55,33 +60,39 @@ pub enum CaseKeyword {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CaseExpression {
     pub nodes: (Expression,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CaseItem {
     NonDefault(Box<CaseItemNondefault>),
     Default(Box<CaseItemDefault>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CaseItemNondefault {
     pub nodes: (List<Symbol, CaseItemExpression>, Symbol, StatementOrNull),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CaseItemDefault {
     pub nodes: (Keyword, Option<Symbol>, StatementOrNull),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CasePatternItem {
     NonDefault(Box<CasePatternItemNondefault>),
     Default(Box<CaseItemDefault>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CasePatternItemNondefault {
     pub nodes: (
         Pattern,
@@ -92,37 +103,44 @@ pub struct CasePatternItemNondefault {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CaseInsideItem {
     NonDefault(Box<CaseInsideItemNondefault>),
     Default(Box<CaseItemDefault>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CaseInsideItemNondefault {
     pub nodes: (OpenRangeList, Symbol, StatementOrNull),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CaseItemExpression {
     pub nodes: (Expression,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RandcaseStatement {
     pub nodes: (Keyword, RandcaseItem, Vec<RandcaseItem>, Keyword),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RandcaseItem {
     pub nodes: (Expression, Symbol, StatementOrNull),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OpenRangeList {
     pub nodes: (List<Symbol, OpenValueRange>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OpenValueRange {
     pub nodes: (ValueRange,),
 }
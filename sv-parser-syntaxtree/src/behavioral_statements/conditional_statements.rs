@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConditionalStatement {
     pub nodes: (
         Option<UniquePriority>,
@@ -15,6 +16,7 @@ pub struct ConditionalStatement {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum UniquePriority {
     Unique(Box<Keyword>),
     Unique0(Box<Keyword>),
@@ -22,17 +24,20 @@ pub enum UniquePriority {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CondPredicate {
     pub nodes: (List<Symbol, ExpressionOrCondPattern>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ExpressionOrCondPattern {
     Expression(Box<Expression>),
     CondPattern(Box<CondPattern>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CondPattern {
     pub nodes: (Expression, Keyword, Pattern),
 }
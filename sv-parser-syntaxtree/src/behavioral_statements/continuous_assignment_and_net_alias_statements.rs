@@ -3,12 +3,14 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ContinuousAssign {
     Net(Box<ContinuousAssignNet>),
     Variable(Box<ContinuousAssignVariable>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ContinuousAssignNet {
     pub nodes: (
         Keyword,
@@ -20,6 +22,7 @@ pub struct ContinuousAssignNet {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ContinuousAssignVariable {
     pub nodes: (
         Keyword,
@@ -30,21 +33,25 @@ pub struct ContinuousAssignVariable {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ListOfNetAssignments {
     pub nodes: (List<Symbol, NetAssignment>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ListOfVariableAssignments {
     pub nodes: (List<Symbol, VariableAssignment>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NetAlias {
     pub nodes: (Keyword, NetLvalue, Symbol, List<Symbol, NetLvalue>, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NetAssignment {
     pub nodes: (NetLvalue, Symbol, Expression),
 }
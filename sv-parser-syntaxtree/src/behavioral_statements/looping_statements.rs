@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum LoopStatement {
     Forever(Box<LoopStatementForever>),
     Repeat(Box<LoopStatementRepeat>),
@@ -13,21 +14,25 @@ pub enum LoopStatement {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LoopStatementForever {
     pub nodes: (Keyword, StatementOrNull),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LoopStatementRepeat {
     pub nodes: (Keyword, Paren<Expression>, StatementOrNull),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LoopStatementWhile {
     pub nodes: (Keyword, Paren<Expression>, StatementOrNull),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LoopStatementFor {
     pub nodes: (
         Keyword,
@@ -43,11 +48,13 @@ pub struct LoopStatementFor {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LoopStatementDoWhile {
     pub nodes: (Keyword, StatementOrNull, Keyword, Paren<Expression>, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LoopStatementForeach {
     pub nodes: (
         Keyword,
@@ -57,17 +64,20 @@ pub struct LoopStatementForeach {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ForInitialization {
     ListOfVariableAssignments(Box<ListOfVariableAssignments>),
     Declaration(Box<ForInitializationDeclaration>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ForInitializationDeclaration {
     pub nodes: (List<Symbol, ForVariableDeclaration>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ForVariableDeclaration {
     pub nodes: (
         Option<Var>,
@@ -77,16 +87,19 @@ pub struct ForVariableDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Var {
     pub nodes: (Keyword,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ForStep {
     pub nodes: (List<Symbol, ForStepAssignment>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ForStepAssignment {
     OperatorAssignment(Box<OperatorAssignment>),
     IncOrDecExpression(Box<IncOrDecExpression>),
@@ -94,6 +107,7 @@ pub enum ForStepAssignment {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LoopVariables {
     pub nodes: (List<Symbol, Option<IndexVariableIdentifier>>,),
 }
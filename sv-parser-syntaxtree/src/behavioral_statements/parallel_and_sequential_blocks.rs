@@ -3,17 +3,20 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ActionBlock {
     StatementOrNull(Box<StatementOrNull>),
     Else(Box<ActionBlockElse>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ActionBlockElse {
     pub nodes: (Option<Statement>, Keyword, StatementOrNull),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SeqBlock {
     pub nodes: (
         Keyword,
@@ -26,6 +29,7 @@ pub struct SeqBlock {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParBlock {
     pub nodes: (
         Keyword,
@@ -38,6 +42,7 @@ pub struct ParBlock {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum JoinKeyword {
     Join(Box<Keyword>),
     JoinAny(Box<Keyword>),
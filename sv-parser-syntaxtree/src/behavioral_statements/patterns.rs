@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Pattern {
     Variable(Box<PatternVariable>),
     Asterisk(Box<Symbol>),
@@ -13,26 +14,31 @@ pub enum Pattern {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PatternVariable {
     pub nodes: (Symbol, VariableIdentifier),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PatternTagged {
     pub nodes: (Keyword, MemberIdentifier, Option<Pattern>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PatternList {
     pub nodes: (ApostropheBrace<List<Symbol, Pattern>>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PatternIdentifierList {
     pub nodes: (ApostropheBrace<List<Symbol, (MemberIdentifier, Symbol, Pattern)>>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AssignmentPattern {
     List(Box<AssignmentPatternList>),
     Structure(Box<AssignmentPatternStructure>),
@@ -41,49 +47,58 @@ pub enum AssignmentPattern {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AssignmentPatternList {
     pub nodes: (ApostropheBrace<List<Symbol, Expression>>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AssignmentPatternStructure {
     pub nodes: (ApostropheBrace<List<Symbol, (StructurePatternKey, Symbol, Expression)>>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AssignmentPatternArray {
     pub nodes: (ApostropheBrace<List<Symbol, (ArrayPatternKey, Symbol, Expression)>>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AssignmentPatternRepeat {
     pub nodes: (ApostropheBrace<(ConstantExpression, Brace<List<Symbol, Expression>>)>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum StructurePatternKey {
     MemberIdentifier(Box<MemberIdentifier>),
     AssignmentPatternKey(Box<AssignmentPatternKey>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ArrayPatternKey {
     ConstantExpression(Box<ConstantExpression>),
     AssignmentPatternKey(Box<AssignmentPatternKey>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AssignmentPatternKey {
     SimpleType(Box<SimpleType>),
     Default(Box<Keyword>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AssignmentPatternExpression {
     pub nodes: (Option<AssignmentPatternExpressionType>, AssignmentPattern),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AssignmentPatternExpressionType {
     PsTypeIdentifier(Box<PsTypeIdentifier>),
     PsParameterIdentifier(Box<PsParameterIdentifier>),
@@ -92,16 +107,19 @@ pub enum AssignmentPatternExpressionType {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConstantAssignmentPatternExpression {
     pub nodes: (AssignmentPatternExpression,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AssignmentPatternNetLvalue {
     pub nodes: (ApostropheBrace<List<Symbol, NetLvalue>>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AssignmentPatternVariableLvalue {
     pub nodes: (ApostropheBrace<List<Symbol, VariableLvalue>>,),
 }
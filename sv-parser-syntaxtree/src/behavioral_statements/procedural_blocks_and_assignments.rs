@@ -3,16 +3,19 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InitialConstruct {
     pub nodes: (Keyword, StatementOrNull),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AlwaysConstruct {
     pub nodes: (AlwaysKeyword, Statement),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AlwaysKeyword {
     Always(Box<Keyword>),
     AlwaysComb(Box<Keyword>),
@@ -21,11 +24,13 @@ pub enum AlwaysKeyword {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FinalConstruct {
     pub nodes: (Keyword, FunctionStatement),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BlockingAssignment {
     Variable(Box<BlockingAssignmentVariable>),
     NonrangeVariable(Box<BlockingAssignmentNonrangeVariable>),
@@ -34,16 +39,19 @@ pub enum BlockingAssignment {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BlockingAssignmentVariable {
     pub nodes: (VariableLvalue, Symbol, DelayOrEventControl, Expression),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BlockingAssignmentNonrangeVariable {
     pub nodes: (NonrangeVariableLvalue, Symbol, DynamicArrayNew),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BlockingAssignmentHierarchicalVariable {
     pub nodes: (
         Option<ImplicitClassHandleOrClassScopeOrPackageScope>,
@@ -55,16 +63,19 @@ pub struct BlockingAssignmentHierarchicalVariable {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OperatorAssignment {
     pub nodes: (VariableLvalue, AssignmentOperator, Expression),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AssignmentOperator {
     pub nodes: (Symbol,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NonblockingAssignment {
     pub nodes: (
         VariableLvalue,
@@ -75,6 +86,7 @@ pub struct NonblockingAssignment {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ProceduralContinuousAssignment {
     Assign(Box<ProceduralContinuousAssignmentAssign>),
     Deassign(Box<ProceduralContinuousAssignmentDeassign>),
@@ -85,36 +97,43 @@ pub enum ProceduralContinuousAssignment {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ProceduralContinuousAssignmentAssign {
     pub nodes: (Keyword, VariableAssignment),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ProceduralContinuousAssignmentDeassign {
     pub nodes: (Keyword, VariableLvalue),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ProceduralContinuousAssignmentForceVariable {
     pub nodes: (Keyword, VariableAssignment),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ProceduralContinuousAssignmentForceNet {
     pub nodes: (Keyword, NetAssignment),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ProceduralContinuousAssignmentReleaseVariable {
     pub nodes: (Keyword, VariableLvalue),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ProceduralContinuousAssignmentReleaseNet {
     pub nodes: (Keyword, NetLvalue),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VariableAssignment {
     pub nodes: (VariableLvalue, Symbol, Expression),
 }
@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RandsequenceStatement {
     pub nodes: (
         Keyword,
@@ -14,6 +15,7 @@ pub struct RandsequenceStatement {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Production {
     pub nodes: (
         Option<DataTypeOrVoid>,
@@ -26,6 +28,7 @@ pub struct Production {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RsRule {
     pub nodes: (
         RsProductionList,
@@ -34,17 +37,20 @@ pub struct RsRule {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum RsProductionList {
     Prod(Box<RsProductionListProd>),
     Join(Box<RsProductionListJoin>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RsProductionListProd {
     pub nodes: (RsProd, Vec<RsProd>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RsProductionListJoin {
     pub nodes: (
         Keyword,
@@ -57,6 +63,7 @@ pub struct RsProductionListJoin {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum WeightSpecification {
     IntegralNumber(Box<IntegralNumber>),
     PsIdentifier(Box<PsIdentifier>),
@@ -64,16 +71,19 @@ pub enum WeightSpecification {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct WeightSpecificationExpression {
     pub nodes: (Paren<Expression>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RsCodeBlock {
     pub nodes: (Brace<(Vec<DataDeclaration>, Vec<StatementOrNull>)>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum RsProd {
     ProductionItem(Box<ProductionItem>),
     RsCodeBlock(Box<RsCodeBlock>),
@@ -83,11 +93,13 @@ pub enum RsProd {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ProductionItem {
     pub nodes: (ProductionIdentifier, Option<Paren<ListOfArguments>>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RsIfElse {
     pub nodes: (
         Keyword,
@@ -98,11 +110,13 @@ pub struct RsIfElse {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RsRepeat {
     pub nodes: (Keyword, Paren<Expression>, ProductionItem),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RsCase {
     pub nodes: (
         Keyword,
@@ -114,12 +128,14 @@ pub struct RsCase {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum RsCaseItem {
     NonDefault(Box<RsCaseItemNondefault>),
     Default(Box<RsCaseItemDefault>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RsCaseItemNondefault {
     pub nodes: (
         List<Symbol, CaseItemExpression>,
@@ -130,6 +146,7 @@ pub struct RsCaseItemNondefault {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RsCaseItemDefault {
     pub nodes: (Keyword, Option<Symbol>, ProductionItem, Symbol),
 }
@@ -3,12 +3,14 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SubroutineCallStatement {
     SubroutineCall(Box<(SubroutineCall, Symbol)>),
     Function(Box<SubroutineCallStatementFunction>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SubroutineCallStatementFunction {
     pub nodes: (Keyword, Symbol, Paren<FunctionSubroutineCall>, Symbol),
 }
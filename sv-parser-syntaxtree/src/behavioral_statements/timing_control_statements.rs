@@ -3,11 +3,13 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ProceduralTimingControlStatement {
     pub nodes: (ProceduralTimingControl, StatementOrNull),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DelayOrEventControl {
     Delay(Box<DelayControl>),
     Event(Box<EventControl>),
@@ -15,27 +17,32 @@ pub enum DelayOrEventControl {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DelayOrEventControlRepeat {
     pub nodes: (Keyword, Paren<Expression>, EventControl),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DelayControl {
     Delay(Box<DelayControlDelay>),
     Mintypmax(Box<DelayControlMintypmax>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DelayControlDelay {
     pub nodes: (Symbol, DelayValue),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DelayControlMintypmax {
     pub nodes: (Symbol, Paren<MintypmaxExpression>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum EventControl {
     EventIdentifier(Box<EventControlEventIdentifier>),
     EventExpression(Box<EventControlEventExpression>),
@@ -45,31 +52,37 @@ pub enum EventControl {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EventControlEventIdentifier {
     pub nodes: (Symbol, HierarchicalEventIdentifier),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EventControlEventExpression {
     pub nodes: (Symbol, Paren<EventExpression>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EventControlAsterisk {
     pub nodes: (Symbol,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EventControlParenAsterisk {
     pub nodes: (Symbol, Paren<Symbol>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EventControlSequenceIdentifier {
     pub nodes: (Symbol, PsOrHierarchicalSequenceIdentifier),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum EventExpression {
     Expression(Box<EventExpressionExpression>),
     Sequence(Box<EventExpressionSequence>),
@@ -79,6 +92,7 @@ pub enum EventExpression {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EventExpressionExpression {
     pub nodes: (
         Option<EdgeIdentifier>,
@@ -88,26 +102,31 @@ pub struct EventExpressionExpression {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EventExpressionSequence {
     pub nodes: (SequenceInstance, Option<(Keyword, Expression)>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EventExpressionOr {
     pub nodes: (EventExpression, Keyword, EventExpression),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EventExpressionComma {
     pub nodes: (EventExpression, Symbol, EventExpression),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EventExpressionParen {
     pub nodes: (Paren<EventExpression>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ProceduralTimingControl {
     DelayControl(Box<DelayControl>),
     EventControl(Box<EventControl>),
@@ -115,6 +134,7 @@ pub enum ProceduralTimingControl {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum JumpStatement {
     Return(Box<JumpStatementReturn>),
     Break(Box<JumpStatementBreak>),
@@ -122,21 +142,25 @@ pub enum JumpStatement {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct JumpStatementReturn {
     pub nodes: (Keyword, Option<Expression>, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct JumpStatementBreak {
     pub nodes: (Keyword, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct JumpStatementContinue {
     pub nodes: (Keyword, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum WaitStatement {
     Wait(Box<WaitStatementWait>),
     Fork(Box<WaitStatementFork>),
@@ -144,16 +168,19 @@ pub enum WaitStatement {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct WaitStatementWait {
     pub nodes: (Keyword, Paren<Expression>, StatementOrNull),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct WaitStatementFork {
     pub nodes: (Keyword, Keyword, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct WaitStatementOrder {
     pub nodes: (
         Keyword,
@@ -163,17 +190,20 @@ pub struct WaitStatementOrder {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum EventTrigger {
     Named(Box<EventTriggerNamed>),
     Nonblocking(Box<EventTriggerNonblocking>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EventTriggerNamed {
     pub nodes: (Symbol, HierarchicalEventIdentifier, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EventTriggerNonblocking {
     pub nodes: (
         Symbol,
@@ -184,6 +214,7 @@ pub struct EventTriggerNonblocking {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DisableStatement {
     Task(Box<DisableStatementTask>),
     Block(Box<DisableStatementBlock>),
@@ -191,16 +222,19 @@ pub enum DisableStatement {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DisableStatementTask {
     pub nodes: (Keyword, HierarchicalTaskIdentifier, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DisableStatementBlock {
     pub nodes: (Keyword, HierarchicalBlockIdentifier, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DisableStatementFork {
     pub nodes: (Keyword, Keyword, Symbol),
 }
@@ -3,12 +3,14 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ConcurrentAssertionItem {
     Statement(Box<ConcurrentAssertionItemStatement>),
     CheckerInstantiation(Box<CheckerInstantiation>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConcurrentAssertionItemStatement {
     pub nodes: (
         Option<(BlockIdentifier, Symbol)>,
@@ -17,6 +19,7 @@ pub struct ConcurrentAssertionItemStatement {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ConcurrentAssertionStatement {
     AssertPropertyStatement(Box<AssertPropertyStatement>),
     AssumePropertyStatement(Box<AssumePropertyStatement>),
@@ -26,26 +29,31 @@ pub enum ConcurrentAssertionStatement {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AssertPropertyStatement {
     pub nodes: (Keyword, Keyword, Paren<PropertySpec>, ActionBlock),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AssumePropertyStatement {
     pub nodes: (Keyword, Keyword, Paren<PropertySpec>, ActionBlock),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CoverPropertyStatement {
     pub nodes: (Keyword, Keyword, Paren<PropertySpec>, StatementOrNull),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ExpectPropertyStatement {
     pub nodes: (Keyword, Paren<PropertySpec>, ActionBlock),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CoverSequenceStatement {
     pub nodes: (
         Keyword,
@@ -60,11 +68,13 @@ pub struct CoverSequenceStatement {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RestrictPropertyStatement {
     pub nodes: (Keyword, Keyword, Paren<PropertySpec>, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyInstance {
     pub nodes: (
         PsOrHierarchicalPropertyIdentifier,
@@ -73,12 +83,14 @@ pub struct PropertyInstance {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PropertyListOfArguments {
     Ordered(Box<PropertyListOfArgumentsOrdered>),
     Named(Box<PropertyListOfArgumentsNamed>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyListOfArgumentsOrdered {
     pub nodes: (
         List<Symbol, Option<PropertyActualArg>>,
@@ -87,17 +99,20 @@ pub struct PropertyListOfArgumentsOrdered {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyListOfArgumentsNamed {
     pub nodes: (List<Symbol, (Symbol, Identifier, Paren<Option<PropertyActualArg>>)>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PropertyActualArg {
     PropertyExpr(Box<PropertyExpr>),
     SequenceActualArg(Box<SequenceActualArg>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AssertionItemDeclaration {
     PropertyDeclaration(Box<PropertyDeclaration>),
     SequenceDeclaration(Box<SequenceDeclaration>),
@@ -105,6 +120,7 @@ pub enum AssertionItemDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyDeclaration {
     pub nodes: (
         Keyword,
@@ -120,11 +136,13 @@ pub struct PropertyDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyPortList {
     pub nodes: (List<Symbol, PropertyPortItem>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyPortItem {
     pub nodes: (
         Vec<AttributeInstance>,
@@ -137,17 +155,20 @@ pub struct PropertyPortItem {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PropertyLvarPortDirection {
     Input(Box<Keyword>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PropertyFormalType {
     SequenceFormalType(Box<SequenceFormalType>),
     Property(Box<Keyword>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertySpec {
     pub nodes: (
         Option<ClockingEvent>,
@@ -157,6 +178,7 @@ pub struct PropertySpec {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PropertyExpr {
     SequenceExpr(Box<SequenceExpr>),
     Strong(Box<PropertyExprStrong>),
@@ -182,36 +204,43 @@ pub enum PropertyExpr {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyExprStrong {
     pub nodes: (Keyword, Paren<SequenceExpr>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyExprWeak {
     pub nodes: (Keyword, Paren<SequenceExpr>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyExprParen {
     pub nodes: (Paren<PropertyExpr>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyExprNot {
     pub nodes: (Keyword, PropertyExpr),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyExprBinaryProperty {
     pub nodes: (PropertyExpr, Keyword, PropertyExpr),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyExprBinarySequence {
     pub nodes: (SequenceExpr, Symbol, PropertyExpr),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyExprIf {
     pub nodes: (
         Keyword,
@@ -222,6 +251,7 @@ pub struct PropertyExprIf {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyExprCase {
     pub nodes: (
         Keyword,
@@ -233,16 +263,19 @@ pub struct PropertyExprCase {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyExprNexttime {
     pub nodes: (Keyword, Option<Bracket<ConstantExpression>>, PropertyExpr),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyExprSNexttime {
     pub nodes: (Keyword, Option<Bracket<ConstantExpression>>, PropertyExpr),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyExprAlways {
     pub nodes: (
         Keyword,
@@ -252,6 +285,7 @@ pub struct PropertyExprAlways {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyExprSAlways {
     pub nodes: (
         Keyword,
@@ -261,11 +295,13 @@ pub struct PropertyExprSAlways {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyExprEventually {
     pub nodes: (Keyword, Bracket<ConstantRange>, PropertyExpr),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyExprSEventually {
     pub nodes: (
         Keyword,
@@ -275,47 +311,56 @@ pub struct PropertyExprSEventually {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyExprAcceptOn {
     pub nodes: (Keyword, Paren<ExpressionOrDist>, PropertyExpr),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyExprRejectOn {
     pub nodes: (Keyword, Paren<ExpressionOrDist>, PropertyExpr),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyExprSyncAcceptOn {
     pub nodes: (Keyword, Paren<ExpressionOrDist>, PropertyExpr),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyExprSyncRejectOn {
     pub nodes: (Keyword, Paren<ExpressionOrDist>, PropertyExpr),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyExprClockingEvent {
     pub nodes: (ClockingEvent, PropertyExpr),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PropertyCaseItem {
     Nondefault(Box<PropertyCaseItemNondefault>),
     Default(Box<PropertyCaseItemDefault>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyCaseItemNondefault {
     pub nodes: (List<Symbol, ExpressionOrDist>, Symbol, PropertyExpr, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyCaseItemDefault {
     pub nodes: (Keyword, Option<Symbol>, PropertyExpr, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SequenceDeclaration {
     pub nodes: (
         Keyword,
@@ -331,11 +376,13 @@ pub struct SequenceDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SequencePortList {
     pub nodes: (List<Symbol, SequencePortItem>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SequencePortItem {
     pub nodes: (
         Vec<AttributeInstance>,
@@ -348,6 +395,7 @@ pub struct SequencePortItem {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SequenceLvarPortDirection {
     Input(Box<Keyword>),
     Inout(Box<Keyword>),
@@ -355,6 +403,7 @@ pub enum SequenceLvarPortDirection {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SequenceFormalType {
     DataTypeOrImplicit(Box<DataTypeOrImplicit>),
     Sequence(Box<Keyword>),
@@ -362,6 +411,7 @@ pub enum SequenceFormalType {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SequenceExpr {
     CycleDelayExpr(Box<SequenceExprCycleDelayExpr>),
     ExprCycleDelayExpr(Box<SequenceExprExprCycleDelayExpr>),
@@ -375,6 +425,7 @@ pub enum SequenceExpr {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SequenceExprCycleDelayExpr {
     pub nodes: (
         CycleDelayRange,
@@ -384,6 +435,7 @@ pub struct SequenceExprCycleDelayExpr {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SequenceExprExprCycleDelayExpr {
     pub nodes: (
         SequenceExpr,
@@ -394,16 +446,19 @@ pub struct SequenceExprExprCycleDelayExpr {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SequenceExprExpression {
     pub nodes: (ExpressionOrDist, Option<BooleanAbbrev>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SequenceExprInstance {
     pub nodes: (SequenceInstance, Option<SequenceAbbrev>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SequenceExprParen {
     pub nodes: (
         Paren<(SequenceExpr, Vec<(Symbol, SequenceMatchItem)>)>,
@@ -412,11 +467,13 @@ pub struct SequenceExprParen {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SequenceExprBinary {
     pub nodes: (SequenceExpr, Keyword, SequenceExpr),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SequenceExprFirstMatch {
     pub nodes: (
         Keyword,
@@ -425,16 +482,19 @@ pub struct SequenceExprFirstMatch {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SequenceExprThroughout {
     pub nodes: (ExpressionOrDist, Keyword, SequenceExpr),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SequenceExprClockingEvent {
     pub nodes: (ClockingEvent, SequenceExpr),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CycleDelayRange {
     Primary(Box<CycleDelayRangePrimary>),
     Expression(Box<CycleDelayRangeExpression>),
@@ -443,31 +503,37 @@ pub enum CycleDelayRange {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CycleDelayRangePrimary {
     pub nodes: (Symbol, ConstantPrimary),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CycleDelayRangeExpression {
     pub nodes: (Symbol, Bracket<CycleDelayConstRangeExpression>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CycleDelayRangeAsterisk {
     pub nodes: (Symbol, Bracket<Symbol>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CycleDelayRangePlus {
     pub nodes: (Symbol, Bracket<Symbol>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SequenceMethodCall {
     pub nodes: (SequenceInstance, Symbol, MethodIdentifier),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SequenceMatchItem {
     OperatorAssignment(Box<OperatorAssignment>),
     IncOrDecExpression(Box<IncOrDecExpression>),
@@ -475,6 +541,7 @@ pub enum SequenceMatchItem {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SequenceInstance {
     pub nodes: (
         PsOrHierarchicalSequenceIdentifier,
@@ -483,12 +550,14 @@ pub struct SequenceInstance {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SequenceListOfArguments {
     Ordered(Box<SequenceListOfArgumentsOrdered>),
     Named(Box<SequenceListOfArgumentsNamed>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SequenceListOfArgumentsOrdered {
     pub nodes: (
         List<Symbol, Option<SequenceActualArg>>,
@@ -497,17 +566,20 @@ pub struct SequenceListOfArgumentsOrdered {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SequenceListOfArgumentsNamed {
     pub nodes: (List<Symbol, (Symbol, Identifier, Paren<Option<SequenceActualArg>>)>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SequenceActualArg {
     EventExpression(Box<EventExpression>),
     SequenceExpr(Box<SequenceExpr>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BooleanAbbrev {
     ConsecutiveRepetition(Box<ConsecutiveRepetition>),
     NonConsecutiveRepetition(Box<NonConsecutiveRepetition>),
@@ -515,11 +587,13 @@ pub enum BooleanAbbrev {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SequenceAbbrev {
     pub nodes: (ConsecutiveRepetition,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ConsecutiveRepetition {
     Expression(Box<ConsecutiveRepetitionExpression>),
     Asterisk(Box<ConsecutiveRepetitionAsterisk>),
@@ -527,58 +601,69 @@ pub enum ConsecutiveRepetition {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConsecutiveRepetitionExpression {
     pub nodes: (Bracket<(Symbol, ConstOrRangeExpression)>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConsecutiveRepetitionAsterisk {
     pub nodes: (Bracket<Symbol>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConsecutiveRepetitionPlus {
     pub nodes: (Bracket<Symbol>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NonConsecutiveRepetition {
     pub nodes: (Bracket<(Symbol, ConstOrRangeExpression)>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GotoRepetition {
     pub nodes: (Bracket<(Symbol, ConstOrRangeExpression)>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ConstOrRangeExpression {
     ConstantExpression(Box<ConstantExpression>),
     CycleDelayConstRangeExpression(Box<CycleDelayConstRangeExpression>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CycleDelayConstRangeExpression {
     Binary(Box<CycleDelayConstRangeExpressionBinary>),
     Dollar(Box<CycleDelayConstRangeExpressionDollar>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CycleDelayConstRangeExpressionBinary {
     pub nodes: (ConstantExpression, Symbol, ConstantExpression),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CycleDelayConstRangeExpressionDollar {
     pub nodes: (ConstantExpression, Symbol, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ExpressionOrDist {
     pub nodes: (Expression, Option<(Keyword, Brace<DistList>)>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AssertionVariableDeclaration {
     pub nodes: (VarDataType, ListOfVariableDeclAssignments, Symbol),
 }
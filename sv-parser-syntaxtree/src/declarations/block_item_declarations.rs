@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BlockItemDeclaration {
     Data(Box<BlockItemDeclarationData>),
     LocalParameter(Box<BlockItemDeclarationLocalParameter>),
@@ -11,21 +12,25 @@ pub enum BlockItemDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BlockItemDeclarationData {
     pub nodes: (Vec<AttributeInstance>, DataDeclaration),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BlockItemDeclarationLocalParameter {
     pub nodes: (Vec<AttributeInstance>, LocalParameterDeclaration, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BlockItemDeclarationParameter {
     pub nodes: (Vec<AttributeInstance>, ParameterDeclaration, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BlockItemDeclarationLet {
     pub nodes: (Vec<AttributeInstance>, LetDeclaration),
 }
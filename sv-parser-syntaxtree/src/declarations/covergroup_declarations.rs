@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CovergroupDeclaration {
     pub nodes: (
         Keyword,
@@ -17,33 +18,39 @@ pub struct CovergroupDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CoverageSpecOrOption {
     Spec(Box<CoverageSpecOrOptionSpec>),
     Option(Box<CoverageSpecOrOptionOption>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CoverageSpecOrOptionSpec {
     pub nodes: (Vec<AttributeInstance>, CoverageSpec),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CoverageSpecOrOptionOption {
     pub nodes: (Vec<AttributeInstance>, CoverageOption, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CoverageOption {
     Option(Box<CoverageOptionOption>),
     TypeOption(Box<CoverageOptionTypeOption>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CoverageOptionOption {
     pub nodes: (Keyword, Symbol, MemberIdentifier, Symbol, Expression),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CoverageOptionTypeOption {
     pub nodes: (
         Keyword,
@@ -55,12 +62,14 @@ pub struct CoverageOptionTypeOption {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CoverageSpec {
     CoverPoint(Box<CoverPoint>),
     CoverCross(Box<CoverCross>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CoverageEvent {
     ClockingEvent(Box<ClockingEvent>),
     Sample(Box<CoverageEventSample>),
@@ -68,16 +77,19 @@ pub enum CoverageEvent {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CoverageEventSample {
     pub nodes: (Keyword, Keyword, Keyword, Paren<Option<TfPortList>>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CoverageEventAt {
     pub nodes: (Symbol, Paren<BlockEventExpression>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BlockEventExpression {
     Or(Box<BlockEventExpressionOr>),
     Begin(Box<BlockEventExpressionBegin>),
@@ -85,21 +97,25 @@ pub enum BlockEventExpression {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BlockEventExpressionOr {
     pub nodes: (BlockEventExpression, Keyword, BlockEventExpression),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BlockEventExpressionBegin {
     pub nodes: (Keyword, HierarchicalBtfIdentifier),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BlockEventExpressionEnd {
     pub nodes: (Keyword, HierarchicalBtfIdentifier),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum HierarchicalBtfIdentifier {
     HierarchicalTfIdentifier(Box<HierarchicalTfIdentifier>),
     HierarchicalBlockIdentifier(Box<HierarchicalBlockIdentifier>),
@@ -107,17 +123,20 @@ pub enum HierarchicalBtfIdentifier {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct HierarchicalBtfIdentifierMethod {
     pub nodes: (Option<HierarchicalIdentifierOrClassScope>, MethodIdentifier),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum HierarchicalIdentifierOrClassScope {
     HierarchicalIdentifier(Box<(HierarchicalIdentifier, Symbol)>),
     ClassScope(Box<ClassScope>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CoverPoint {
     pub nodes: (
         Option<(Option<DataTypeOrImplicit>, CoverPointIdentifier, Symbol)>,
@@ -129,17 +148,20 @@ pub struct CoverPoint {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BinsOrEmpty {
     NonEmpty(Box<BinsOrEmptyNonEmpty>),
     Empty(Box<Symbol>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BinsOrEmptyNonEmpty {
     pub nodes: (Brace<(Vec<AttributeInstance>, Vec<(BinsOrOptions, Symbol)>)>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BinsOrOptions {
     CoverageOption(Box<CoverageOption>),
     Covergroup(Box<BinsOrOptionsCovergroup>),
@@ -151,6 +173,7 @@ pub enum BinsOrOptions {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BinsOrOptionsCovergroup {
     pub nodes: (
         Option<Wildcard>,
@@ -165,11 +188,13 @@ pub struct BinsOrOptionsCovergroup {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Wildcard {
     pub nodes: (Keyword,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BinsOrOptionsCoverPoint {
     pub nodes: (
         Option<Wildcard>,
@@ -185,6 +210,7 @@ pub struct BinsOrOptionsCoverPoint {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BinsOrOptionsSetCovergroup {
     pub nodes: (
         Option<Wildcard>,
@@ -198,6 +224,7 @@ pub struct BinsOrOptionsSetCovergroup {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BinsOrOptionsTransList {
     pub nodes: (
         Option<Wildcard>,
@@ -211,6 +238,7 @@ pub struct BinsOrOptionsTransList {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BinsOrOptionsDefault {
     pub nodes: (
         BinsKeyword,
@@ -223,6 +251,7 @@ pub struct BinsOrOptionsDefault {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BinsOrOptionsDefaultSequence {
     pub nodes: (
         BinsKeyword,
@@ -235,6 +264,7 @@ pub struct BinsOrOptionsDefaultSequence {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BinsKeyword {
     Bins(Box<Keyword>),
     IllegalBins(Box<Keyword>),
@@ -242,16 +272,19 @@ pub enum BinsKeyword {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TransList {
     pub nodes: (List<Symbol, Paren<TransSet>>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TransSet {
     pub nodes: (List<Symbol, TransRangeList>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TransRangeList {
     TransItem(Box<TransItem>),
     Asterisk(Box<TransRangeListAsterisk>),
@@ -260,37 +293,44 @@ pub enum TransRangeList {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TransRangeListAsterisk {
     pub nodes: (TransItem, Bracket<(Symbol, RepeatRange)>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TransRangeListArrow {
     pub nodes: (TransItem, Bracket<(Symbol, RepeatRange)>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TransRangeListEqual {
     pub nodes: (TransItem, Bracket<(Symbol, RepeatRange)>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TransItem {
     pub nodes: (CovergroupRangeList,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum RepeatRange {
     CovergroupExpression(Box<CovergroupExpression>),
     Binary(Box<RepeatRangeBinary>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RepeatRangeBinary {
     pub nodes: (CovergroupExpression, Symbol, CovergroupExpression),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CoverCross {
     pub nodes: (
         Option<(CrossIdentifier, Symbol)>,
@@ -302,50 +342,59 @@ pub struct CoverCross {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ListOfCrossItems {
     pub nodes: (CrossItem, Symbol, List<Symbol, CrossItem>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CrossItem {
     CoverPointIdentifier(Box<CoverPointIdentifier>),
     VariableIdentifier(Box<VariableIdentifier>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CrossBody {
     NonEmpty(Box<CrossBodyNonEmpty>),
     Empty(Box<Symbol>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CrossBodyNonEmpty {
     pub nodes: (Brace<Vec<CrossBodyItem>>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CrossBodyItem {
     FunctionDeclaration(Box<FunctionDeclaration>),
     BinsSelectionOrOption(Box<(BinsSelectionOrOption, Symbol)>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BinsSelectionOrOption {
     Coverage(Box<BinsSelectionOrOptionCoverage>),
     Bins(Box<BinsSelectionOrOptionBins>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BinsSelectionOrOptionCoverage {
     pub nodes: (Vec<AttributeInstance>, CoverageOption),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BinsSelectionOrOptionBins {
     pub nodes: (Vec<AttributeInstance>, BinsSelection),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BinsSelection {
     pub nodes: (
         BinsKeyword,
@@ -357,6 +406,7 @@ pub struct BinsSelection {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SelectExpression {
     SelectCondition(Box<SelectCondition>),
     Not(Box<SelectExpressionNot>),
@@ -369,26 +419,31 @@ pub enum SelectExpression {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SelectExpressionNot {
     pub nodes: (Symbol, SelectCondition),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SelectExpressionAnd {
     pub nodes: (SelectExpression, Symbol, SelectExpression),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SelectExpressionOr {
     pub nodes: (SelectExpression, Symbol, SelectExpression),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SelectExpressionParen {
     pub nodes: (Paren<SelectExpression>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SelectExpressionWith {
     pub nodes: (
         SelectExpression,
@@ -399,6 +454,7 @@ pub struct SelectExpressionWith {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SelectExpressionCrossSet {
     pub nodes: (
         CrossSetExpression,
@@ -407,6 +463,7 @@ pub struct SelectExpressionCrossSet {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SelectCondition {
     pub nodes: (
         Keyword,
@@ -416,53 +473,63 @@ pub struct SelectCondition {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BinsExpression {
     VariableIdentifier(Box<VariableIdentifier>),
     CoverPoint(Box<BinsExpressionCoverPoint>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BinsExpressionCoverPoint {
     pub nodes: (CoverPointIdentifier, Option<(Symbol, BinIdentifier)>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CovergroupRangeList {
     pub nodes: (List<Symbol, CovergroupValueRange>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CovergroupValueRange {
     CovergroupExpression(Box<CovergroupExpression>),
     Binary(Box<CovergroupValueRangeBinary>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CovergroupValueRangeBinary {
     pub nodes: (Bracket<(CovergroupExpression, Symbol, CovergroupExpression)>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct WithCovergroupExpression {
     pub nodes: (CovergroupExpression,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SetCovergroupExpression {
     pub nodes: (CovergroupExpression,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IntegerCovergroupExpression {
     pub nodes: (CovergroupExpression,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CrossSetExpression {
     pub nodes: (CovergroupExpression,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CovergroupExpression {
     pub nodes: (Expression,),
 }
@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DefparamAssignment {
     pub nodes: (
         HierarchicalParameterIdentifier,
@@ -12,6 +13,7 @@ pub struct DefparamAssignment {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NetDeclAssignment {
     pub nodes: (
         NetIdentifier,
@@ -21,6 +23,7 @@ pub struct NetDeclAssignment {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParamAssignment {
     pub nodes: (
         ParameterIdentifier,
@@ -30,28 +33,33 @@ pub struct ParamAssignment {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SpecparamAssignment {
     Mintypmax(Box<SpecparamAssignmentMintypmax>),
     PulseControlSpecparam(Box<PulseControlSpecparam>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SpecparamAssignmentMintypmax {
     pub nodes: (SpecparamIdentifier, Symbol, ConstantMintypmaxExpression),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TypeAssignment {
     pub nodes: (TypeIdentifier, Option<(Symbol, DataType)>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PulseControlSpecparam {
     WithoutDescriptor(Box<PulseControlSpecparamWithoutDescriptor>),
     WithDescriptor(Box<PulseControlSpecparamWithDescriptor>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PulseControlSpecparamWithoutDescriptor {
     pub nodes: (
         Symbol,
@@ -61,6 +69,7 @@ pub struct PulseControlSpecparamWithoutDescriptor {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PulseControlSpecparamWithDescriptor {
     pub nodes: (
         Symbol,
@@ -73,21 +82,25 @@ pub struct PulseControlSpecparamWithDescriptor {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ErrorLimitValue {
     pub nodes: (LimitValue,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RejectLimitValue {
     pub nodes: (LimitValue,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LimitValue {
     pub nodes: (ConstantMintypmaxExpression,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum VariableDeclAssignment {
     Variable(Box<VariableDeclAssignmentVariable>),
     DynamicArray(Box<VariableDeclAssignmentDynamicArray>),
@@ -95,6 +108,7 @@ pub enum VariableDeclAssignment {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VariableDeclAssignmentVariable {
     pub nodes: (
         VariableIdentifier,
@@ -104,6 +118,7 @@ pub struct VariableDeclAssignmentVariable {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VariableDeclAssignmentDynamicArray {
     pub nodes: (
         DynamicArrayVariableIdentifier,
@@ -114,27 +129,32 @@ pub struct VariableDeclAssignmentDynamicArray {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VariableDeclAssignmentClass {
     pub nodes: (ClassVariableIdentifier, (Symbol, ClassNew)),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ClassNew {
     Argument(Box<ClassNewArgument>),
     Expression(Box<ClassNewExpression>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ClassNewArgument {
     pub nodes: (Option<ClassScope>, Keyword, Option<Paren<ListOfArguments>>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ClassNewExpression {
     pub nodes: (Keyword, Expression),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DynamicArrayNew {
     pub nodes: (Keyword, Bracket<Expression>, Option<Paren<Expression>>),
 }
@@ -3,17 +3,20 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Delay3 {
     Single(Box<Delay3Single>),
     Mintypmax(Box<Delay3Mintypmax>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Delay3Single {
     pub nodes: (Symbol, DelayValue),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Delay3Mintypmax {
     pub nodes: (
         Symbol,
@@ -29,17 +32,20 @@ pub struct Delay3Mintypmax {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Delay2 {
     Single(Box<Delay2Single>),
     Mintypmax(Box<Delay2Mintypmax>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Delay2Single {
     pub nodes: (Symbol, DelayValue),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Delay2Mintypmax {
     pub nodes: (
         Symbol,
@@ -48,6 +54,7 @@ pub struct Delay2Mintypmax {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DelayValue {
     UnsignedNumber(Box<UnsignedNumber>),
     RealNumber(Box<RealNumber>),
@@ -3,23 +3,27 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FunctionDataTypeOrImplicit {
     DataTypeOrVoid(Box<DataTypeOrVoid>),
     ImplicitDataType(Box<ImplicitDataType>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FunctionDeclaration {
     pub nodes: (Keyword, Option<Lifetime>, FunctionBodyDeclaration),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FunctionBodyDeclaration {
     WithoutPort(Box<FunctionBodyDeclarationWithoutPort>),
     WithPort(Box<FunctionBodyDeclarationWithPort>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FunctionBodyDeclarationWithoutPort {
     pub nodes: (
         FunctionDataTypeOrImplicit,
@@ -34,6 +38,7 @@ pub struct FunctionBodyDeclarationWithoutPort {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FunctionBodyDeclarationWithPort {
     pub nodes: (
         FunctionDataTypeOrImplicit,
@@ -49,12 +54,14 @@ pub struct FunctionBodyDeclarationWithPort {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum InterfaceIdentifierOrClassScope {
     InterfaceIdentifier(Box<(InterfaceIdentifier, Symbol)>),
     ClassScope(Box<ClassScope>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FunctionPrototype {
     pub nodes: (
         Keyword,
@@ -65,6 +72,7 @@ pub struct FunctionPrototype {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DpiImportExport {
     ImportFunction(Box<DpiImportExportImportFunction>),
     ImportTask(Box<DpiImportExportImportTask>),
@@ -73,6 +81,7 @@ pub enum DpiImportExport {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DpiImportExportImportFunction {
     pub nodes: (
         Keyword,
@@ -85,6 +94,7 @@ pub struct DpiImportExportImportFunction {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DpiImportExportImportTask {
     pub nodes: (
         Keyword,
@@ -97,6 +107,7 @@ pub struct DpiImportExportImportTask {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DpiImportExportExportFunction {
     pub nodes: (
         Keyword,
@@ -109,6 +120,7 @@ pub struct DpiImportExportExportFunction {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DpiImportExportExportTask {
     pub nodes: (
         Keyword,
@@ -121,28 +133,33 @@ pub struct DpiImportExportExportTask {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DpiSpecString {
     DpiC(Box<Keyword>),
     Dpi(Box<Keyword>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DpiFunctionImportProperty {
     Context(Box<Keyword>),
     Pure(Box<Keyword>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DpiTaskImportProperty {
     Context(Box<Keyword>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DpiFunctionProto {
     pub nodes: (FunctionPrototype,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DpiTaskProto {
     pub nodes: (TaskPrototype,),
 }
@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LetDeclaration {
     pub nodes: (
         Keyword,
@@ -15,16 +16,19 @@ pub struct LetDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LetIdentifier {
     pub nodes: (Identifier,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LetPortList {
     pub nodes: (List<Symbol, LetPortItem>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LetPortItem {
     pub nodes: (
         Vec<AttributeInstance>,
@@ -36,12 +40,14 @@ pub struct LetPortItem {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum LetFormalType {
     DataTypeOrImplicit(Box<DataTypeOrImplicit>),
     Untyped(Box<Keyword>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LetExpression {
     pub nodes: (
         Option<PackageScope>,
@@ -51,12 +57,14 @@ pub struct LetExpression {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum LetListOfArguments {
     Ordered(Box<LetListOfArgumentsOrdered>),
     Named(Box<LetListOfArgumentsNamed>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LetListOfArgumentsOrdered {
     pub nodes: (
         List<Symbol, Option<LetActualArg>>,
@@ -65,11 +73,13 @@ pub struct LetListOfArgumentsOrdered {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LetListOfArgumentsNamed {
     pub nodes: (List<Symbol, (Symbol, Identifier, Paren<Option<LetActualArg>>)>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LetActualArg {
     pub nodes: (Expression,),
 }
@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CastingType {
     SimpleType(Box<SimpleType>),
     ConstantPrimary(Box<ConstantPrimary>),
@@ -12,6 +13,7 @@ pub enum CastingType {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DataType {
     Vector(Box<DataTypeVector>),
     Atom(Box<DataTypeAtom>),
@@ -29,16 +31,19 @@ pub enum DataType {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DataTypeVector {
     pub nodes: (IntegerVectorType, Option<Signing>, Vec<PackedDimension>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DataTypeAtom {
     pub nodes: (IntegerAtomType, Option<Signing>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DataTypeStructUnion {
     pub nodes: (
         StructUnion,
@@ -49,11 +54,13 @@ pub struct DataTypeStructUnion {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Packed {
     pub nodes: (Keyword,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DataTypeEnum {
     pub nodes: (
         Keyword,
@@ -64,6 +71,7 @@ pub struct DataTypeEnum {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DataTypeVirtual {
     pub nodes: (
         Keyword,
@@ -75,11 +83,13 @@ pub struct DataTypeVirtual {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Interface {
     pub nodes: (Keyword,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DataTypeType {
     pub nodes: (
         Option<PackageScopeOrClassScope>,
@@ -89,17 +99,20 @@ pub struct DataTypeType {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DataTypeOrImplicit {
     DataType(Box<DataType>),
     ImplicitDataType(Box<ImplicitDataType>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ImplicitDataType {
     pub nodes: (Option<Signing>, Vec<PackedDimension>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum EnumBaseType {
     Atom(Box<EnumBaseTypeAtom>),
     Vector(Box<EnumBaseTypeVector>),
@@ -107,21 +120,25 @@ pub enum EnumBaseType {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EnumBaseTypeAtom {
     pub nodes: (IntegerAtomType, Option<Signing>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EnumBaseTypeVector {
     pub nodes: (IntegerVectorType, Option<Signing>, Option<PackedDimension>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EnumBaseTypeType {
     pub nodes: (TypeIdentifier, Option<PackedDimension>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EnumNameDeclaration {
     pub nodes: (
         EnumIdentifier,
@@ -131,11 +148,13 @@ pub struct EnumNameDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ClassScope {
     pub nodes: (ClassType, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ClassType {
     pub nodes: (
         PsClassIdentifier,
@@ -145,12 +164,14 @@ pub struct ClassType {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum IntegerType {
     IntegerVectorType(Box<IntegerVectorType>),
     IntegerAtomType(Box<IntegerAtomType>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum IntegerAtomType {
     Byte(Box<Keyword>),
     Shortint(Box<Keyword>),
@@ -161,6 +182,7 @@ pub enum IntegerAtomType {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum IntegerVectorType {
     Bit(Box<Keyword>),
     Logic(Box<Keyword>),
@@ -168,6 +190,7 @@ pub enum IntegerVectorType {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NonIntegerType {
     Shortreal(Box<Keyword>),
     Real(Box<Keyword>),
@@ -175,6 +198,7 @@ pub enum NonIntegerType {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NetType {
     Supply0(Box<Keyword>),
     Supply1(Box<Keyword>),
@@ -191,6 +215,7 @@ pub enum NetType {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NetPortType {
     DataType(Box<NetPortTypeDataType>),
     NetTypeIdentifier(Box<NetTypeIdentifier>),
@@ -198,38 +223,45 @@ pub enum NetPortType {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NetPortTypeDataType {
     pub nodes: (Option<NetType>, DataTypeOrImplicit),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NetPortTypeInterconnect {
     pub nodes: (Keyword, ImplicitDataType),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VariablePortType {
     pub nodes: (VarDataType,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum VarDataType {
     DataType(Box<DataType>),
     Var(Box<VarDataTypeVar>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VarDataTypeVar {
     pub nodes: (Keyword, DataTypeOrImplicit),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Signing {
     Signed(Box<Keyword>),
     Unsigned(Box<Keyword>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SimpleType {
     IntegerType(Box<IntegerType>),
     NonIntegerType(Box<NonIntegerType>),
@@ -238,6 +270,7 @@ pub enum SimpleType {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct StructUnionMember {
     pub nodes: (
         Vec<AttributeInstance>,
@@ -249,12 +282,14 @@ pub struct StructUnionMember {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DataTypeOrVoid {
     DataType(Box<DataType>),
     Void(Box<Keyword>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum StructUnion {
     Struct(Box<Keyword>),
     Union(Box<Keyword>),
@@ -262,17 +297,20 @@ pub enum StructUnion {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TypeReference {
     Expression(Box<TypeReferenceExpression>),
     DataType(Box<TypeReferenceDataType>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TypeReferenceExpression {
     pub nodes: (Keyword, Paren<Expression>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TypeReferenceDataType {
     pub nodes: (Keyword, Paren<DataType>),
 }
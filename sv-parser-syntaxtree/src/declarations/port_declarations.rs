@@ -3,43 +3,51 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InoutDeclaration {
     pub nodes: (Keyword, NetPortType, ListOfPortIdentifiers),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum InputDeclaration {
     Net(Box<InputDeclarationNet>),
     Variable(Box<InputDeclarationVariable>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InputDeclarationNet {
     pub nodes: (Keyword, NetPortType, ListOfPortIdentifiers),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InputDeclarationVariable {
     pub nodes: (Keyword, VariablePortType, ListOfVariableIdentifiers),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum OutputDeclaration {
     Net(Box<OutputDeclarationNet>),
     Variable(Box<OutputDeclarationVariable>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OutputDeclarationNet {
     pub nodes: (Keyword, NetPortType, ListOfPortIdentifiers),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OutputDeclarationVariable {
     pub nodes: (Keyword, VariablePortType, ListOfVariablePortIdentifiers),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InterfacePortDeclaration {
     pub nodes: (
         InterfaceIdentifier,
@@ -49,6 +57,7 @@ pub struct InterfacePortDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RefDeclaration {
     pub nodes: (Keyword, VariablePortType, ListOfVariableIdentifiers),
 }
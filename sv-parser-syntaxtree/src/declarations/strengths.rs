@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DriveStrength {
     Strength01(Box<DriveStrength01>),
     Strength10(Box<DriveStrength10>),
@@ -13,36 +14,43 @@ pub enum DriveStrength {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DriveStrength01 {
     pub nodes: (Paren<(Strength0, Symbol, Strength1)>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DriveStrength10 {
     pub nodes: (Paren<(Strength1, Symbol, Strength0)>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DriveStrength0z {
     pub nodes: (Paren<(Strength0, Symbol, Keyword)>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DriveStrength1z {
     pub nodes: (Paren<(Strength1, Symbol, Keyword)>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DriveStrengthz1 {
     pub nodes: (Paren<(Keyword, Symbol, Strength1)>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DriveStrengthz0 {
     pub nodes: (Paren<(Keyword, Symbol, Strength0)>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Strength0 {
     Supply0(Box<Keyword>),
     Strong0(Box<Keyword>),
@@ -51,6 +59,7 @@ pub enum Strength0 {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Strength1 {
     Supply1(Box<Keyword>),
     Strong1(Box<Keyword>),
@@ -59,6 +68,7 @@ pub enum Strength1 {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ChargeStrength {
     Small(Box<ChargeStrengthSmall>),
     Medium(Box<ChargeStrengthMedium>),
@@ -66,16 +76,19 @@ pub enum ChargeStrength {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChargeStrengthSmall {
     pub nodes: (Paren<Keyword>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChargeStrengthMedium {
     pub nodes: (Paren<Keyword>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChargeStrengthLarge {
     pub nodes: (Paren<Keyword>,),
 }
@@ -3,17 +3,20 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TaskDeclaration {
     pub nodes: (Keyword, Option<Lifetime>, TaskBodyDeclaration),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TaskBodyDeclaration {
     WithoutPort(Box<TaskBodyDeclarationWithoutPort>),
     WithPort(Box<TaskBodyDeclarationWithPort>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TaskBodyDeclarationWithoutPort {
     pub nodes: (
         Option<InterfaceIdentifierOrClassScope>,
@@ -27,6 +30,7 @@ pub struct TaskBodyDeclarationWithoutPort {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TaskBodyDeclarationWithPort {
     pub nodes: (
         Option<InterfaceIdentifierOrClassScope>,
@@ -41,17 +45,20 @@ pub struct TaskBodyDeclarationWithPort {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TfItemDeclaration {
     BlockItemDeclaration(Box<BlockItemDeclaration>),
     TfPortDeclaration(Box<TfPortDeclaration>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TfPortList {
     pub nodes: (List<Symbol, TfPortItem>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TfPortItem {
     pub nodes: (
         Vec<AttributeInstance>,
@@ -67,12 +74,14 @@ pub struct TfPortItem {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TfPortDirection {
     PortDirection(Box<PortDirection>),
     ConstRef(Box<(Keyword, Keyword)>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TfPortDeclaration {
     pub nodes: (
         Vec<AttributeInstance>,
@@ -85,6 +94,7 @@ pub struct TfPortDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TaskPrototype {
     pub nodes: (Keyword, TaskIdentifier, Option<Paren<Option<TfPortList>>>),
 }
@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DataDeclaration {
     Variable(Box<DataDeclarationVariable>),
     TypeDeclaration(Box<TypeDeclaration>),
@@ -11,6 +12,7 @@ pub enum DataDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DataDeclarationVariable {
     pub nodes: (
         Option<Const>,
@@ -23,53 +25,63 @@ pub struct DataDeclarationVariable {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Const {
     pub nodes: (Keyword,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PackageImportDeclaration {
     pub nodes: (Keyword, List<Symbol, PackageImportItem>, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PackageImportItem {
     Identifier(Box<PackageImportItemIdentifier>),
     Asterisk(Box<PackageImportItemAsterisk>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PackageImportItemIdentifier {
     pub nodes: (PackageIdentifier, Symbol, Identifier),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PackageImportItemAsterisk {
     pub nodes: (PackageIdentifier, Symbol, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PackageExportDeclaration {
     Asterisk(Box<PackageExportDeclarationAsterisk>),
     Item(Box<PackageExportDeclarationItem>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PackageExportDeclarationAsterisk {
     pub nodes: (Keyword, Symbol, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PackageExportDeclarationItem {
     pub nodes: (Keyword, List<Symbol, PackageImportItem>, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GenvarDeclaration {
     pub nodes: (Keyword, ListOfGenvarIdentifiers, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NetDeclaration {
     NetType(Box<NetDeclarationNetType>),
     NetTypeIdentifier(Box<NetDeclarationNetTypeIdentifier>),
@@ -77,6 +89,7 @@ pub enum NetDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NetDeclarationNetType {
     pub nodes: (
         NetType,
@@ -90,18 +103,21 @@ pub struct NetDeclarationNetType {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Strength {
     Drive(Box<DriveStrength>),
     Charge(Box<ChargeStrength>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum VectorScalar {
     Vectored(Box<Keyword>),
     Scalared(Box<Keyword>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NetDeclarationNetTypeIdentifier {
     pub nodes: (
         NetTypeIdentifier,
@@ -112,6 +128,7 @@ pub struct NetDeclarationNetTypeIdentifier {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NetDeclarationInterconnect {
     pub nodes: (
         Keyword,
@@ -125,6 +142,7 @@ pub struct NetDeclarationInterconnect {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TypeDeclaration {
     DataType(Box<TypeDeclarationDataType>),
     Interface(Box<TypeDeclarationInterface>),
@@ -132,6 +150,7 @@ pub enum TypeDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TypeDeclarationDataType {
     pub nodes: (
         Keyword,
@@ -143,6 +162,7 @@ pub struct TypeDeclarationDataType {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TypeDeclarationInterface {
     pub nodes: (
         Keyword,
@@ -156,6 +176,7 @@ pub struct TypeDeclarationInterface {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TypeDeclarationReserved {
     pub nodes: (
         Keyword,
@@ -166,6 +187,7 @@ pub struct TypeDeclarationReserved {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TypeDeclarationKeyword {
     Enum(Box<Keyword>),
     Struct(Box<Keyword>),
@@ -175,12 +197,14 @@ pub enum TypeDeclarationKeyword {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NetTypeDeclaration {
     DataType(Box<NetTypeDeclarationDataType>),
     NetType(Box<NetTypeDeclarationNetType>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NetTypeDeclarationDataType {
     pub nodes: (
         Keyword,
@@ -192,6 +216,7 @@ pub struct NetTypeDeclarationDataType {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NetTypeDeclarationNetType {
     pub nodes: (
         Keyword,
@@ -203,6 +228,7 @@ pub struct NetTypeDeclarationNetType {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Lifetime {
     Static(Box<Keyword>),
     Automatic(Box<Keyword>),
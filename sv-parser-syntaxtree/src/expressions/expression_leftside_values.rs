@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NetLvalue {
     Identifier(Box<NetLvalueIdentifier>),
     Lvalue(Box<NetLvalueLvalue>),
@@ -10,16 +11,19 @@ pub enum NetLvalue {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NetLvalueIdentifier {
     pub nodes: (PsOrHierarchicalNetIdentifier, ConstantSelect),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NetLvalueLvalue {
     pub nodes: (Brace<List<Symbol, NetLvalue>>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NetLvaluePattern {
     pub nodes: (
         Option<AssignmentPatternExpressionType>,
@@ -28,6 +32,7 @@ pub struct NetLvaluePattern {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum VariableLvalue {
     Identifier(Box<VariableLvalueIdentifier>),
     Lvalue(Box<VariableLvalueLvalue>),
@@ -36,6 +41,7 @@ pub enum VariableLvalue {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VariableLvalueIdentifier {
     pub nodes: (
         Option<ImplicitClassHandleOrPackageScope>,
@@ -45,11 +51,13 @@ pub struct VariableLvalueIdentifier {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VariableLvalueLvalue {
     pub nodes: (Brace<List<Symbol, VariableLvalue>>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VariableLvaluePattern {
     pub nodes: (
         Option<AssignmentPatternExpressionType>,
@@ -58,6 +66,7 @@ pub struct VariableLvaluePattern {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NonrangeVariableLvalue {
     pub nodes: (
         Option<ImplicitClassHandleOrPackageScope>,
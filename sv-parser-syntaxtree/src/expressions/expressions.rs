@@ -3,22 +3,26 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum IncOrDecExpression {
     Prefix(Box<IncOrDecExpressionPrefix>),
     Suffix(Box<IncOrDecExpressionSuffix>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IncOrDecExpressionPrefix {
     pub nodes: (IncOrDecOperator, Vec<AttributeInstance>, VariableLvalue),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IncOrDecExpressionSuffix {
     pub nodes: (VariableLvalue, Vec<AttributeInstance>, IncOrDecOperator),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConditionalExpression {
     pub nodes: (
         CondPredicate,
@@ -31,6 +35,7 @@ pub struct ConditionalExpression {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ConstantExpression {
     ConstantPrimary(Box<ConstantPrimary>),
     Unary(Box<ConstantExpressionUnary>),
@@ -39,11 +44,13 @@ pub enum ConstantExpression {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConstantExpressionUnary {
     pub nodes: (UnaryOperator, Vec<AttributeInstance>, ConstantPrimary),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConstantExpressionBinary {
     pub nodes: (
         ConstantExpression,
@@ -54,6 +61,7 @@ pub struct ConstantExpressionBinary {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConstantExpressionTernary {
     pub nodes: (
         ConstantExpression,
@@ -66,12 +74,14 @@ pub struct ConstantExpressionTernary {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ConstantMintypmaxExpression {
     Unary(Box<ConstantExpression>),
     Ternary(Box<ConstantMintypmaxExpressionTernary>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConstantMintypmaxExpressionTernary {
     pub nodes: (
         ConstantExpression,
@@ -83,6 +93,7 @@ pub struct ConstantMintypmaxExpressionTernary {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ConstantParamExpression {
     ConstantMintypmaxExpression(Box<ConstantMintypmaxExpression>),
     DataType(Box<DataType>),
@@ -90,6 +101,7 @@ pub enum ConstantParamExpression {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ParamExpression {
     MintypmaxExpression(Box<MintypmaxExpression>),
     DataType(Box<DataType>),
@@ -97,28 +109,33 @@ pub enum ParamExpression {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ConstantRangeExpression {
     ConstantExpression(Box<ConstantExpression>),
     ConstantPartSelectRange(Box<ConstantPartSelectRange>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ConstantPartSelectRange {
     ConstantRange(Box<ConstantRange>),
     ConstantIndexedRange(Box<ConstantIndexedRange>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConstantRange {
     pub nodes: (ConstantExpression, Symbol, ConstantExpression),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConstantIndexedRange {
     pub nodes: (ConstantExpression, Symbol, ConstantExpression),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Expression {
     Primary(Box<Primary>),
     Unary(Box<ExpressionUnary>),
@@ -131,16 +148,19 @@ pub enum Expression {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ExpressionUnary {
     pub nodes: (UnaryOperator, Vec<AttributeInstance>, Primary),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ExpressionOperatorAssignment {
     pub nodes: (Paren<OperatorAssignment>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ExpressionBinary {
     pub nodes: (
         Expression,
@@ -151,38 +171,45 @@ pub struct ExpressionBinary {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TaggedUnionExpression {
     pub nodes: (Keyword, MemberIdentifier, Option<Expression>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InsideExpression {
     pub nodes: (Expression, Keyword, Brace<OpenRangeList>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ValueRange {
     Expression(Box<Expression>),
     Binary(Box<ValueRangeBinary>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ValueRangeBinary {
     pub nodes: (Bracket<(Expression, Symbol, Expression)>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum MintypmaxExpression {
     Expression(Box<Expression>),
     Ternary(Box<MintypmaxExpressionTernary>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MintypmaxExpressionTernary {
     pub nodes: (Expression, Symbol, Expression, Symbol, Expression),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ModulePathConditionalExpression {
     pub nodes: (
         ModulePathExpression,
@@ -195,6 +222,7 @@ pub struct ModulePathConditionalExpression {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ModulePathExpression {
     ModulePathPrimary(Box<ModulePathPrimary>),
     Unary(Box<ModulePathExpressionUnary>),
@@ -203,6 +231,7 @@ pub enum ModulePathExpression {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ModulePathExpressionUnary {
     pub nodes: (
         UnaryModulePathOperator,
@@ -212,6 +241,7 @@ pub struct ModulePathExpressionUnary {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ModulePathExpressionBinary {
     pub nodes: (
         ModulePathExpression,
@@ -222,12 +252,14 @@ pub struct ModulePathExpressionBinary {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ModulePathMintypmaxExpression {
     ModulePathExpression(Box<ModulePathExpression>),
     Ternary(Box<ModulePathMintypmaxExpressionTernary>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ModulePathMintypmaxExpressionTernary {
     pub nodes: (
         ModulePathExpression,
@@ -239,17 +271,20 @@ pub struct ModulePathMintypmaxExpressionTernary {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PartSelectRange {
     ConstantRange(Box<ConstantRange>),
     IndexedRange(Box<IndexedRange>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IndexedRange {
     pub nodes: (Expression, Symbol, ConstantExpression),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GenvarExpression {
     pub nodes: (ConstantExpression,),
 }
@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ConstantPrimary {
     PrimaryLiteral(Box<PrimaryLiteral>),
     PsParameter(Box<ConstantPrimaryPsParameter>),
@@ -23,11 +24,13 @@ pub enum ConstantPrimary {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConstantPrimaryPsParameter {
     pub nodes: (PsParameterIdentifier, ConstantSelect),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConstantPrimarySpecparam {
     pub nodes: (
         SpecparamIdentifier,
@@ -36,16 +39,19 @@ pub struct ConstantPrimarySpecparam {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConstantPrimaryFormalPort {
     pub nodes: (FormalPortIdentifier, ConstantSelect),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConstantPrimaryEnum {
     pub nodes: (PackageScopeOrClassScope, EnumIdentifier),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConstantPrimaryConcatenation {
     pub nodes: (
         ConstantConcatenation,
@@ -54,6 +60,7 @@ pub struct ConstantPrimaryConcatenation {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConstantPrimaryMultipleConcatenation {
     pub nodes: (
         ConstantMultipleConcatenation,
@@ -62,11 +69,13 @@ pub struct ConstantPrimaryMultipleConcatenation {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConstantPrimaryMintypmaxExpression {
     pub nodes: (Paren<ConstantMintypmaxExpression>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ModulePathPrimary {
     Number(Box<Number>),
     Identifier(Box<Identifier>),
@@ -77,11 +86,13 @@ pub enum ModulePathPrimary {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ModulePathPrimaryMintypmax {
     pub nodes: (Paren<ModulePathMintypmaxExpression>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Primary {
     PrimaryLiteral(Box<PrimaryLiteral>),
     Hierarchical(Box<PrimaryHierarchical>),
@@ -101,6 +112,7 @@ pub enum Primary {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PrimaryHierarchical {
     pub nodes: (
         Option<ClassQualifierOrPackageScope>,
@@ -110,38 +122,45 @@ pub struct PrimaryHierarchical {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PrimaryConcatenation {
     pub nodes: (Concatenation, Option<Bracket<RangeExpression>>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PrimaryMultipleConcatenation {
     pub nodes: (MultipleConcatenation, Option<Bracket<RangeExpression>>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PrimaryMintypmaxExpression {
     pub nodes: (Paren<MintypmaxExpression>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ClassQualifierOrPackageScope {
     ClassQualifier(Box<ClassQualifier>),
     PackageScope(Box<PackageScope>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ClassQualifier {
     pub nodes: (Option<Local>, Option<ImplicitClassHandleOrClassScope>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum RangeExpression {
     Expression(Box<Expression>),
     PartSelectRange(Box<PartSelectRange>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PrimaryLiteral {
     Number(Box<Number>),
     TimeLiteral(Box<TimeLiteral>),
@@ -150,22 +169,26 @@ pub enum PrimaryLiteral {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TimeLiteral {
     Unsigned(Box<TimeLiteralUnsigned>),
     FixedPoint(Box<TimeLiteralFixedPoint>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TimeLiteralUnsigned {
     pub nodes: (UnsignedNumber, TimeUnit),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TimeLiteralFixedPoint {
     pub nodes: (FixedPointNumber, TimeUnit),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TimeUnit {
     S(Box<Keyword>),
     MS(Box<Keyword>),
@@ -176,6 +199,7 @@ pub enum TimeUnit {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ImplicitClassHandle {
     This(Box<Keyword>),
     Super(Box<Keyword>),
@@ -183,11 +207,13 @@ pub enum ImplicitClassHandle {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BitSelect {
     pub nodes: (Vec<Bracket<Expression>>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Select {
     pub nodes: (
         Option<(
@@ -201,6 +227,7 @@ pub struct Select {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NonrangeSelect {
     pub nodes: (
         Option<(
@@ -213,11 +240,13 @@ pub struct NonrangeSelect {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConstantBitSelect {
     pub nodes: (Vec<Bracket<ConstantExpression>>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConstantSelect {
     pub nodes: (
         Option<(
@@ -231,16 +260,19 @@ pub struct ConstantSelect {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConstantCast {
     pub nodes: (CastingType, Symbol, Paren<ConstantExpression>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConstantLetExpression {
     pub nodes: (LetExpression,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Cast {
     pub nodes: (CastingType, Symbol, Paren<Expression>),
 }
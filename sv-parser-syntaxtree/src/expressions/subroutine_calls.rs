@@ -3,11 +3,13 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConstantFunctionCall {
     pub nodes: (FunctionSubroutineCall,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TfCall {
     pub nodes: (
         PsOrHierarchicalTfIdentifier,
@@ -17,6 +19,7 @@ pub struct TfCall {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SystemTfCall {
     ArgOptionl(Box<SystemTfCallArgOptional>),
     ArgDataType(Box<SystemTfCallArgDataType>),
@@ -24,11 +27,13 @@ pub enum SystemTfCall {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SystemTfCallArgOptional {
     pub nodes: (SystemTfIdentifier, Option<Paren<ListOfArguments>>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SystemTfCallArgDataType {
     pub nodes: (
         SystemTfIdentifier,
@@ -37,6 +42,7 @@ pub struct SystemTfCallArgDataType {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SystemTfCallArgExpression {
     pub nodes: (
         SystemTfIdentifier,
@@ -48,6 +54,7 @@ pub struct SystemTfCallArgExpression {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SubroutineCall {
     TfCall(Box<TfCall>),
     SystemTfCall(Box<SystemTfCall>),
@@ -56,22 +63,26 @@ pub enum SubroutineCall {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SubroutineCallRandomize {
     pub nodes: (Option<(Keyword, Symbol)>, RandomizeCall),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FunctionSubroutineCall {
     pub nodes: (SubroutineCall,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ListOfArguments {
     Ordered(Box<ListOfArgumentsOrdered>),
     Named(Box<ListOfArgumentsNamed>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ListOfArgumentsOrdered {
     pub nodes: (
         List<Symbol, Option<Expression>>,
@@ -80,6 +91,7 @@ pub struct ListOfArgumentsOrdered {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ListOfArgumentsNamed {
     pub nodes: (
         Symbol,
@@ -90,17 +102,20 @@ pub struct ListOfArgumentsNamed {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MethodCall {
     pub nodes: (MethodCallRoot, Symbol, MethodCallBody),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum MethodCallBody {
     User(Box<MethodCallBodyUser>),
     BuiltInMethodCall(Box<BuiltInMethodCall>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MethodCallBodyUser {
     pub nodes: (
         MethodIdentifier,
@@ -110,12 +125,14 @@ pub struct MethodCallBodyUser {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BuiltInMethodCall {
     ArrayManipulationCall(Box<ArrayManipulationCall>),
     RandomizeCall(Box<RandomizeCall>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ArrayManipulationCall {
     pub nodes: (
         ArrayMethodName,
@@ -126,6 +143,7 @@ pub struct ArrayManipulationCall {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RandomizeCall {
     pub nodes: (
         Keyword,
@@ -140,18 +158,21 @@ pub struct RandomizeCall {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum VariableIdentifierListOrNull {
     VariableIdentifierList(Box<VariableIdentifierList>),
     Null(Box<Keyword>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum MethodCallRoot {
     Primary(Box<Primary>),
     ImplicitClassHandle(Box<ImplicitClassHandle>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ArrayMethodName {
     MethodIdentifier(Box<MethodIdentifier>),
     Unique(Box<Keyword>),
@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CheckerInstantiation {
     pub nodes: (
         PsCheckerIdentifier,
@@ -13,33 +14,39 @@ pub struct CheckerInstantiation {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ListOfCheckerPortConnections {
     Ordered(Box<ListOfCheckerPortConnectionsOrdered>),
     Named(Box<ListOfCheckerPortConnectionsNamed>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ListOfCheckerPortConnectionsOrdered {
     pub nodes: (List<Symbol, OrderedCheckerPortConnection>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ListOfCheckerPortConnectionsNamed {
     pub nodes: (List<Symbol, NamedCheckerPortConnection>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OrderedCheckerPortConnection {
     pub nodes: (Vec<AttributeInstance>, Option<PropertyActualArg>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NamedCheckerPortConnection {
     Identifier(Box<NamedCheckerPortConnectionIdentifier>),
     Asterisk(Box<NamedCheckerPortConnectionAsterisk>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NamedCheckerPortConnectionIdentifier {
     pub nodes: (
         Vec<AttributeInstance>,
@@ -50,6 +57,7 @@ pub struct NamedCheckerPortConnectionIdentifier {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NamedCheckerPortConnectionAsterisk {
     pub nodes: (Vec<AttributeInstance>, Symbol),
 }
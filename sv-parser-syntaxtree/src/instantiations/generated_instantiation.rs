@@ -3,11 +3,13 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GenerateRegion {
     pub nodes: (Keyword, Vec<GenerateItem>, Keyword),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LoopGenerateConstruct {
     pub nodes: (
         Keyword,
@@ -23,16 +25,19 @@ pub struct LoopGenerateConstruct {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GenvarInitialization {
     pub nodes: (Option<Genvar>, GenvarIdentifier, Symbol, ConstantExpression),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Genvar {
     pub nodes: (Keyword,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GenvarIteration {
     Assignment(Box<GenvarIterationAssignment>),
     Prefix(Box<GenvarIterationPrefix>),
@@ -40,27 +45,32 @@ pub enum GenvarIteration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GenvarIterationAssignment {
     pub nodes: (GenvarIdentifier, AssignmentOperator, GenvarExpression),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GenvarIterationPrefix {
     pub nodes: (IncOrDecOperator, GenvarIdentifier),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GenvarIterationSuffix {
     pub nodes: (GenvarIdentifier, IncOrDecOperator),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ConditionalGenerateConstruct {
     If(Box<IfGenerateConstruct>),
     Case(Box<CaseGenerateConstruct>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IfGenerateConstruct {
     pub nodes: (
         Keyword,
@@ -71,6 +81,7 @@ pub struct IfGenerateConstruct {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CaseGenerateConstruct {
     pub nodes: (
         Keyword,
@@ -81,28 +92,33 @@ pub struct CaseGenerateConstruct {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CaseGenerateItem {
     Nondefault(Box<CaseGenerateItemNondefault>),
     Default(Box<CaseGenerateItemDefault>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CaseGenerateItemNondefault {
     pub nodes: (List<Symbol, ConstantExpression>, Symbol, GenerateBlock),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CaseGenerateItemDefault {
     pub nodes: (Keyword, Option<Symbol>, GenerateBlock),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GenerateBlock {
     GenerateItem(Box<GenerateItem>),
     Multiple(Box<GenerateBlockMultiple>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GenerateBlockMultiple {
     pub nodes: (
         Option<(GenerateBlockIdentifier, Symbol)>,
@@ -115,6 +131,7 @@ pub struct GenerateBlockMultiple {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GenerateItem {
     ModuleOrGenerateItem(Box<ModuleOrGenerateItem>),
     InterfaceOrGenerateItem(Box<InterfaceOrGenerateItem>),
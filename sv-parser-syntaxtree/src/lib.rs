@@ -6,35 +6,46 @@
 )]
 
 pub mod any_node;
+pub mod any_node_mut;
 pub mod behavioral_statements;
 pub mod declarations;
 pub mod expressions;
 pub mod general;
 pub mod instantiations;
+pub mod node_index;
 pub mod preprocessor;
 pub mod primitive_instances;
 pub mod source_text;
 pub mod special_node;
 pub mod specify_section;
 pub mod udp_declaration_and_instantiation;
+pub mod unparse;
+pub mod visit;
 pub use any_node::*;
+pub use any_node_mut::*;
 pub use behavioral_statements::*;
 pub use declarations::*;
 pub use expressions::*;
 pub use general::*;
 pub use instantiations::*;
+pub use node_index::*;
 pub use preprocessor::*;
 pub use primitive_instances::*;
 pub use source_text::*;
 pub use special_node::*;
 pub use specify_section::*;
 pub use udp_declaration_and_instantiation::*;
+pub use unparse::*;
+pub use visit::*;
 
+#[cfg(feature = "serde")]
+pub(crate) use serde::{Deserialize, Serialize};
 pub(crate) use sv_parser_macros::*;
 
 // -----------------------------------------------------------------------------
 
 #[derive(Copy, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Locate {
     pub offset: usize,
     pub line: u32,
@@ -59,6 +70,16 @@ impl<'a> Node<'a> for Locate {
     }
 }
 
+impl<'a> core::convert::TryFrom<RefNode<'a>> for &'a Locate {
+    type Error = ();
+    fn try_from(x: RefNode<'a>) -> Result<Self, Self::Error> {
+        match x {
+            RefNode::Locate(x) => Ok(x),
+            _ => Err(()),
+        }
+    }
+}
+
 impl<'a> IntoIterator for &'a Locate {
     type Item = RefNode<'a>;
     type IntoIter = Iter<'a>;
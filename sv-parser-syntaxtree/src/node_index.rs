@@ -0,0 +1,137 @@
+use crate::*;
+use core::any::TypeId;
+use core::convert::TryFrom;
+use std::collections::HashMap;
+
+// -----------------------------------------------------------------------------
+
+/// Identifier of a node in `NodeIndex`, which is the position in pre-order
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(pub usize);
+
+/// Indexed view of a tree to query parents, children and positions of nodes
+pub struct NodeIndex<'a> {
+    nodes: Vec<RefNode<'a>>,
+    parents: Vec<Option<NodeId>>,
+    children: Vec<Vec<NodeId>>,
+    // (offset, NodeId) of all Locates, sorted by offset
+    locates: Vec<(usize, NodeId)>,
+    // (NodeId, innermost node of the type containing it) where the innermost node changes,
+    // sorted by NodeId for each type
+    innermost: HashMap<TypeId, Vec<(NodeId, Option<NodeId>)>>,
+}
+
+impl<'a> NodeIndex<'a> {
+    pub fn new(root: RefNode<'a>) -> Self {
+        let mut nodes = Vec::new();
+        let mut parents = Vec::new();
+        let mut children: Vec<Vec<NodeId>> = Vec::new();
+        let mut locates = Vec::new();
+        let mut innermost: HashMap<TypeId, Vec<(NodeId, Option<NodeId>)>> = HashMap::new();
+        let mut stack: Vec<NodeId> = Vec::new();
+        let mut type_stacks: HashMap<TypeId, Vec<NodeId>> = HashMap::new();
+
+        for event in root.into_iter().event() {
+            match event {
+                NodeEvent::Enter(x) => {
+                    let id = NodeId(nodes.len());
+                    let parent = stack.last().copied();
+                    if let Some(parent) = parent {
+                        children[parent.0].push(id);
+                    }
+                    if let RefNode::Locate(x) = x {
+                        // Tokens made in code have no offset in the text
+                        if x.len != 0 && !Tokens::made_in_code(x) {
+                            locates.push((x.offset, id));
+                        }
+                    }
+                    let type_id = x.node_type_id();
+                    innermost.entry(type_id).or_default().push((id, Some(id)));
+                    type_stacks.entry(type_id).or_default().push(id);
+                    nodes.push(x);
+                    parents.push(parent);
+                    children.push(Vec::new());
+                    stack.push(id);
+                }
+                NodeEvent::Leave(x) => {
+                    stack.pop();
+                    // The following nodes are in the node of the same type containing it
+                    let type_id = x.node_type_id();
+                    let type_stack = type_stacks.get_mut(&type_id).unwrap();
+                    type_stack.pop();
+                    let next = (NodeId(nodes.len()), type_stack.last().copied());
+                    innermost.get_mut(&type_id).unwrap().push(next);
+                }
+            }
+        }
+        locates.sort_by_key(|(offset, _)| *offset);
+
+        NodeIndex {
+            nodes,
+            parents,
+            children,
+            locates,
+            innermost,
+        }
+    }
+
+    pub fn root(&self) -> NodeId {
+        NodeId(0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn node(&self, id: NodeId) -> RefNode<'a> {
+        self.nodes[id.0].clone()
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.parents[id.0]
+    }
+
+    /// Iterate ancestors from the parent to the root
+    pub fn ancestors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        let mut next = self.parent(id);
+        std::iter::from_fn(move || {
+            let ret = next?;
+            next = self.parent(ret);
+            Some(ret)
+        })
+    }
+
+    pub fn children(&self, id: NodeId) -> &[NodeId] {
+        &self.children[id.0]
+    }
+
+    /// Get the innermost node at the specified offset, which is a `Locate`
+    pub fn node_at_offset(&self, offset: usize) -> Option<NodeId> {
+        let i = match self.locates.binary_search_by_key(&offset, |(x, _)| *x) {
+            Ok(i) => i,
+            Err(0) => return None,
+            Err(i) => i - 1,
+        };
+        let (begin, id) = self.locates[i];
+        match self.nodes[id.0] {
+            RefNode::Locate(x) if offset < begin + x.len => Some(id),
+            _ => None,
+        }
+    }
+
+    /// Get the nearest ancestor of type `T`
+    pub fn enclosing<T: 'static>(&self, id: NodeId) -> Option<(NodeId, &'a T)>
+    where
+        &'a T: TryFrom<RefNode<'a>>,
+    {
+        let parent = self.parent(id)?;
+        let innermost = self.innermost.get(&TypeId::of::<T>())?;
+        let i = innermost.partition_point(|(x, _)| *x <= parent);
+        let ret = innermost[i.checked_sub(1)?].1?;
+        <&T>::try_from(self.node(ret)).ok().map(|node| (ret, node))
+    }
+}
@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GateInstantiation {
     Cmos(Box<GateInstantiationCmos>),
     Enable(Box<GateInstantiationEnable>),
@@ -16,6 +17,7 @@ pub enum GateInstantiation {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GateInstantiationCmos {
     pub nodes: (
         CmosSwitchtype,
@@ -26,6 +28,7 @@ pub struct GateInstantiationCmos {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GateInstantiationEnable {
     pub nodes: (
         EnableGatetype,
@@ -37,6 +40,7 @@ pub struct GateInstantiationEnable {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GateInstantiationMos {
     pub nodes: (
         MosSwitchtype,
@@ -47,6 +51,7 @@ pub struct GateInstantiationMos {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GateInstantiationNInput {
     pub nodes: (
         NInputGatetype,
@@ -58,6 +63,7 @@ pub struct GateInstantiationNInput {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GateInstantiationNOutput {
     pub nodes: (
         NOutputGatetype,
@@ -69,6 +75,7 @@ pub struct GateInstantiationNOutput {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GateInstantiationPassEn {
     pub nodes: (
         PassEnSwitchtype,
@@ -79,11 +86,13 @@ pub struct GateInstantiationPassEn {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GateInstantiationPass {
     pub nodes: (PassSwitchtype, List<Symbol, PassSwitchInstance>, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GateInstantiationPulldown {
     pub nodes: (
         Keyword,
@@ -94,6 +103,7 @@ pub struct GateInstantiationPulldown {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GateInstantiationPullup {
     pub nodes: (
         Keyword,
@@ -104,6 +114,7 @@ pub struct GateInstantiationPullup {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CmosSwitchInstance {
     pub nodes: (
         Option<NameOfInstance>,
@@ -120,6 +131,7 @@ pub struct CmosSwitchInstance {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EnableGateInstance {
     pub nodes: (
         Option<NameOfInstance>,
@@ -134,6 +146,7 @@ pub struct EnableGateInstance {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MosSwitchInstance {
     pub nodes: (
         Option<NameOfInstance>,
@@ -148,6 +161,7 @@ pub struct MosSwitchInstance {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NInputGateInstance {
     pub nodes: (
         Option<NameOfInstance>,
@@ -156,6 +170,7 @@ pub struct NInputGateInstance {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NOutputGateInstance {
     pub nodes: (
         Option<NameOfInstance>,
@@ -164,6 +179,7 @@ pub struct NOutputGateInstance {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PassSwitchInstance {
     pub nodes: (
         Option<NameOfInstance>,
@@ -172,6 +188,7 @@ pub struct PassSwitchInstance {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PassEnableSwitchInstance {
     pub nodes: (
         Option<NameOfInstance>,
@@ -180,6 +197,7 @@ pub struct PassEnableSwitchInstance {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PullGateInstance {
     pub nodes: (Option<NameOfInstance>, Paren<OutputTerminal>),
 }
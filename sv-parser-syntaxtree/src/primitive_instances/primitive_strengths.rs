@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PulldownStrength {
     Strength01(Box<PulldownStrength01>),
     Strength10(Box<PulldownStrength10>),
@@ -10,21 +11,25 @@ pub enum PulldownStrength {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PulldownStrength01 {
     pub nodes: (Paren<(Strength0, Symbol, Strength1)>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PulldownStrength10 {
     pub nodes: (Paren<(Strength1, Symbol, Strength0)>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PulldownStrength0 {
     pub nodes: (Paren<Strength0>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PullupStrength {
     Strength01(Box<PullupStrength01>),
     Strength10(Box<PullupStrength10>),
@@ -32,16 +37,19 @@ pub enum PullupStrength {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PullupStrength01 {
     pub nodes: (Paren<(Strength0, Symbol, Strength1)>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PullupStrength10 {
     pub nodes: (Paren<(Strength1, Symbol, Strength0)>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PullupStrength1 {
     pub nodes: (Paren<Strength1>,),
 }
@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ClassItem {
     Property(Box<ClassItemProperty>),
     Method(Box<ClassItemMethod>),
@@ -15,42 +16,50 @@ pub enum ClassItem {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ClassItemProperty {
     pub nodes: (Vec<AttributeInstance>, ClassProperty),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ClassItemMethod {
     pub nodes: (Vec<AttributeInstance>, ClassMethod),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ClassItemConstraint {
     pub nodes: (Vec<AttributeInstance>, ClassConstraint),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ClassItemDeclaration {
     pub nodes: (Vec<AttributeInstance>, ClassDeclaration),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ClassItemCovergroup {
     pub nodes: (Vec<AttributeInstance>, CovergroupDeclaration),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ClassProperty {
     NonConst(Box<ClassPropertyNonConst>),
     Const(Box<ClassPropertyConst>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ClassPropertyNonConst {
     pub nodes: (Vec<PropertyQualifier>, DataDeclaration),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ClassPropertyConst {
     pub nodes: (
         Keyword,
@@ -63,12 +72,14 @@ pub struct ClassPropertyConst {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ClassPropertyConstExpression {
     ConstantExpression(Box<ConstantExpression>),
     ClassNew(Box<ClassNew>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ClassMethod {
     Task(Box<ClassMethodTask>),
     Function(Box<ClassMethodFunction>),
@@ -79,16 +90,19 @@ pub enum ClassMethod {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ClassMethodTask {
     pub nodes: (Vec<MethodQualifier>, TaskDeclaration),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ClassMethodFunction {
     pub nodes: (Vec<MethodQualifier>, FunctionDeclaration),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ClassMethodPureVirtual {
     pub nodes: (
         Keyword,
@@ -100,32 +114,38 @@ pub struct ClassMethodPureVirtual {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ClassMethodExternMethod {
     pub nodes: (Keyword, Vec<MethodQualifier>, MethodPrototype, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ClassMethodConstructor {
     pub nodes: (Vec<MethodQualifier>, ClassConstructorDeclaration),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ClassMethodExternConstructor {
     pub nodes: (Keyword, Vec<MethodQualifier>, ClassConstructorPrototype),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ClassConstructorPrototype {
     pub nodes: (Keyword, Keyword, Option<Paren<Option<TfPortList>>>, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ClassConstraint {
     ConstraintPrototype(Box<ConstraintPrototype>),
     ConstraintDeclaration(Box<ConstraintDeclaration>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ClassItemQualifier {
     Static(Box<Keyword>),
     Protected(Box<Keyword>),
@@ -133,18 +153,21 @@ pub enum ClassItemQualifier {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PropertyQualifier {
     RandomQualifier(Box<RandomQualifier>),
     ClassItemQualifier(Box<ClassItemQualifier>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum RandomQualifier {
     Rand(Box<Keyword>),
     Randc(Box<Keyword>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum MethodQualifier {
     Virtual(Box<Keyword>),
     PureVirtual(Box<(Keyword, Keyword)>),
@@ -152,12 +175,14 @@ pub enum MethodQualifier {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum MethodPrototype {
     TaskPrototype(Box<TaskPrototype>),
     FunctionPrototype(Box<FunctionPrototype>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ClassConstructorDeclaration {
     pub nodes: (
         Keyword,
@@ -180,6 +205,7 @@ pub struct ClassConstructorDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct New {
     pub nodes: (Keyword,),
 }
@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConfigDeclaration {
     pub nodes: (
         Keyword,
@@ -17,6 +18,7 @@ pub struct ConfigDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DesignStatement {
     pub nodes: (
         Keyword,
@@ -26,6 +28,7 @@ pub struct DesignStatement {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ConfigRuleStatement {
     Default(Box<ConfigRuleStatementDefault>),
     InstLib(Box<ConfigRuleStatementInstLib>),
@@ -35,56 +38,67 @@ pub enum ConfigRuleStatement {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConfigRuleStatementDefault {
     pub nodes: (DefaultClause, LiblistClause, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConfigRuleStatementInstLib {
     pub nodes: (InstClause, LiblistClause, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConfigRuleStatementInstUse {
     pub nodes: (InstClause, UseClause, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConfigRuleStatementCellLib {
     pub nodes: (CellClause, LiblistClause, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConfigRuleStatementCellUse {
     pub nodes: (CellClause, UseClause, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DefaultClause {
     pub nodes: (Keyword,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InstClause {
     pub nodes: (Keyword, InstName),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InstName {
     pub nodes: (TopmoduleIdentifier, Vec<(Symbol, InstanceIdentifier)>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CellClause {
     pub nodes: (Keyword, Option<(LibraryIdentifier, Symbol)>, CellIdentifier),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LiblistClause {
     pub nodes: (Keyword, Vec<LibraryIdentifier>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum UseClause {
     Cell(Box<UseClauseCell>),
     Named(Box<UseClauseNamed>),
@@ -92,6 +106,7 @@ pub enum UseClause {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UseClauseCell {
     pub nodes: (
         Keyword,
@@ -102,6 +117,7 @@ pub struct UseClauseCell {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UseClauseNamed {
     pub nodes: (
         Keyword,
@@ -111,6 +127,7 @@ pub struct UseClauseNamed {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UseClauseCellNamed {
     pub nodes: (
         Keyword,
@@ -122,6 +139,7 @@ pub struct UseClauseCellNamed {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Config {
     pub nodes: (Keyword,),
 }
@@ -3,11 +3,13 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LibraryText {
     pub nodes: (Vec<WhiteSpace>, Vec<LibraryDescription>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum LibraryDescription {
     LibraryDeclaration(Box<LibraryDeclaration>),
     IncludeStatement(Box<IncludeStatement>),
@@ -16,6 +18,7 @@ pub enum LibraryDescription {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LibraryDeclaration {
     pub nodes: (
         Keyword,
@@ -27,17 +30,20 @@ pub struct LibraryDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IncludeStatement {
     pub nodes: (Keyword, FilePathSpec, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FilePathSpec {
     Literal(StringLiteral),
     NonLiteral(FilePathSpecNonLiteral),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FilePathSpecNonLiteral {
     pub nodes: (Locate, Vec<WhiteSpace>),
 }
@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ElaborationSystemTask {
     TaskFatal(Box<ElaborationSystemTaskFatal>),
     TaskError(Box<ElaborationSystemTaskError>),
@@ -11,6 +12,7 @@ pub enum ElaborationSystemTask {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ElaborationSystemTaskFatal {
     pub nodes: (
         Keyword,
@@ -20,21 +22,25 @@ pub struct ElaborationSystemTaskFatal {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ElaborationSystemTaskError {
     pub nodes: (Keyword, Option<Paren<Option<ListOfArguments>>>, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ElaborationSystemTaskWarning {
     pub nodes: (Keyword, Option<Paren<Option<ListOfArguments>>>, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ElaborationSystemTaskInfo {
     pub nodes: (Keyword, Option<Paren<Option<ListOfArguments>>>, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FinishNumber {
     Zero(Box<Symbol>),
     One(Box<Symbol>),
@@ -42,6 +48,7 @@ pub enum FinishNumber {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ModuleCommonItem {
     ModuleOrGenerateItemDeclaration(Box<ModuleOrGenerateItemDeclaration>),
     InterfaceInstantiation(Box<InterfaceInstantiation>),
@@ -59,12 +66,15 @@ pub enum ModuleCommonItem {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ModuleItem {
     PortDeclaration(Box<(PortDeclaration, Symbol)>),
     NonPortModuleItem(Box<NonPortModuleItem>),
+    Error(Box<ErrorNode>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ModuleOrGenerateItem {
     Parameter(Box<ModuleOrGenerateItemParameter>),
     Gate(Box<ModuleOrGenerateItemGate>),
@@ -74,31 +84,37 @@ pub enum ModuleOrGenerateItem {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ModuleOrGenerateItemParameter {
     pub nodes: (Vec<AttributeInstance>, ParameterOverride),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ModuleOrGenerateItemGate {
     pub nodes: (Vec<AttributeInstance>, GateInstantiation),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ModuleOrGenerateItemUdp {
     pub nodes: (Vec<AttributeInstance>, UdpInstantiation),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ModuleOrGenerateItemModule {
     pub nodes: (Vec<AttributeInstance>, ModuleInstantiation),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ModuleOrGenerateItemModuleItem {
     pub nodes: (Vec<AttributeInstance>, ModuleCommonItem),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ModuleOrGenerateItemDeclaration {
     PackageOrGenerateItemDeclaration(Box<PackageOrGenerateItemDeclaration>),
     GenvarDeclaration(Box<GenvarDeclaration>),
@@ -108,16 +124,19 @@ pub enum ModuleOrGenerateItemDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ModuleOrGenerateItemDeclarationClocking {
     pub nodes: (Keyword, Keyword, ClockingIdentifier, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ModuleOrGenerateItemDeclarationDisable {
     pub nodes: (Keyword, Keyword, Keyword, ExpressionOrDist, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NonPortModuleItem {
     GenerateRegion(Box<GenerateRegion>),
     ModuleOrGenerateItem(Box<ModuleOrGenerateItem>),
@@ -127,25 +146,30 @@ pub enum NonPortModuleItem {
     ModuleDeclaration(Box<ModuleDeclaration>),
     InterfaceDeclaration(Box<InterfaceDeclaration>),
     TimeunitsDeclaration(Box<TimeunitsDeclaration>),
+    Error(Box<ErrorNode>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NonPortModuleItemSpecparam {
     pub nodes: (Vec<AttributeInstance>, SpecparamDeclaration),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParameterOverride {
     pub nodes: (Keyword, ListOfDefparamAssignments, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BindDirective {
     Scope(Box<BindDirectiveScope>),
     Instance(Box<BindDirectiveInstance>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BindDirectiveScope {
     pub nodes: (
         Keyword,
@@ -156,27 +180,32 @@ pub struct BindDirectiveScope {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BindDirectiveInstance {
     pub nodes: (Keyword, BindTargetInstance, BindInstantiation),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BindTargetScope {
     ModuleIdentifier(Box<ModuleIdentifier>),
     InterfaceIdentifier(Box<InterfaceIdentifier>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BindTargetInstance {
     pub nodes: (HierarchicalIdentifier, ConstantBitSelect),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BindTargetInstanceList {
     pub nodes: (List<Symbol, BindTargetInstance>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BindInstantiation {
     ProgramInstantiation(Box<ProgramInstantiation>),
     ModuleInstantiation(Box<ModuleInstantiation>),
@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ParameterPortList {
     Assignment(Box<ParameterPortListAssignment>),
     Declaration(Box<ParameterPortListDeclaration>),
@@ -10,6 +11,7 @@ pub enum ParameterPortList {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParameterPortListAssignment {
     pub nodes: (
         Symbol,
@@ -21,11 +23,13 @@ pub struct ParameterPortListAssignment {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParameterPortListDeclaration {
     pub nodes: (Symbol, Paren<List<Symbol, ParameterPortDeclaration>>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ParameterPortDeclaration {
     ParameterDeclaration(Box<ParameterDeclaration>),
     LocalParameterDeclaration(Box<LocalParameterDeclaration>),
@@ -34,26 +38,31 @@ pub enum ParameterPortDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParameterPortDeclarationParamList {
     pub nodes: (DataType, ListOfParamAssignments),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParameterPortDeclarationTypeList {
     pub nodes: (Keyword, ListOfTypeAssignments),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ListOfPorts {
     pub nodes: (Paren<List<Symbol, Port>>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ListOfPortDeclarations {
     pub nodes: (Paren<Option<List<Symbol, (Vec<AttributeInstance>, AnsiPortDeclaration)>>>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PortDeclaration {
     Inout(Box<PortDeclarationInout>),
     Input(Box<PortDeclarationInput>),
@@ -63,63 +72,75 @@ pub enum PortDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PortDeclarationInout {
     pub nodes: (Vec<AttributeInstance>, InoutDeclaration),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PortDeclarationInput {
     pub nodes: (Vec<AttributeInstance>, InputDeclaration),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PortDeclarationOutput {
     pub nodes: (Vec<AttributeInstance>, OutputDeclaration),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PortDeclarationRef {
     pub nodes: (Vec<AttributeInstance>, RefDeclaration),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PortDeclarationInterface {
     pub nodes: (Vec<AttributeInstance>, InterfacePortDeclaration),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Port {
     NonNamed(Box<PortNonNamed>),
     Named(Box<PortNamed>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PortNonNamed {
     pub nodes: (Option<PortExpression>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PortNamed {
     pub nodes: (Symbol, PortIdentifier, Paren<Option<PortExpression>>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PortExpression {
     PortReference(Box<PortReference>),
     Brace(Box<PortExpressionBrace>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PortExpressionBrace {
     pub nodes: (Brace<List<Symbol, PortReference>>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PortReference {
     pub nodes: (PortIdentifier, ConstantSelect),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PortDirection {
     Input(Box<Keyword>),
     Output(Box<Keyword>),
@@ -128,37 +149,44 @@ pub enum PortDirection {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NetPortHeader {
     pub nodes: (Option<PortDirection>, NetPortType),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VariablePortHeader {
     pub nodes: (Option<PortDirection>, VariablePortType),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum InterfacePortHeader {
     Identifier(Box<InterfacePortHeaderIdentifier>),
     Interface(Box<InterfacePortHeaderInterface>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InterfacePortHeaderIdentifier {
     pub nodes: (InterfaceIdentifier, Option<(Symbol, ModportIdentifier)>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InterfacePortHeaderInterface {
     pub nodes: (Keyword, Option<(Symbol, ModportIdentifier)>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NetPortHeaderOrInterfacePortHeader {
     NetPortHeader(Box<NetPortHeader>),
     InterfacePortHeader(Box<InterfacePortHeader>),
 }
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AnsiPortDeclaration {
     Net(Box<AnsiPortDeclarationNet>),
     Variable(Box<AnsiPortDeclarationVariable>),
@@ -166,6 +194,7 @@ pub enum AnsiPortDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AnsiPortDeclarationNet {
     pub nodes: (
         Option<NetPortHeaderOrInterfacePortHeader>,
@@ -176,6 +205,7 @@ pub struct AnsiPortDeclarationNet {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AnsiPortDeclarationVariable {
     pub nodes: (
         Option<VariablePortHeader>,
@@ -186,6 +216,7 @@ pub struct AnsiPortDeclarationVariable {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AnsiPortDeclarationParen {
     pub nodes: (
         Option<PortDirection>,
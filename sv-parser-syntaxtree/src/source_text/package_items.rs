@@ -3,14 +3,17 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PackageItem {
     PackageOrGenerateItemDeclaration(Box<PackageOrGenerateItemDeclaration>),
     AnonymousProgram(Box<AnonymousProgram>),
     PackageExportDeclaration(Box<PackageExportDeclaration>),
     TimeunitsDeclaration(Box<TimeunitsDeclaration>),
+    Error(Box<ErrorNode>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PackageOrGenerateItemDeclaration {
     NetDeclaration(Box<NetDeclaration>),
     DataDeclaration(Box<DataDeclaration>),
@@ -30,11 +33,13 @@ pub enum PackageOrGenerateItemDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AnonymousProgram {
     pub nodes: (Keyword, Symbol, Vec<AnonymousProgramItem>, Keyword),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AnonymousProgramItem {
     TaskDeclaration(Box<TaskDeclaration>),
     FunctionDeclaration(Box<FunctionDeclaration>),
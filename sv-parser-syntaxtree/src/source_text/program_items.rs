@@ -3,12 +3,14 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ProgramItem {
     PortDeclaration(Box<(PortDeclaration, Symbol)>),
     NonPortProgramItem(Box<NonPortProgramItem>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NonPortProgramItem {
     Assign(Box<NonPortProgramItemAssign>),
     Module(Box<NonPortProgramItemModule>),
@@ -20,31 +22,37 @@ pub enum NonPortProgramItem {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NonPortProgramItemAssign {
     pub nodes: (Vec<AttributeInstance>, ContinuousAssign),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NonPortProgramItemModule {
     pub nodes: (Vec<AttributeInstance>, ModuleOrGenerateItemDeclaration),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NonPortProgramItemInitial {
     pub nodes: (Vec<AttributeInstance>, InitialConstruct),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NonPortProgramItemFinal {
     pub nodes: (Vec<AttributeInstance>, FinalConstruct),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NonPortProgramItemAssertion {
     pub nodes: (Vec<AttributeInstance>, ConcurrentAssertionItem),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ProgramGenerateItem {
     LoopGenerateConstruct(Box<LoopGenerateConstruct>),
     ConditionalGenerateConstruct(Box<ConditionalGenerateConstruct>),
@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SourceText {
     pub nodes: (
         Vec<WhiteSpace>,
@@ -12,6 +13,7 @@ pub struct SourceText {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Description {
     ResetallCompilerDirective(Box<ResetallCompilerDirective>),
     ModuleDeclaration(Box<ModuleDeclaration>),
@@ -23,19 +25,23 @@ pub enum Description {
     PackageItem(Box<DescriptionPackageItem>),
     BindDirective(Box<DescriptionBindDirective>),
     ConfigDeclaration(Box<ConfigDeclaration>),
+    Error(Box<ErrorNode>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DescriptionPackageItem {
     pub nodes: (Vec<AttributeInstance>, PackageItem),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DescriptionBindDirective {
     pub nodes: (Vec<AttributeInstance>, BindDirective),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ModuleNonansiHeader {
     pub nodes: (
         Vec<AttributeInstance>,
@@ -50,6 +56,7 @@ pub struct ModuleNonansiHeader {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ModuleAnsiHeader {
     pub nodes: (
         Vec<AttributeInstance>,
@@ -64,6 +71,7 @@ pub struct ModuleAnsiHeader {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ModuleDeclaration {
     Nonansi(Box<ModuleDeclarationNonansi>),
     Ansi(Box<ModuleDeclarationAnsi>),
@@ -73,6 +81,7 @@ pub enum ModuleDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ModuleDeclarationNonansi {
     pub nodes: (
         ModuleNonansiHeader,
@@ -84,6 +93,7 @@ pub struct ModuleDeclarationNonansi {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ModuleDeclarationAnsi {
     pub nodes: (
         ModuleAnsiHeader,
@@ -95,6 +105,7 @@ pub struct ModuleDeclarationAnsi {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ModuleDeclarationWildcard {
     pub nodes: (
         Vec<AttributeInstance>,
@@ -111,22 +122,26 @@ pub struct ModuleDeclarationWildcard {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ModuleDeclarationExternNonansi {
     pub nodes: (Keyword, ModuleNonansiHeader),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ModuleDeclarationExternAnsi {
     pub nodes: (Keyword, ModuleAnsiHeader),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ModuleKeyword {
     Module(Box<Keyword>),
     Macromodule(Box<Keyword>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum InterfaceDeclaration {
     Nonansi(Box<InterfaceDeclarationNonansi>),
     Ansi(Box<InterfaceDeclarationAnsi>),
@@ -136,6 +151,7 @@ pub enum InterfaceDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InterfaceDeclarationNonansi {
     pub nodes: (
         InterfaceNonansiHeader,
@@ -147,6 +163,7 @@ pub struct InterfaceDeclarationNonansi {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InterfaceDeclarationAnsi {
     pub nodes: (
         InterfaceAnsiHeader,
@@ -158,6 +175,7 @@ pub struct InterfaceDeclarationAnsi {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InterfaceDeclarationWildcard {
     pub nodes: (
         Vec<AttributeInstance>,
@@ -174,16 +192,19 @@ pub struct InterfaceDeclarationWildcard {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InterfaceDeclarationExternNonansi {
     pub nodes: (Keyword, InterfaceNonansiHeader),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InterfaceDeclarationExternAnsi {
     pub nodes: (Keyword, InterfaceAnsiHeader),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InterfaceNonansiHeader {
     pub nodes: (
         Vec<AttributeInstance>,
@@ -198,6 +219,7 @@ pub struct InterfaceNonansiHeader {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InterfaceAnsiHeader {
     pub nodes: (
         Vec<AttributeInstance>,
@@ -212,6 +234,7 @@ pub struct InterfaceAnsiHeader {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ProgramDeclaration {
     Nonansi(Box<ProgramDeclarationNonansi>),
     Ansi(Box<ProgramDeclarationAnsi>),
@@ -221,6 +244,7 @@ pub enum ProgramDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ProgramDeclarationNonansi {
     pub nodes: (
         ProgramNonansiHeader,
@@ -232,6 +256,7 @@ pub struct ProgramDeclarationNonansi {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ProgramDeclarationAnsi {
     pub nodes: (
         ProgramAnsiHeader,
@@ -243,6 +268,7 @@ pub struct ProgramDeclarationAnsi {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ProgramDeclarationWildcard {
     pub nodes: (
         Vec<AttributeInstance>,
@@ -258,16 +284,19 @@ pub struct ProgramDeclarationWildcard {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ProgramDeclarationExternNonansi {
     pub nodes: (Keyword, ProgramNonansiHeader),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ProgramDeclarationExternAnsi {
     pub nodes: (Keyword, ProgramAnsiHeader),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ProgramNonansiHeader {
     pub nodes: (
         Vec<AttributeInstance>,
@@ -282,6 +311,7 @@ pub struct ProgramNonansiHeader {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ProgramAnsiHeader {
     pub nodes: (
         Vec<AttributeInstance>,
@@ -296,6 +326,7 @@ pub struct ProgramAnsiHeader {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CheckerDeclaration {
     pub nodes: (
         Keyword,
@@ -309,6 +340,7 @@ pub struct CheckerDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ClassDeclaration {
     pub nodes: (
         Option<Virtual>,
@@ -326,16 +358,19 @@ pub struct ClassDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Virtual {
     pub nodes: (Keyword,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InterfaceClassType {
     pub nodes: (PsClassIdentifier, Option<ParameterValueAssignment>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InterfaceClassDeclaration {
     pub nodes: (
         Keyword,
@@ -351,6 +386,7 @@ pub struct InterfaceClassDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum InterfaceClassItem {
     TypeDeclaration(Box<TypeDeclaration>),
     Method(Box<InterfaceClassItemMethod>),
@@ -360,16 +396,19 @@ pub enum InterfaceClassItem {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InterfaceClassItemMethod {
     pub nodes: (Vec<AttributeInstance>, InterfaceClassMethod),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InterfaceClassMethod {
     pub nodes: (Keyword, Keyword, MethodPrototype, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PackageDeclaration {
     pub nodes: (
         Vec<AttributeInstance>,
@@ -385,6 +424,7 @@ pub struct PackageDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TimeunitsDeclaration {
     Timeunit(Box<TimeunitsDeclarationTimeunit>),
     Timeprecision(Box<TimeunitsDeclarationTimeprecision>),
@@ -393,21 +433,25 @@ pub enum TimeunitsDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TimeunitsDeclarationTimeunit {
     pub nodes: (Keyword, TimeLiteral, Option<(Symbol, TimeLiteral)>, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TimeunitsDeclarationTimeprecision {
     pub nodes: (Keyword, TimeLiteral, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TimeunitsDeclarationTimeunitTimeprecision {
     pub nodes: (Keyword, TimeLiteral, Symbol, Keyword, TimeLiteral, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TimeunitsDeclarationTimeprecisionTimeunit {
     pub nodes: (Keyword, TimeLiteral, Symbol, Keyword, TimeLiteral, Symbol),
 }
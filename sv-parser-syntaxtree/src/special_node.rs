@@ -3,16 +3,19 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Symbol {
     pub nodes: (Locate, Vec<WhiteSpace>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Keyword {
     pub nodes: (Locate, Vec<WhiteSpace>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum WhiteSpace {
     Newline(Box<Locate>),
     Space(Box<Locate>),
@@ -20,27 +23,38 @@ pub enum WhiteSpace {
     CompilerDirective(Box<CompilerDirective>),
 }
 
+#[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ErrorNode {
+    pub nodes: (Locate, Vec<WhiteSpace>),
+}
+
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Paren<T> {
     pub nodes: (Symbol, T, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Brace<T> {
     pub nodes: (Symbol, T, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Bracket<T> {
     pub nodes: (Symbol, T, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ApostropheBrace<T> {
     pub nodes: (Symbol, T, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct List<T, U> {
     pub nodes: (U, Vec<(T, U)>),
 }
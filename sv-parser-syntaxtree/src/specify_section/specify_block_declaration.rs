@@ -3,11 +3,13 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SpecifyBlock {
     pub nodes: (Keyword, Vec<SpecifyItem>, Keyword),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SpecifyItem {
     SpecparamDeclaration(Box<SpecparamDeclaration>),
     PulsestyleDeclaration(Box<PulsestyleDeclaration>),
@@ -17,11 +19,13 @@ pub enum SpecifyItem {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PulsestyleDeclaration {
     pub nodes: (Keyword, ListOfPathOutputs, Symbol),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ShowcancelledDeclaration {
     pub nodes: (Keyword, ListOfPathOutputs, Symbol),
 }
@@ -3,16 +3,19 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SpecifyInputTerminalDescriptor {
     pub nodes: (InputIdentifier, Option<Bracket<ConstantRangeExpression>>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SpecifyOutputTerminalDescriptor {
     pub nodes: (OutputIdentifier, Option<Bracket<ConstantRangeExpression>>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum InputIdentifier {
     InputPortIdentifier(Box<InputPortIdentifier>),
     InoutPortIdentifier(Box<InoutPortIdentifier>),
@@ -20,11 +23,13 @@ pub enum InputIdentifier {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InputIdentifierInterface {
     pub nodes: (InterfaceIdentifier, Symbol, PortIdentifier),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum OutputIdentifier {
     OutputPortIdentifier(Box<OutputPortIdentifier>),
     InoutPortIdentifier(Box<InoutPortIdentifier>),
@@ -32,6 +37,7 @@ pub enum OutputIdentifier {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OutputIdentifierInterface {
     pub nodes: (InterfaceIdentifier, Symbol, PortIdentifier),
 }
@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PathDeclaration {
     SimplePathDeclaration(Box<(SimplePathDeclaration, Symbol)>),
     EdgeSensitivePathDeclaration(Box<(EdgeSensitivePathDeclaration, Symbol)>),
@@ -10,22 +11,26 @@ pub enum PathDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SimplePathDeclaration {
     Parallel(Box<SimplePathDeclarationParallel>),
     Full(Box<SimplePathDeclarationFull>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SimplePathDeclarationParallel {
     pub nodes: (ParallelPathDescription, Symbol, PathDelayValue),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SimplePathDeclarationFull {
     pub nodes: (FullPathDescription, Symbol, PathDelayValue),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParallelPathDescription {
     pub nodes: (
         Paren<(
@@ -38,6 +43,7 @@ pub struct ParallelPathDescription {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FullPathDescription {
     pub nodes: (
         Paren<(
@@ -50,11 +56,13 @@ pub struct FullPathDescription {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ListOfPathInputs {
     pub nodes: (List<Symbol, SpecifyInputTerminalDescriptor>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ListOfPathOutputs {
     pub nodes: (List<Symbol, SpecifyOutputTerminalDescriptor>,),
 }
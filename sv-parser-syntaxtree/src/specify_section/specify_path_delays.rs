@@ -3,47 +3,56 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PathDelayValue {
     ListOfPathDelayExpressions(Box<ListOfPathDelayExpressions>),
     Paren(Box<PathDelayValueParen>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PathDelayValueParen {
     pub nodes: (Paren<ListOfPathDelayExpressions>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ListOfPathDelayExpressions {
     pub nodes: (List<Symbol, TPathDelayExpression>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TPathDelayExpression {
     pub nodes: (PathDelayExpression,),
 }
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PathDelayExpression {
     pub nodes: (ConstantMintypmaxExpression,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum EdgeSensitivePathDeclaration {
     Parallel(Box<EdgeSensitivePathDeclarationParallel>),
     Full(Box<EdgeSensitivePathDeclarationFull>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EdgeSensitivePathDeclarationParallel {
     pub nodes: (ParallelEdgeSensitivePathDescription, Symbol, PathDelayValue),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EdgeSensitivePathDeclarationFull {
     pub nodes: (FullEdgeSensitivePathDescription, Symbol, PathDelayValue),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParallelEdgeSensitivePathDescription {
     pub nodes: (
         Paren<(
@@ -62,6 +71,7 @@ pub struct ParallelEdgeSensitivePathDescription {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FullEdgeSensitivePathDescription {
     pub nodes: (
         Paren<(
@@ -80,11 +90,13 @@ pub struct FullEdgeSensitivePathDescription {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DataSourceExpression {
     pub nodes: (Expression,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum EdgeIdentifier {
     Posedge(Box<Keyword>),
     Negedge(Box<Keyword>),
@@ -92,6 +104,7 @@ pub enum EdgeIdentifier {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum StateDependentPathDeclaration {
     IfSimple(Box<StateDependentPathDeclarationIfSimple>),
     IfEdgeSensitive(Box<StateDependentPathDeclarationIfEdgeSensitive>),
@@ -99,11 +112,13 @@ pub enum StateDependentPathDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct StateDependentPathDeclarationIfSimple {
     pub nodes: (Keyword, Paren<ModulePathExpression>, SimplePathDeclaration),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct StateDependentPathDeclarationIfEdgeSensitive {
     pub nodes: (
         Keyword,
@@ -113,11 +128,13 @@ pub struct StateDependentPathDeclarationIfEdgeSensitive {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct StateDependentPathDeclarationIfNone {
     pub nodes: (Keyword, SimplePathDeclaration),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PolarityOperator {
     pub nodes: (Symbol,),
 }
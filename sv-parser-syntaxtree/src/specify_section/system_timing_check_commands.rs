@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SystemTimingCheck {
     SetupTimingCheck(Box<SetupTimingCheck>),
     HoldTimingCheck(Box<HoldTimingCheck>),
@@ -19,6 +20,7 @@ pub enum SystemTimingCheck {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SetupTimingCheck {
     pub nodes: (
         Keyword,
@@ -35,6 +37,7 @@ pub struct SetupTimingCheck {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct HoldTimingCheck {
     pub nodes: (
         Keyword,
@@ -51,6 +54,7 @@ pub struct HoldTimingCheck {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SetupholdTimingCheck {
     pub nodes: (
         Keyword,
@@ -85,6 +89,7 @@ pub struct SetupholdTimingCheck {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RecoveryTimingCheck {
     pub nodes: (
         Keyword,
@@ -101,6 +106,7 @@ pub struct RecoveryTimingCheck {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RemovalTimingCheck {
     pub nodes: (
         Keyword,
@@ -117,6 +123,7 @@ pub struct RemovalTimingCheck {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RecremTimingCheck {
     pub nodes: (
         Keyword,
@@ -151,6 +158,7 @@ pub struct RecremTimingCheck {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SkewTimingCheck {
     pub nodes: (
         Keyword,
@@ -167,6 +175,7 @@ pub struct SkewTimingCheck {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TimeskewTimingCheck {
     pub nodes: (
         Keyword,
@@ -191,6 +200,7 @@ pub struct TimeskewTimingCheck {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FullskewTimingCheck {
     pub nodes: (
         Keyword,
@@ -217,6 +227,7 @@ pub struct FullskewTimingCheck {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PeriodTimingCheck {
     pub nodes: (
         Keyword,
@@ -231,6 +242,7 @@ pub struct PeriodTimingCheck {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct WidthTimingCheck {
     pub nodes: (
         Keyword,
@@ -247,6 +259,7 @@ pub struct WidthTimingCheck {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NochangeTimingCheck {
     pub nodes: (
         Keyword,
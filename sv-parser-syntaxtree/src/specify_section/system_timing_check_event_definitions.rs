@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TimingCheckEvent {
     pub nodes: (
         Option<TimingCheckEventControl>,
@@ -12,6 +13,7 @@ pub struct TimingCheckEvent {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ControlledTimingCheckEvent {
     pub nodes: (
         TimingCheckEventControl,
@@ -21,6 +23,7 @@ pub struct ControlledTimingCheckEvent {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TimingCheckEventControl {
     Posedge(Box<Keyword>),
     Negedge(Box<Keyword>),
@@ -29,33 +32,39 @@ pub enum TimingCheckEventControl {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SpecifyTerminalDescriptor {
     SpecifyInputTerminalDescriptor(Box<SpecifyInputTerminalDescriptor>),
     SpecifyOutputTerminalDescriptor(Box<SpecifyOutputTerminalDescriptor>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EdgeControlSpecifier {
     pub nodes: (Keyword, Bracket<List<Symbol, EdgeDescriptor>>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EdgeDescriptor {
     pub nodes: (Keyword,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TimingCheckCondition {
     ScalarTimingCheckCondition(Box<ScalarTimingCheckCondition>),
     Paren(Box<TimingCheckConditionParen>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TimingCheckConditionParen {
     pub nodes: (Paren<ScalarTimingCheckCondition>,),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ScalarTimingCheckCondition {
     Expression(Box<Expression>),
     Unary(Box<ScalarTimingCheckConditionUnary>),
@@ -63,16 +72,19 @@ pub enum ScalarTimingCheckCondition {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ScalarTimingCheckConditionUnary {
     pub nodes: (Symbol, Expression),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ScalarTimingCheckConditionBinary {
     pub nodes: (Expression, Symbol, ScalarConstant),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ScalarConstant {
     pub nodes: (Keyword,),
 }
@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UdpNonansiDeclaration {
     pub nodes: (
         Vec<AttributeInstance>,
@@ -14,6 +15,7 @@ pub struct UdpNonansiDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UdpAnsiDeclaration {
     pub nodes: (
         Vec<AttributeInstance>,
@@ -25,6 +27,7 @@ pub struct UdpAnsiDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum UdpDeclaration {
     Nonansi(Box<UdpDeclarationNonansi>),
     Ansi(Box<UdpDeclarationAnsi>),
@@ -34,6 +37,7 @@ pub enum UdpDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UdpDeclarationNonansi {
     pub nodes: (
         UdpNonansiDeclaration,
@@ -46,6 +50,7 @@ pub struct UdpDeclarationNonansi {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UdpDeclarationAnsi {
     pub nodes: (
         UdpAnsiDeclaration,
@@ -56,16 +61,19 @@ pub struct UdpDeclarationAnsi {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UdpDeclarationExternNonansi {
     pub nodes: (Keyword, UdpNonansiDeclaration),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UdpDeclarationExternAnsi {
     pub nodes: (Keyword, UdpAnsiDeclaration),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UdpDeclarationWildcard {
     pub nodes: (
         Vec<AttributeInstance>,
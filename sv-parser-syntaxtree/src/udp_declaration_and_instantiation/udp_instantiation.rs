@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UdpInstantiation {
     pub nodes: (
         UdpIdentifier,
@@ -14,6 +15,7 @@ pub struct UdpInstantiation {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UdpInstance {
     pub nodes: (
         Option<NameOfInstance>,
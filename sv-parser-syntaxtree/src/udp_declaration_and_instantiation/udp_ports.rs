@@ -3,6 +3,7 @@ use crate::*;
 // -----------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UdpPortList {
     pub nodes: (
         OutputPortIdentifier,
@@ -12,6 +13,7 @@ pub struct UdpPortList {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UdpDeclarationPortList {
     pub nodes: (
         UdpOutputDeclaration,
@@ -21,6 +23,7 @@ pub struct UdpDeclarationPortList {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum UdpPortDeclaration {
     UdpOutputDeclaration(Box<(UdpOutputDeclaration, Symbol)>),
     UdpInputDeclaration(Box<(UdpInputDeclaration, Symbol)>),
@@ -28,17 +31,20 @@ pub enum UdpPortDeclaration {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum UdpOutputDeclaration {
     Nonreg(Box<UdpOutputDeclarationNonreg>),
     Reg(Box<UdpOutputDeclarationReg>),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UdpOutputDeclarationNonreg {
     pub nodes: (Vec<AttributeInstance>, Keyword, PortIdentifier),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UdpOutputDeclarationReg {
     pub nodes: (
         Vec<AttributeInstance>,
@@ -50,11 +56,13 @@ pub struct UdpOutputDeclarationReg {
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UdpInputDeclaration {
     pub nodes: (Vec<AttributeInstance>, Keyword, ListOfUdpPortIdentifiers),
 }
 
 #[derive(Clone, Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UdpRegDeclaration {
     pub nodes: (Vec<AttributeInstance>, Keyword, VariableIdentifier),
 }
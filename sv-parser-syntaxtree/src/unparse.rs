@@ -0,0 +1,87 @@
+use crate::*;
+
+// -----------------------------------------------------------------------------
+
+// Tokens made in code are placed beyond any preprocessed text
+const TOKENS_OFFSET: usize = usize::MAX / 2;
+
+/// Text of tokens made in code, which aren't in the preprocessed text
+///
+/// A `Locate` made by `Tokens::locate` points beyond the preprocessed text, so it has no
+/// source location and no node is found at its offset.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Tokens {
+    text: String,
+}
+
+impl Tokens {
+    pub fn new() -> Self {
+        core::default::Default::default()
+    }
+
+    /// Make a `Locate` of a token which isn't in the preprocessed text
+    pub fn locate(&mut self, s: &str) -> Locate {
+        let offset = TOKENS_OFFSET + self.text.len();
+        self.text.push_str(s);
+        Locate {
+            offset,
+            line: 0,
+            len: s.len(),
+        }
+    }
+
+    /// Check whether the `Locate` was made by `Tokens::locate`
+    pub fn made_in_code(locate: &Locate) -> bool {
+        locate.offset >= TOKENS_OFFSET
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Get the text of the `Locate` from the tokens or `text`, or `None` if it is out of them
+    pub fn get_str<'a>(&'a self, locate: &Locate, text: &'a str) -> Option<&'a str> {
+        if Self::made_in_code(locate) {
+            let offset = locate.offset - TOKENS_OFFSET;
+            self.text.get(offset..offset + locate.len)
+        } else {
+            text.get(locate.offset..locate.offset + locate.len)
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// Regenerate source code from a node.
+///
+/// Each `Locate` is resolved through `text`, which is the string the `Locate`s point to,
+/// or through `tokens` if it was made in code. A `Locate` out of them is written as empty.
+/// Nodes are written in tree order, so a tree which was built or edited in code is
+/// printed as it is.
+pub trait Unparse {
+    fn unparse(&self, text: &str, tokens: &Tokens, out: &mut String);
+
+    fn to_source(&self, text: &str) -> String {
+        self.to_source_with(text, &Tokens::new())
+    }
+
+    fn to_source_with(&self, text: &str, tokens: &Tokens) -> String {
+        let mut ret = String::new();
+        self.unparse(text, tokens, &mut ret);
+        ret
+    }
+}
+
+impl<T> Unparse for T
+where
+    for<'a> &'a T: Into<RefNodes<'a>>,
+{
+    fn unparse(&self, text: &str, tokens: &Tokens, out: &mut String) {
+        for x in Iter::new(self.into()) {
+            if let RefNode::Locate(x) = x {
+                out.push_str(tokens.get_str(x, text).unwrap_or(""));
+            }
+        }
+    }
+}
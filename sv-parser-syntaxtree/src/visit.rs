@@ -0,0 +1,31 @@
+use crate::*;
+
+// -----------------------------------------------------------------------------
+
+include!(concat!(env!("OUT_DIR"), "/visit.rs"));
+
+// -----------------------------------------------------------------------------
+
+/// Return value of `Visitor` hooks
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum VisitControl {
+    /// Continue visiting
+    Continue,
+    /// Don't visit the children of the current node, but continue visiting the siblings
+    SkipChildren,
+    /// Stop visiting
+    Break,
+}
+
+/// Visit the children of `node` by `visitor`
+pub fn walk<'a, V: Visitor<'a> + ?Sized, T: Node<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &'a T,
+) -> VisitControl {
+    for x in node.next().0 {
+        if x.accept(visitor) == VisitControl::Break {
+            return VisitControl::Break;
+        }
+    }
+    VisitControl::Continue
+}
@@ -3,10 +3,11 @@ use std::error::Error as StdError;
 use std::fs::File;
 use std::io::Read;
 use std::path::PathBuf;
-use std::{cmp, process};
+use std::process;
 use structopt::StructOpt;
-use sv_parser::{parse_sv, Define, DefineText};
+use sv_parser::{parse_sv, Define, DefineText, ParseDiagnostic};
 use sv_parser_error::Error;
+use sv_parser_pp::emit::emit;
 use sv_parser_pp::preprocess::preprocess;
 
 #[derive(StructOpt)]
@@ -25,6 +26,14 @@ struct Opt {
     #[structopt(short = "p", long = "pp")]
     pub pp: bool,
 
+    /// Insert `line markers to preprocesed text
+    #[structopt(long = "line-markers")]
+    pub line_markers: bool,
+
+    /// Write JSON source map of preprocesed text
+    #[structopt(long = "source-map")]
+    pub source_map: Option<PathBuf>,
+
     /// Allow incomplete source code
     #[structopt(long = "incomplete")]
     pub incomplete: bool,
@@ -55,115 +64,86 @@ fn main() {
         defines.insert(ident, Some(define));
     }
 
-    let builder = std::thread::Builder::new().stack_size(20 * 1024 * 1024);
-
-    let child = builder
-        .spawn(move || {
-            let mut exit = 0;
-            for path in &opt.files {
-                if opt.pp {
-                    match preprocess(&path, &defines, &opt.includes, false, false) {
-                        Ok((preprocessed_text, new_defines)) => {
-                            println!("{}", preprocessed_text.text());
-                            defines = new_defines;
+    let mut exit = 0;
+    for path in &opt.files {
+        if opt.pp {
+            match preprocess(&path, &defines, &opt.includes, false, false) {
+                Ok((preprocessed_text, new_defines)) => {
+                    if opt.line_markers || opt.source_map.is_some() {
+                        let emitted = emit(&preprocessed_text, opt.source_map.is_some());
+                        println!("{}", emitted.text);
+                        if let (Some(path), Some(x)) = (&opt.source_map, emitted.source_map) {
+                            std::fs::write(path, x).unwrap();
                         }
-                        _ => (),
+                    } else {
+                        println!("{}", preprocessed_text.text());
                     }
-                } else {
-                    match parse_sv(&path, &defines, &opt.includes, false, opt.incomplete) {
-                        Ok((syntax_tree, new_defines)) => {
-                            if opt.tree {
-                                println!("{}", syntax_tree);
-                            }
-                            defines = new_defines;
-                            if !opt.quiet {
-                                println!("parse succeeded: {:?}", path);
-                            }
+                    defines = new_defines;
+                }
+                _ => (),
+            }
+        } else {
+            match parse_sv(&path, &defines, &opt.includes, false, opt.incomplete) {
+                Ok((syntax_tree, new_defines)) => {
+                    if opt.tree {
+                        println!("{}", syntax_tree);
+                    }
+                    defines = new_defines;
+                    if !opt.quiet {
+                        println!("parse succeeded: {:?}", path);
+                    }
+                }
+                Err(x) => {
+                    match x {
+                        Error::Parse(Some(x)) => {
+                            println!("parse failed: {:?}", path);
+                            print_parse_error(&x);
                         }
-                        Err(x) => {
-                            match x {
-                                Error::Parse(Some((origin_path, origin_pos))) => {
-                                    println!("parse failed: {:?}", path);
-                                    print_parse_error(&origin_path, &origin_pos);
-                                }
-                                x => {
-                                    println!("parse failed: {:?} ({})", path, x);
-                                    let mut err = x.source();
-                                    while let Some(x) = err {
-                                        println!("  Caused by {}", x);
-                                        err = x.source();
-                                    }
-                                }
+                        x => {
+                            println!("parse failed: {:?} ({})", path, x);
+                            let mut err = x.source();
+                            while let Some(x) = err {
+                                println!("  Caused by {}", x);
+                                err = x.source();
                             }
-                            exit = 1;
                         }
                     }
+                    exit = 1;
                 }
             }
-            process::exit(exit);
-        })
-        .expect("thread spawn failure");
-
-    let _ = child.join();
-}
-
-static CHAR_CR: u8 = 0x0d;
-static CHAR_LF: u8 = 0x0a;
-
-fn print_parse_error(origin_path: &PathBuf, origin_pos: &usize) {
-    let mut f = File::open(&origin_path).unwrap();
-    let mut s = String::new();
-    let _ = f.read_to_string(&mut s);
-
-    let mut pos = 0;
-    let mut column = 1;
-    let mut last_lf = None;
-    while pos < s.len() {
-        if s.as_bytes()[pos] == CHAR_LF {
-            column += 1;
-            last_lf = Some(pos);
         }
-        pos += 1;
-
-        if *origin_pos == pos {
-            let row = if let Some(last_lf) = last_lf {
-                pos - last_lf
-            } else {
-                pos + 1
-            };
-            let mut next_crlf = pos;
-            while next_crlf < s.len() {
-                if s.as_bytes()[next_crlf] == CHAR_CR || s.as_bytes()[next_crlf] == CHAR_LF {
-                    break;
-                }
-                next_crlf += 1;
-            }
-
-            let column_len = format!("{}", column).len();
-
-            print!(" {}:{}:{}\n", origin_path.to_string_lossy(), column, row);
-
-            print!("{}|\n", " ".repeat(column_len + 1));
-
-            print!("{} |", column);
-
-            let beg = if let Some(last_lf) = last_lf {
-                last_lf + 1
-            } else {
-                0
-            };
-            print!(
-                " {}\n",
-                String::from_utf8_lossy(&s.as_bytes()[beg..next_crlf])
-            );
+    }
+    process::exit(exit);
+}
 
-            print!("{}|", " ".repeat(column_len + 1));
+fn print_parse_error(diagnostic: &ParseDiagnostic) {
+    let location = format!("{}:{}", diagnostic.line, diagnostic.column);
+    let location = if let Some(ref origin_path) = diagnostic.path {
+        format!("{}:{}", origin_path.to_string_lossy(), location)
+    } else {
+        location
+    };
+    println!(" {}", location);
+
+    let line = diagnostic.path.as_ref().and_then(|origin_path| {
+        let mut s = String::new();
+        let mut f = File::open(origin_path).ok()?;
+        f.read_to_string(&mut s).ok()?;
+        s.lines().nth(diagnostic.line - 1).map(String::from)
+    });
+
+    if let Some(line) = line {
+        let line_len = format!("{}", diagnostic.line).len();
+        println!("{}|", " ".repeat(line_len + 1));
+        println!("{} | {}", diagnostic.line, line);
+        println!(
+            "{}| {}^",
+            " ".repeat(line_len + 1),
+            " ".repeat(diagnostic.column - 1)
+        );
+    }
 
-            print!(
-                " {}{}\n",
-                " ".repeat(pos - beg),
-                "^".repeat(cmp::min(origin_pos + 1, next_crlf) - origin_pos)
-            );
-        }
+    if !diagnostic.expected.is_empty() {
+        println!(" expected one of {}", diagnostic.expected.join(", "));
     }
 }
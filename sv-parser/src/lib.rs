@@ -1,45 +1,135 @@
 #![recursion_limit = "256"]
 
-use nom_greedyerror::error_position;
+use nom::error::ErrorKind;
+use nom::Slice;
+use nom_greedyerror::GreedyError;
+use std::collections::HashMap;
 use std::fmt;
 use std::hash::BuildHasher;
+use std::panic;
 use std::path::{Path, PathBuf};
-pub use sv_parser_error::Error;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+pub use sv_parser_error::{Error, ParseDiagnostic};
+pub use sv_parser_parser::Version;
 use sv_parser_parser::{
-    lib_parser, lib_parser_incomplete, sv_parser, sv_parser_incomplete, Span, SpanInfo,
+    description_parser, lib_parser, lib_parser_incomplete, module_item_parser,
+    non_port_module_item_parser, package_item_parser, sv_parser, sv_parser_incomplete,
+    sv_parser_recover, IResult, ParserContext, ParserOptions, Span, SpanInfo,
 };
+pub use sv_parser_pp::configuration::{
+    conditional_macros, define_combinations, DefineAssignment, EXHAUSTIVE_LIMIT,
+};
+pub use sv_parser_pp::emit::{emit, Emitted};
 pub use sv_parser_pp::preprocess::{
-    preprocess, preprocess_str, Define, DefineText, Defines, PreprocessedText,
+    preprocess, preprocess_str, preprocess_str_with, preprocess_with, Define, DefineText, Defines,
+    Dependency, Expansion, InactiveRegion, PreprocessOptions, PreprocessedText, SkippedInclude,
+    SourceLocation, RECURSIVE_LIMIT,
 };
+pub use sv_parser_pp::range::Range;
+pub use sv_parser_pp::source_provider::{FileSystem, SourceProvider};
 pub use sv_parser_syntaxtree::*;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SyntaxTree {
     node: AnyNode,
     text: PreprocessedText,
+    tokens: Tokens,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    input: Option<ParseInput>,
+}
+
+// `input` is kept only to reparse edits, so it doesn't affect equality
+impl PartialEq for SyntaxTree {
+    fn eq(&self, other: &Self) -> bool {
+        self.node == other.node && self.text == other.text && self.tokens == other.tokens
+    }
+}
+
+// Source and options of `Parser` to parse the tree again after an edit
+#[derive(Clone, Debug)]
+struct ParseInput {
+    path: PathBuf,
+    source: String,
+    parse_options: ParseOptions,
+    options: ParserOptions,
+    // Parsed with error recovery
+    recover: bool,
+}
+
+impl ParseInput {
+    fn new<T: AsRef<Path>>(
+        path: T,
+        source: &str,
+        parse_options: &ParseOptions,
+        options: ParserOptions,
+    ) -> Self {
+        ParseInput {
+            path: PathBuf::from(path.as_ref()),
+            source: String::from(source),
+            parse_options: parse_options.clone(),
+            options,
+            recover: false,
+        }
+    }
+}
+
+// Preprocessed text of `Parser::parse_files` with its index, defines and input
+type ParseJob = (usize, PreprocessedText, Defines, ParseInput);
+
+// Node containing an edit which can be parsed by itself
+enum Reparse {
+    Description(usize),
+    ModuleItem(usize, usize),
+    NonPortModuleItem(usize, usize),
+    PackageItem(usize, usize),
+}
+
+// Shift `Locate` after an edit of the preprocessed text
+struct ShiftLocate {
+    from: usize,
+    delta: isize,
+    line_delta: isize,
+}
+
+impl VisitMut for ShiftLocate {
+    fn visit_locate_mut(&mut self, node: &mut Locate) {
+        if node.offset >= self.from && !Tokens::made_in_code(node) {
+            node.offset = (node.offset as isize + self.delta) as usize;
+            node.line = (node.line as isize + self.line_delta) as u32;
+        }
+    }
 }
 
 impl SyntaxTree {
     /// Get `&str` from the specified node
+    ///
+    /// `None` is returned if the node has tokens made in code, which aren't in the text.
+    /// `to_source` can get them.
     pub fn get_str<'a, T: Into<RefNodes<'a>>>(&self, nodes: T) -> Option<&str> {
         let mut beg = None;
         let mut end = 0;
         for n in Iter::new(nodes.into()) {
             if let RefNode::Locate(x) = n {
+                if Tokens::made_in_code(x) {
+                    return None;
+                }
                 if beg.is_none() {
                     beg = Some(x.offset);
                 }
                 end = x.offset + x.len;
             }
         }
-        if let Some(beg) = beg {
-            let ret = unsafe { self.text.text().get_unchecked(beg..end) };
-            Some(ret)
-        } else {
-            None
-        }
+        self.text.text().get(beg?..end)
     }
 
     /// Get `&str` without trailing `WhiteSpace` from the specified node
+    ///
+    /// `None` is returned if the node has tokens made in code like `get_str`.
     pub fn get_str_trim<'a, T: Into<RefNodes<'a>>>(&self, nodes: T) -> Option<&str> {
         let mut beg = None;
         let mut end = 0;
@@ -53,6 +143,9 @@ impl SyntaxTree {
                     skip = false;
                 }
                 NodeEvent::Enter(RefNode::Locate(x)) if !skip => {
+                    if Tokens::made_in_code(x) {
+                        return None;
+                    }
                     if beg.is_none() {
                         beg = Some(x.offset);
                     }
@@ -61,18 +154,216 @@ impl SyntaxTree {
                 _ => (),
             }
         }
-        if let Some(beg) = beg {
-            let ret = unsafe { self.text.text().get_unchecked(beg..end) };
-            Some(ret)
-        } else {
-            None
-        }
+        self.text.text().get(beg?..end)
+    }
+
+    /// Regenerate the preprocessed source code from the tree
+    pub fn to_source(&self) -> String {
+        self.node.to_source_with(self.text.text(), &self.tokens)
+    }
+
+    /// Make a `Locate` of a token which isn't in the preprocessed text to put it into the tree
+    pub fn make_token(&mut self, s: &str) -> Locate {
+        self.tokens.locate(s)
+    }
+
+    /// Build `NodeIndex` to query parents, children and positions of nodes
+    pub fn index(&self) -> NodeIndex<'_> {
+        NodeIndex::new((&self.node).into())
+    }
+
+    /// Visit all nodes by `visitor`
+    pub fn visit<'a, V: Visitor<'a>>(&'a self, visitor: &mut V) -> VisitControl {
+        let node: RefNode = (&self.node).into();
+        node.accept(visitor)
+    }
+
+    /// Visit all nodes by `visitor` which can modify them in place
+    pub fn visit_mut<V: VisitMut>(&mut self, visitor: &mut V) {
+        let node: RefNodeMut = (&mut self.node).into();
+        node.accept_mut(visitor);
+    }
+
+    /// Iterate all `Locate`s of the tree to modify them in place
+    pub fn iter_mut(&mut self) -> IterMut<'_> {
+        let node: RefNodeMut = (&mut self.node).into();
+        IterMut::new(vec![node].into())
     }
 
     /// Get source code location of the specified `Locate`
     pub fn get_origin(&self, locate: &Locate) -> Option<(&PathBuf, usize)> {
         self.text.origin(locate.offset)
     }
+
+    /// Get path, line and column in the source file of the specified `Locate`
+    pub fn get_location(&self, locate: &Locate) -> Option<SourceLocation> {
+        self.text.location(locate.offset)
+    }
+
+    /// Get path, line and column in the source file of the specified `Locate` ignoring `` `line ``
+    pub fn get_physical_location(&self, locate: &Locate) -> Option<SourceLocation> {
+        self.text.physical_location(locate.offset)
+    }
+
+    /// Get macro expansions and file inclusions which produced the specified `Locate`
+    pub fn get_expansions(&self, locate: &Locate) -> Vec<Expansion> {
+        self.text.expansions(locate.offset)
+    }
+
+    /// Get files read by the preprocessor in the order of their first inclusion
+    pub fn get_dependencies(&self) -> &[Dependency] {
+        self.text.dependencies()
+    }
+
+    /// Get `` `include `` in inactive `` `ifdef `` branches
+    pub fn get_skipped_includes(&self) -> &[SkippedInclude] {
+        self.text.skipped_includes()
+    }
+
+    /// Get blocks of `` `ifdef `` and `` `ifndef `` dropped by the preprocessor
+    pub fn get_inactive_regions(&self) -> &[InactiveRegion] {
+        self.text.inactive_regions()
+    }
+
+    /// Replace the specified byte range of the parsed file with `new_text`, and parse it again
+    ///
+    /// Only the smallest `ModuleItem`, `NonPortModuleItem`, `PackageItem` or `Description`
+    /// containing the edit is parsed again, and the other nodes are reused.
+    /// If the edit may change the result of the preprocessor, the whole file is parsed again
+    /// with the options and the source provider of the `Parser` which produced this tree.
+    /// The whole file is also parsed again if `` `begin_keywords `` appears anywhere in the
+    /// preprocessed text, or if `strip_comments` of `ParseOptions` is set.
+    /// A tree parsed with error recovery is parsed again with error recovery, but the
+    /// diagnostics aren't returned.
+    ///
+    /// Trees of `parse_sv`, `parse_sv_str`, `parse_sv_many`, `parse_sv_recover`,
+    /// `parse_sv_str_recover`, `parse_sv_configurations` and the methods of `Parser` for them
+    /// can be edited. `Error::Reparse` is returned for the other trees, like ones of
+    /// `parse_sv_pp` and library map files, and `Error::EditRange` is returned if `range`
+    /// isn't on `char` boundaries of the source.
+    pub fn edit(&self, range: Range, new_text: &str) -> Result<SyntaxTree, Error> {
+        let input = self.input.as_ref().ok_or(Error::Reparse)?;
+        let mut source = input.source.clone();
+        if range.begin > range.end
+            || !source.is_char_boundary(range.begin)
+            || !source.is_char_boundary(range.end)
+        {
+            return Err(Error::EditRange {
+                begin: range.begin,
+                end: range.end,
+            });
+        }
+        source.replace_range(range.begin..range.end, new_text);
+        let input = ParseInput {
+            source,
+            ..input.clone()
+        };
+
+        if let Some(mut x) = self.edit_incremental(&input, range, new_text) {
+            x.input = Some(input);
+            return Ok(x);
+        }
+        let parse_options = &input.parse_options;
+        let (text, defines) = preprocess_str_with(
+            &input.source,
+            &input.path,
+            &parse_options.pre_defines,
+            &parse_options.include_paths,
+            &parse_options.preprocess_options(),
+        )?;
+        let mut x = if input.recover {
+            parse_sv_text_recover(text, input.options)?.0
+        } else {
+            parse_sv_text(text, defines, parse_options.allow_incomplete, input.options)?.0
+        };
+        x.input = Some(input);
+        Ok(x)
+    }
+
+    fn edit_incremental(
+        &self,
+        input: &ParseInput,
+        range: Range,
+        new_text: &str,
+    ) -> Option<SyntaxTree> {
+        // Keywords depend on the preceding `begin_keywords,
+        // and stripped comments aren't in the preprocessed text
+        if self.text.text().contains("`begin_keywords") || input.parse_options.strip_comments {
+            return None;
+        }
+        let source_text = match self.node {
+            AnyNode::SourceText(ref x) => x,
+            _ => return None,
+        };
+
+        let mut text = self.text.clone();
+        let edit = text.edit_source(&input.path, range, new_text)?;
+        let old_text = &self.text.text()[edit.begin..edit.end];
+        let delta = new_text.len() as isize - old_text.len() as isize;
+        let line_delta =
+            new_text.matches('\n').count() as isize - old_text.matches('\n').count() as isize;
+        let shift = |x: usize| (x as isize + delta) as usize;
+
+        let targets = reparse_targets(source_text, edit);
+        let mut source_text = source_text.clone();
+        let mut shift_locate = ShiftLocate {
+            from: edit.end,
+            delta,
+            line_delta,
+        };
+        RefNodeMut::from(&mut source_text).accept_mut(&mut shift_locate);
+
+        let descriptions = &mut source_text.nodes.2;
+        let replaced = targets.into_iter().any(|(target, x)| {
+            let x = Range::new(x.begin, shift(x.end));
+            match target {
+                Reparse::Description(i) => reparse(&text, x, input.options, description_parser)
+                    .map(|x| descriptions[i] = x)
+                    .is_some(),
+                Reparse::ModuleItem(i, j) => match &mut descriptions[i] {
+                    Description::ModuleDeclaration(y) => match &mut **y {
+                        ModuleDeclaration::Nonansi(y) => {
+                            reparse(&text, x, input.options, module_item_parser)
+                                .map(|x| y.nodes.2[j] = x)
+                                .is_some()
+                        }
+                        _ => false,
+                    },
+                    _ => false,
+                },
+                Reparse::NonPortModuleItem(i, j) => match &mut descriptions[i] {
+                    Description::ModuleDeclaration(y) => match &mut **y {
+                        ModuleDeclaration::Ansi(y) => {
+                            reparse(&text, x, input.options, non_port_module_item_parser)
+                                .map(|x| y.nodes.2[j] = x)
+                                .is_some()
+                        }
+                        _ => false,
+                    },
+                    _ => false,
+                },
+                Reparse::PackageItem(i, j) => match &mut descriptions[i] {
+                    Description::PackageDeclaration(y) => {
+                        reparse(&text, x, input.options, package_item_parser)
+                            .map(|x| y.nodes.6[j] = x)
+                            .is_some()
+                    }
+                    _ => false,
+                },
+            }
+        });
+
+        if replaced {
+            Some(SyntaxTree {
+                node: source_text.into(),
+                text,
+                tokens: self.tokens.clone(),
+                input: None,
+            })
+        } else {
+            None
+        }
+    }
 }
 
 impl fmt::Display for SyntaxTree {
@@ -87,7 +378,7 @@ impl fmt::Display for SyntaxTree {
                         ret.push_str(&format!(
                             "{}Token: '{}' @ line:{}\n",
                             " ".repeat(depth),
-                            self.get_str(locate).unwrap(),
+                            self.tokens.get_str(locate, self.text.text()).unwrap_or(""),
                             locate.line,
                         ));
                     }
@@ -124,6 +415,509 @@ impl<'a> IntoIterator for &'a SyntaxTree {
     }
 }
 
+// Convert the error of a parser which consumed `text` with `context`
+fn parse_error(
+    text: &PreprocessedText,
+    context: &ParserContext,
+    x: nom::Err<GreedyError<Span, ErrorKind>>,
+) -> Error {
+    match context.exceeded_depth() {
+        Some(pos) => Error::ExceedDepthLimit(Some(Box::new(text.diagnostic(pos, vec![], vec![])))),
+        None => text.parse_error(x),
+    }
+}
+
+// Byte range of the preprocessed text covered by the specified nodes
+fn node_range<'a, T: Into<RefNodes<'a>>>(nodes: T) -> Option<Range> {
+    let mut ret: Option<Range> = None;
+    for n in Iter::new(nodes.into()) {
+        if let RefNode::Locate(x) = n {
+            let begin = ret.map_or(x.offset, |x| x.begin);
+            ret = Some(Range::new(begin, x.offset + x.len));
+        }
+    }
+    ret
+}
+
+// Nodes containing the edited range from the innermost, and their ranges
+fn reparse_targets(source_text: &SourceText, edit: Range) -> Vec<(Reparse, Range)> {
+    // An insertion at the boundary of two nodes belongs to the latter
+    let contains = |x: Option<Range>| {
+        x.filter(|x| x.begin <= edit.begin && edit.end <= x.end && edit.begin < x.end)
+    };
+
+    let mut ret = Vec::new();
+    for (i, description) in source_text.nodes.2.iter().enumerate() {
+        let range = match contains(node_range(description)) {
+            Some(x) => x,
+            None => continue,
+        };
+        match description {
+            Description::ModuleDeclaration(x) => match &**x {
+                ModuleDeclaration::Nonansi(x) => {
+                    for (j, item) in x.nodes.2.iter().enumerate() {
+                        if let Some(x) = contains(node_range(item)) {
+                            ret.push((Reparse::ModuleItem(i, j), x));
+                        }
+                    }
+                }
+                ModuleDeclaration::Ansi(x) => {
+                    for (j, item) in x.nodes.2.iter().enumerate() {
+                        if let Some(x) = contains(node_range(item)) {
+                            ret.push((Reparse::NonPortModuleItem(i, j), x));
+                        }
+                    }
+                }
+                _ => (),
+            },
+            Description::PackageDeclaration(x) => {
+                for (j, item) in x.nodes.6.iter().enumerate() {
+                    if let Some(x) = contains(node_range(item)) {
+                        ret.push((Reparse::PackageItem(i, j), x));
+                    }
+                }
+            }
+            _ => (),
+        }
+        ret.push((Reparse::Description(i), range));
+        break;
+    }
+    ret
+}
+
+// Parse the specified range of the text by `parser`, which must consume the whole range
+fn reparse<T, F: Fn(Span) -> IResult<Span, T>>(
+    text: &PreprocessedText,
+    range: Range,
+    options: ParserOptions,
+    parser: F,
+) -> Option<T> {
+    let context = ParserContext::with_options(options);
+    let span = Span::new_extra(text.text(), SpanInfo::new(&context));
+    match parser(span.slice(range.begin..)) {
+        Ok((s, x)) if s.location_offset() == range.end => Some(x),
+        _ => None,
+    }
+}
+
+/// Options of `Parser`
+///
+/// The defaults are the same as `parse_sv` without defines and include paths.
+#[derive(Clone)]
+pub struct ParseOptions {
+    pre_defines: Defines,
+    include_paths: Vec<PathBuf>,
+    strip_comments: bool,
+    ignore_include: bool,
+    allow_incomplete: bool,
+    skip_guarded_include: bool,
+    version: Option<Version>,
+    extension_versions: HashMap<String, Option<Version>>,
+    recursive_limit: usize,
+    max_depth: usize,
+    provider: Arc<dyn SourceProvider + Send + Sync>,
+}
+
+impl ParseOptions {
+    pub fn new() -> Self {
+        ParseOptions {
+            pre_defines: Defines::new(),
+            include_paths: Vec::new(),
+            strip_comments: false,
+            ignore_include: false,
+            allow_incomplete: false,
+            skip_guarded_include: false,
+            version: None,
+            extension_versions: HashMap::new(),
+            recursive_limit: RECURSIVE_LIMIT,
+            max_depth: ParserOptions::default().max_depth,
+            provider: Arc::new(FileSystem),
+        }
+    }
+
+    /// Macros defined before the file
+    pub fn defines<V: BuildHasher>(mut self, pre_defines: &Defines<V>) -> Self {
+        self.pre_defines = pre_defines
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        self
+    }
+
+    /// Directories searched for `` `include ``
+    pub fn include_paths<U: AsRef<Path>>(mut self, include_paths: &[U]) -> Self {
+        self.include_paths = include_paths
+            .iter()
+            .map(|x| PathBuf::from(x.as_ref()))
+            .collect();
+        self
+    }
+
+    /// Remove comments from the preprocessed text
+    pub fn strip_comments(mut self, strip_comments: bool) -> Self {
+        self.strip_comments = strip_comments;
+        self
+    }
+
+    /// Leave `` `include `` without reading the included files
+    pub fn ignore_include(mut self, ignore_include: bool) -> Self {
+        self.ignore_include = ignore_include;
+        self
+    }
+
+    /// Accept a file which ends in the middle of a description
+    pub fn allow_incomplete(mut self, allow_incomplete: bool) -> Self {
+        self.allow_incomplete = allow_incomplete;
+        self
+    }
+
+    /// Skip a file included again if its include guard is still defined
+    pub fn skip_guarded_include(mut self, skip_guarded_include: bool) -> Self {
+        self.skip_guarded_include = skip_guarded_include;
+        self
+    }
+
+    /// Version of the language outside of `` `begin_keywords `` blocks
+    ///
+    /// If `None`, IEEE 1800-2017 is used. Constructs of IEEE 1800 fail in IEEE 1364,
+    /// and the main additions of IEEE 1364-2001 fail in IEEE 1364-1995.
+    /// Other differences between the versions are not checked, see `Version`.
+    pub fn version(mut self, version: Option<Version>) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Keywords and grammar of files with the specified extension like `"v"`, instead of `version`
+    pub fn extension_version(mut self, extension: &str, version: Option<Version>) -> Self {
+        self.extension_versions
+            .insert(String::from(extension), version);
+        self
+    }
+
+    /// Maximum nesting depth of macro usages
+    pub fn recursive_limit(mut self, recursive_limit: usize) -> Self {
+        self.recursive_limit = recursive_limit;
+        self
+    }
+
+    /// Maximum nesting depth, which is unlimited by default
+    ///
+    /// Each nested expression, statement, generate block, struct or union type and class item
+    /// is a level.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Source of the file and the included files
+    ///
+    /// It is kept by the parsed trees to read the files again by `SyntaxTree::edit`.
+    pub fn provider(mut self, provider: Arc<dyn SourceProvider + Send + Sync>) -> Self {
+        self.provider = provider;
+        self
+    }
+
+    fn preprocess_options(&self) -> PreprocessOptions<'_> {
+        PreprocessOptions::new()
+            .strip_comments(self.strip_comments)
+            .ignore_include(self.ignore_include)
+            .skip_guarded_include(self.skip_guarded_include)
+            .recursive_limit(self.recursive_limit)
+            .provider(&*self.provider)
+    }
+
+    fn parser_options(&self, path: &Path) -> ParserOptions {
+        let mut ret = ParserOptions::default();
+        ret.max_depth = self.max_depth;
+        ret.version = path
+            .extension()
+            .and_then(|x| self.extension_versions.get(x.to_string_lossy().as_ref()))
+            .copied()
+            .unwrap_or(self.version);
+        ret
+    }
+}
+
+impl core::default::Default for ParseOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// The provider isn't printed
+impl fmt::Debug for ParseOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParseOptions")
+            .field("pre_defines", &self.pre_defines)
+            .field("include_paths", &self.include_paths)
+            .field("strip_comments", &self.strip_comments)
+            .field("ignore_include", &self.ignore_include)
+            .field("allow_incomplete", &self.allow_incomplete)
+            .field("skip_guarded_include", &self.skip_guarded_include)
+            .field("version", &self.version)
+            .field("extension_versions", &self.extension_versions)
+            .field("recursive_limit", &self.recursive_limit)
+            .field("max_depth", &self.max_depth)
+            .finish()
+    }
+}
+
+/// Parser of SystemVerilog with `ParseOptions`
+#[derive(Clone, Debug)]
+pub struct Parser {
+    options: ParseOptions,
+}
+
+impl Parser {
+    pub fn new(options: ParseOptions) -> Self {
+        Parser { options }
+    }
+
+    pub fn options(&self) -> &ParseOptions {
+        &self.options
+    }
+
+    /// Parse the specified file read by the provider
+    pub fn parse_file<T: AsRef<Path>>(&self, path: T) -> Result<(SyntaxTree, Defines), Error> {
+        let s = self.read(path.as_ref())?;
+        self.parse_str(&s, path)
+    }
+
+    /// Parse the specified string as the content of `path`
+    pub fn parse_str<T: AsRef<Path>>(
+        &self,
+        s: &str,
+        path: T,
+    ) -> Result<(SyntaxTree, Defines), Error> {
+        let (text, defines) = self.preprocess(s, path.as_ref())?;
+        self.parse_text(s, path, text, defines)
+    }
+
+    /// Parse the specified file with error recovery like `parse_sv_recover`
+    pub fn parse_file_recover<T: AsRef<Path>>(
+        &self,
+        path: T,
+    ) -> Result<(SyntaxTree, Vec<ParseDiagnostic>), Error> {
+        let s = self.read(path.as_ref())?;
+        self.parse_str_recover(&s, path)
+    }
+
+    /// Parse the specified string as the content of `path` with error recovery
+    pub fn parse_str_recover<T: AsRef<Path>>(
+        &self,
+        s: &str,
+        path: T,
+    ) -> Result<(SyntaxTree, Vec<ParseDiagnostic>), Error> {
+        let (text, _) = self.preprocess(s, path.as_ref())?;
+        let options = self.options.parser_options(path.as_ref());
+        let (mut tree, diagnostics) = parse_sv_text_recover(text, options)?;
+        tree.input = Some(ParseInput {
+            recover: true,
+            ..ParseInput::new(path, s, &self.options, options)
+        });
+        Ok((tree, diagnostics))
+    }
+
+    /// Parse the specified library map file
+    pub fn parse_lib_file<T: AsRef<Path>>(&self, path: T) -> Result<(SyntaxTree, Defines), Error> {
+        let s = self.read(path.as_ref())?;
+        self.parse_lib_str(&s, path)
+    }
+
+    /// Parse the specified string as the library map file `path`
+    pub fn parse_lib_str<T: AsRef<Path>>(
+        &self,
+        s: &str,
+        path: T,
+    ) -> Result<(SyntaxTree, Defines), Error> {
+        let (text, defines) = self.preprocess(s, path.as_ref())?;
+        let options = self.options.parser_options(path.as_ref());
+        parse_lib_text(text, defines, self.options.allow_incomplete, options)
+    }
+
+    /// Parse files of a compilation unit on `threads` worker threads like `parse_sv_many`
+    ///
+    /// The macros defined by a file are added to the options of the following files.
+    /// If no worker thread can be spawned, the files are parsed by the current thread.
+    pub fn parse_files<T: AsRef<Path>>(
+        &self,
+        paths: &[T],
+        threads: usize,
+    ) -> Vec<Result<(SyntaxTree, Defines), Error>> {
+        let mut parser = self.clone();
+        let mut ret = Vec::new();
+        let mut jobs = Vec::new();
+        for (i, path) in paths.iter().enumerate() {
+            let result = parser.read(path.as_ref()).and_then(|source| {
+                let (text, defines) = parser.preprocess(&source, path.as_ref())?;
+                Ok((source, text, defines))
+            });
+            match result {
+                Ok((source, text, new_defines)) => {
+                    let options = parser.options.parser_options(path.as_ref());
+                    let input = ParseInput::new(path, &source, &parser.options, options);
+                    jobs.push((i, text, new_defines.clone(), input));
+                    parser.options.pre_defines = new_defines;
+                    ret.push(None);
+                }
+                Err(x) => ret.push(Some(Err(x))),
+            }
+        }
+
+        let parse = |(i, text, defines, input): ParseJob| {
+            let allow_incomplete = input.parse_options.allow_incomplete;
+            let result = parse_sv_text(text, defines, allow_incomplete, input.options).map(
+                |(mut tree, defines)| {
+                    tree.input = Some(input);
+                    (tree, defines)
+                },
+            );
+            (i, result)
+        };
+
+        let threads = if threads == 0 {
+            thread::available_parallelism().map_or(1, |x| x.get())
+        } else {
+            threads
+        };
+        let jobs = Arc::new(Mutex::new(jobs.into_iter()));
+        let (sender, receiver) = mpsc::channel();
+        let mut workers = Vec::new();
+        for _ in 0..threads.min(paths.len()) {
+            let jobs = Arc::clone(&jobs);
+            let sender = sender.clone();
+            let worker = thread::Builder::new().spawn(move || loop {
+                let job = jobs.lock().unwrap().next();
+                match job {
+                    Some(x) => {
+                        if sender.send(parse(x)).is_err() {
+                            break;
+                        }
+                    }
+                    None => break,
+                }
+            });
+            if let Ok(x) = worker {
+                workers.push(x);
+            }
+        }
+        drop(sender);
+
+        for (i, result) in receiver {
+            ret[i] = Some(result);
+        }
+        for worker in workers {
+            if let Err(x) = worker.join() {
+                panic::resume_unwind(x);
+            }
+        }
+        // Jobs are left only if no worker was spawned
+        for job in jobs.lock().unwrap().by_ref() {
+            let (i, result) = parse(job);
+            ret[i] = Some(result);
+        }
+
+        ret.into_iter().map(|x| x.unwrap()).collect()
+    }
+
+    /// Parse under combinations of macros like `parse_sv_configurations`
+    pub fn parse_configurations<T: AsRef<Path>>(
+        &self,
+        path: T,
+    ) -> Result<Vec<Configuration>, Error> {
+        let options = &self.options;
+        let macros: Vec<_> =
+            conditional_macros(path.as_ref(), &options.include_paths, &*options.provider)?
+                .into_iter()
+                .filter(|x| !options.pre_defines.contains_key(x))
+                .collect();
+        let s = self.read(path.as_ref())?;
+
+        let mut ret: Vec<Configuration> = Vec::new();
+        // Preprocessed text of each configuration
+        let mut texts: Vec<Option<String>> = Vec::new();
+        for assignment in define_combinations(&macros) {
+            let mut parser = self.clone();
+            for (name, defined) in &assignment {
+                if *defined {
+                    parser.options.pre_defines.insert(name.clone(), None);
+                }
+            }
+
+            let preprocessed = parser.preprocess(&s, path.as_ref());
+            let text = preprocessed
+                .as_ref()
+                .ok()
+                .map(|(x, _)| String::from(x.text()));
+            let same = text
+                .as_ref()
+                .and_then(|x| texts.iter().position(|y| y.as_ref() == Some(x)));
+            if let Some(i) = same {
+                ret[i].assignments.push(assignment);
+                continue;
+            }
+
+            let result = preprocessed
+                .and_then(|(text, defines)| parser.parse_text(&s, path.as_ref(), text, defines))
+                .map(|(x, _)| x);
+            ret.push(Configuration {
+                assignments: vec![assignment],
+                result,
+            });
+            texts.push(text);
+        }
+        Ok(ret)
+    }
+
+    // Parse the preprocessed text of `s`
+    fn parse_text<T: AsRef<Path>>(
+        &self,
+        s: &str,
+        path: T,
+        text: PreprocessedText,
+        defines: Defines,
+    ) -> Result<(SyntaxTree, Defines), Error> {
+        let options = self.options.parser_options(path.as_ref());
+        let (mut tree, defines) =
+            parse_sv_text(text, defines, self.options.allow_incomplete, options)?;
+        tree.input = Some(ParseInput::new(path, s, &self.options, options));
+        Ok((tree, defines))
+    }
+
+    fn read(&self, path: &Path) -> Result<String, Error> {
+        self.options.provider.read(path).map_err(|x| Error::File {
+            source: x,
+            path: PathBuf::from(path),
+        })
+    }
+
+    fn preprocess(&self, s: &str, path: &Path) -> Result<(PreprocessedText, Defines), Error> {
+        preprocess_str_with(
+            s,
+            path,
+            &self.options.pre_defines,
+            &self.options.include_paths,
+            &self.options.preprocess_options(),
+        )
+    }
+}
+
+// `Parser` with the arguments of `parse_sv` and its variants
+fn positional_parser<U: AsRef<Path>, V: BuildHasher>(
+    pre_defines: &Defines<V>,
+    include_paths: &[U],
+    ignore_include: bool,
+    allow_incomplete: bool,
+) -> Parser {
+    Parser::new(
+        ParseOptions::new()
+            .defines(pre_defines)
+            .include_paths(include_paths)
+            .ignore_include(ignore_include)
+            .allow_incomplete(allow_incomplete),
+    )
+}
+
 pub fn parse_sv<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
     path: T,
     pre_defines: &Defines<V>,
@@ -131,8 +925,7 @@ pub fn parse_sv<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
     ignore_include: bool,
     allow_incomplete: bool,
 ) -> Result<(SyntaxTree, Defines), Error> {
-    let (text, defines) = preprocess(path, pre_defines, include_paths, false, ignore_include)?;
-    parse_sv_pp(text, defines, allow_incomplete)
+    positional_parser(pre_defines, include_paths, ignore_include, allow_incomplete).parse_file(path)
 }
 
 pub fn parse_sv_pp(
@@ -140,7 +933,17 @@ pub fn parse_sv_pp(
     defines: Defines,
     allow_incomplete: bool,
 ) -> Result<(SyntaxTree, Defines), Error> {
-    let span = Span::new_extra(text.text(), SpanInfo::default());
+    parse_sv_text(text, defines, allow_incomplete, ParserOptions::default())
+}
+
+fn parse_sv_text(
+    text: PreprocessedText,
+    defines: Defines,
+    allow_incomplete: bool,
+    options: ParserOptions,
+) -> Result<(SyntaxTree, Defines), Error> {
+    let context = ParserContext::with_options(options);
+    let span = Span::new_extra(text.text(), SpanInfo::new(&context));
     let result = if allow_incomplete {
         sv_parser_incomplete(span)
     } else {
@@ -151,26 +954,12 @@ pub fn parse_sv_pp(
             SyntaxTree {
                 node: x.into(),
                 text,
+                tokens: Tokens::new(),
+                input: None,
             },
             defines,
         )),
-        Err(x) => {
-            let pos = match x {
-                nom::Err::Incomplete(_) => None,
-                nom::Err::Error(e) => error_position(&e),
-                nom::Err::Failure(e) => error_position(&e),
-            };
-            let origin = if let Some(pos) = pos {
-                if let Some(origin) = text.origin(pos) {
-                    Some((origin.0.clone(), origin.1))
-                } else {
-                    None
-                }
-            } else {
-                None
-            };
-            Err(Error::Parse(origin))
-        }
+        Err(x) => Err(parse_error(&text, &context, x)),
     }
 }
 
@@ -182,16 +971,100 @@ pub fn parse_sv_str<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
     ignore_include: bool,
     allow_incomplete: bool,
 ) -> Result<(SyntaxTree, Defines), Error> {
-    let (text, defines) = preprocess_str(
-        s,
-        path,
-        pre_defines,
-        include_paths,
-        ignore_include,
-        false,
-        0,
-    )?;
-    parse_sv_pp(text, defines, allow_incomplete)
+    positional_parser(pre_defines, include_paths, ignore_include, allow_incomplete)
+        .parse_str(s, path)
+}
+
+/// Parse files of a compilation unit on `threads` worker threads
+///
+/// The files are preprocessed in order, so macros defined in a file are visible in the
+/// following files. Then the preprocessed texts are parsed in parallel, and the results are
+/// returned in the order of `paths` with the macros defined at the end of each file.
+/// The macros defined in `options` are visible in the first file.
+/// If `threads` is 0, the available parallelism is used.
+pub fn parse_sv_many<T: AsRef<Path>>(
+    paths: &[T],
+    options: &ParseOptions,
+    threads: usize,
+) -> Vec<Result<(SyntaxTree, Defines), Error>> {
+    Parser::new(options.clone()).parse_files(paths, threads)
+}
+
+/// Parse with error recovery.
+///
+/// An item of a module or package, or a statement of a block which fails to parse is skipped
+/// to the next `;` or the end keyword of the block which contains the error (`end`,
+/// `endfunction`, etc.), and recorded as the `Error` variant like `ModuleItem::Error`.
+/// The region is cut before the end keyword of the enclosing block, so the rest of the block
+/// is kept. A `Description` which fails to parse even so is skipped to its end keyword or
+/// the next `;`, and recorded as `Description::Error`. A module or package missing its end
+/// keyword at the end of the text, like one being typed, is closed there instead.
+/// The returned diagnostics correspond to the skipped regions.
+pub fn parse_sv_recover<T: AsRef<Path>>(
+    path: T,
+    options: &ParseOptions,
+) -> Result<(SyntaxTree, Vec<ParseDiagnostic>), Error> {
+    Parser::new(options.clone()).parse_file_recover(path)
+}
+
+pub fn parse_sv_pp_recover(
+    text: PreprocessedText,
+) -> Result<(SyntaxTree, Vec<ParseDiagnostic>), Error> {
+    parse_sv_text_recover(text, ParserOptions::default())
+}
+
+fn parse_sv_text_recover(
+    text: PreprocessedText,
+    options: ParserOptions,
+) -> Result<(SyntaxTree, Vec<ParseDiagnostic>), Error> {
+    let context = ParserContext::with_options(options);
+    let span = Span::new_extra(text.text(), SpanInfo::new(&context));
+    let result = sv_parser_recover(span);
+    match result {
+        Ok((_, x)) => {
+            let diagnostics = context
+                .recovered_errors()
+                .into_iter()
+                .map(|x| text.diagnostic(x.position, x.context, x.expected))
+                .collect();
+            Ok((
+                SyntaxTree {
+                    node: x.into(),
+                    text,
+                    tokens: Tokens::new(),
+                    input: None,
+                },
+                diagnostics,
+            ))
+        }
+        Err(x) => Err(parse_error(&text, &context, x)),
+    }
+}
+
+pub fn parse_sv_str_recover<T: AsRef<Path>>(
+    s: &str,
+    path: T,
+    options: &ParseOptions,
+) -> Result<(SyntaxTree, Vec<ParseDiagnostic>), Error> {
+    Parser::new(options.clone()).parse_str_recover(s, path)
+}
+
+/// Result of parsing under define assignments which produce the same preprocessed text
+#[derive(Debug)]
+pub struct Configuration {
+    pub assignments: Vec<DefineAssignment>,
+    pub result: Result<SyntaxTree, Error>,
+}
+
+/// Parse under combinations of defining and undefining macros tested by conditional directives
+///
+/// Macros defined in `options` keep their definitions. Assignments which produce the same
+/// preprocessed text share a `Configuration`.
+pub fn parse_sv_configurations<T: AsRef<Path>>(
+    path: T,
+    options: &ParseOptions,
+) -> Result<Vec<Configuration>, Error> {
+    Parser::new(options.clone()).parse_configurations(path)
 }
 
 pub fn parse_lib<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
@@ -201,8 +1074,8 @@ pub fn parse_lib<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
     ignore_include: bool,
     allow_incomplete: bool,
 ) -> Result<(SyntaxTree, Defines), Error> {
-    let (text, defines) = preprocess(path, pre_defines, include_paths, false, ignore_include)?;
-    parse_lib_pp(text, defines, allow_incomplete)
+    positional_parser(pre_defines, include_paths, ignore_include, allow_incomplete)
+        .parse_lib_file(path)
 }
 
 pub fn parse_lib_str<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
@@ -213,16 +1086,8 @@ pub fn parse_lib_str<T: AsRef<Path>, U: AsRef<Path>, V: BuildHasher>(
     ignore_include: bool,
     allow_incomplete: bool,
 ) -> Result<(SyntaxTree, Defines), Error> {
-    let (text, defines) = preprocess_str(
-        s,
-        path,
-        pre_defines,
-        include_paths,
-        ignore_include,
-        false,
-        0,
-    )?;
-    parse_lib_pp(text, defines, allow_incomplete)
+    positional_parser(pre_defines, include_paths, ignore_include, allow_incomplete)
+        .parse_lib_str(s, path)
 }
 
 pub fn parse_lib_pp(
@@ -230,7 +1095,17 @@ pub fn parse_lib_pp(
     defines: Defines,
     allow_incomplete: bool,
 ) -> Result<(SyntaxTree, Defines), Error> {
-    let span = Span::new_extra(text.text(), SpanInfo::default());
+    parse_lib_text(text, defines, allow_incomplete, ParserOptions::default())
+}
+
+fn parse_lib_text(
+    text: PreprocessedText,
+    defines: Defines,
+    allow_incomplete: bool,
+    options: ParserOptions,
+) -> Result<(SyntaxTree, Defines), Error> {
+    let context = ParserContext::with_options(options);
+    let span = Span::new_extra(text.text(), SpanInfo::new(&context));
     let result = if allow_incomplete {
         lib_parser_incomplete(span)
     } else {
@@ -241,26 +1116,12 @@ pub fn parse_lib_pp(
             SyntaxTree {
                 node: x.into(),
                 text,
+                tokens: Tokens::new(),
+                input: None,
             },
             defines,
         )),
-        Err(x) => {
-            let pos = match x {
-                nom::Err::Incomplete(_) => None,
-                nom::Err::Error(e) => error_position(&e),
-                nom::Err::Failure(e) => error_position(&e),
-            };
-            let origin = if let Some(pos) = pos {
-                if let Some(origin) = text.origin(pos) {
-                    Some((origin.0.clone(), origin.1))
-                } else {
-                    None
-                }
-            } else {
-                None
-            };
-            Err(Error::Parse(origin))
-        }
+        Err(x) => Err(parse_error(&text, &context, x)),
     }
 }
 
@@ -300,6 +1161,7 @@ macro_rules! unwrap_locate {
 mod test {
     use super::*;
     use std::collections::HashMap;
+    use std::convert::TryFrom;
 
     #[test]
     fn test() {
@@ -310,6 +1172,554 @@ mod test {
         assert!(comment.is_some());
     }
 
+    #[test]
+    fn test_parse_error() {
+        let src = "module A();\n  parameter A = 1 endmodule";
+        let ret = parse_sv_str(
+            src,
+            PathBuf::from("test.sv"),
+            &HashMap::new(),
+            &[""],
+            false,
+            false,
+        );
+        if let Err(Error::Parse(Some(x))) = ret {
+            assert_eq!(x.path, Some(PathBuf::from("test.sv")));
+            assert_eq!(x.offset, 30);
+            assert_eq!((x.line, x.column), (2, 19));
+            assert!(x.expected.contains(&String::from(";")));
+            assert_eq!(x.found, Some(String::from("endmodule")));
+        } else {
+            panic!("{:?}", ret.err());
+        }
+    }
+
+    #[test]
+    fn test_recover() {
+        let src = r##"module A;
+  wire a
+endmodule : A
+typedef int T
+module B;
+endmodule
+module C; endmodule"##;
+        let (syntax_tree, diagnostics) =
+            parse_sv_str_recover(src, PathBuf::from(""), &ParseOptions::new()).unwrap();
+
+        let mut modules = vec![];
+        let mut errors = vec![];
+        for node in &syntax_tree {
+            match node {
+                RefNode::ModuleIdentifier(x) => {
+                    modules.push(syntax_tree.get_str_trim(x).unwrap());
+                }
+                RefNode::ErrorNode(x) => {
+                    errors.push(syntax_tree.get_str_trim(x).unwrap());
+                }
+                _ => (),
+            }
+        }
+        // The label of `endmodule` is also a module identifier
+        assert_eq!(modules, vec!["A", "A", "B", "C"]);
+        assert_eq!(errors, vec!["wire a", "typedef int T"]);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!((diagnostics[0].line, diagnostics[0].column), (3, 1));
+        assert!(diagnostics[0].expected.contains(&String::from(";")));
+        assert_eq!(diagnostics[1].line, 5);
+
+        // The error position just after an identifier
+        let src = "module A; wire a+; endmodule\nmodule B; endmodule";
+        let (syntax_tree, diagnostics) =
+            parse_sv_str_recover(src, PathBuf::from(""), &ParseOptions::new()).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].column, 17);
+        let modules: Vec<_> = syntax_tree
+            .into_iter()
+            .filter_map(|x| match x {
+                RefNode::ModuleIdentifier(x) => syntax_tree.get_str_trim(x),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(modules, vec!["A", "B"]);
+
+        // Items and statements are skipped to the next ';' or the end of the block
+        let src = r##"module A;
+  initial begin
+    a = ;
+    b = 1;
+  end
+  always_comb begin
+    case (s)
+      0: c = 1 +;
+    endcase
+    d = 0;
+  end
+  wire e
+  assign f = g;
+  assign h = i;
+endmodule
+package P;
+  function int F; return 1 +; endfunction
+  int j;
+endpackage"##;
+        let (syntax_tree, diagnostics) =
+            parse_sv_str_recover(src, PathBuf::from(""), &ParseOptions::new()).unwrap();
+
+        let mut errors = vec![];
+        let mut variables = vec![];
+        for node in &syntax_tree {
+            match node {
+                RefNode::ErrorNode(x) => {
+                    errors.push(syntax_tree.get_str_trim(x).unwrap());
+                }
+                RefNode::VariableLvalueIdentifier(x) => {
+                    variables.push(syntax_tree.get_str_trim(x).unwrap());
+                }
+                RefNode::NetLvalueIdentifier(x) => {
+                    variables.push(syntax_tree.get_str_trim(x).unwrap());
+                }
+                RefNode::VariableIdentifier(x) => {
+                    variables.push(syntax_tree.get_str_trim(x).unwrap());
+                }
+                _ => (),
+            }
+        }
+        assert_eq!(
+            errors,
+            vec![
+                "a = ;",
+                "case (s)\n      0: c = 1 +;\n    endcase",
+                "wire e\n  assign f = g;",
+                "function int F; return 1 +; endfunction",
+            ]
+        );
+        assert_eq!(variables, vec!["b", "d", "h", "j"]);
+        assert_eq!(diagnostics.len(), 4);
+        let lines: Vec<_> = diagnostics.iter().map(|x| x.line).collect();
+        assert_eq!(lines, vec![3, 8, 13, 17]);
+
+        // A stray end keyword is skipped alone, and a module missing its end keyword is
+        // cut before the next module
+        let cases = [
+            (
+                "module A; initial begin end end wire b; endmodule",
+                vec!["end"],
+            ),
+            (
+                "module A(input a);\n  wire b;\nmodule B; endmodule",
+                vec!["module A(input a);\n  wire b;"],
+            ),
+        ];
+        for (src, expected) in cases {
+            let (syntax_tree, diagnostics) =
+                parse_sv_str_recover(src, PathBuf::from(""), &ParseOptions::new()).unwrap();
+            let errors: Vec<_> = syntax_tree
+                .into_iter()
+                .filter_map(|x| match x {
+                    RefNode::ErrorNode(x) => syntax_tree.get_str_trim(x),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(errors, expected);
+            assert_eq!(diagnostics.len(), 1);
+        }
+
+        // A module being typed is closed at the end of the text
+        let src = "module a; wire x";
+        let (syntax_tree, diagnostics) =
+            parse_sv_str_recover(src, PathBuf::from(""), &ParseOptions::new()).unwrap();
+        let mut modules = vec![];
+        let mut errors = vec![];
+        for node in &syntax_tree {
+            match node {
+                RefNode::ModuleIdentifier(x) => {
+                    modules.push(syntax_tree.get_str_trim(x).unwrap());
+                }
+                RefNode::ErrorNode(x) => {
+                    errors.push(syntax_tree.get_str_trim(x).unwrap());
+                }
+                _ => (),
+            }
+        }
+        assert_eq!(modules, vec!["a"]);
+        assert_eq!(errors, vec!["wire x"]);
+        assert_eq!(syntax_tree.to_source(), src);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].offset, src.len());
+        assert!(diagnostics[0].expected.contains(&String::from(";")));
+
+        // The tokens expected at the error position are reported
+        let src = "module a; wire x = ; endmodule";
+        let (_, diagnostics) =
+            parse_sv_str_recover(src, PathBuf::from(""), &ParseOptions::new()).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].offset, src.find(" ;").unwrap() + 1);
+        assert!(diagnostics[0]
+            .expected
+            .contains(&String::from("identifier")));
+    }
+
+    #[test]
+    fn test_deep_expression() {
+        // The nesting depth of expressions is unlimited by default
+        let depth = 100;
+        let src = format!(
+            "module A; assign a = {}1{}; endmodule",
+            "(".repeat(depth),
+            ")".repeat(depth)
+        );
+        let ret = parse_sv_str(
+            &src,
+            PathBuf::from(""),
+            &HashMap::new(),
+            &[""],
+            false,
+            false,
+        );
+        assert!(ret.is_ok());
+    }
+
+    #[test]
+    fn test_get_location() {
+        let src = "`define W wire\n/* あ */ `W a;\n  wire b;";
+        let (syntax_tree, _) = parse_sv_str(
+            src,
+            PathBuf::from("test.sv"),
+            &HashMap::new(),
+            &[""],
+            false,
+            false,
+        )
+        .unwrap();
+
+        let mut locations = vec![];
+        for node in &syntax_tree {
+            let locate = match node {
+                RefNode::NetType(x) => unwrap_locate!(x),
+                RefNode::SimpleIdentifier(x) => unwrap_locate!(x),
+                _ => None,
+            };
+            if let Some(locate) = locate {
+                let x = syntax_tree.get_location(locate).unwrap();
+                assert_eq!(x.path, PathBuf::from("test.sv"));
+                locations.push((x.line, x.column, x.byte_offset));
+            }
+        }
+        // `W is expanded from the body of `define
+        assert_eq!(
+            locations,
+            vec![(1, 9, 8), (1, 11, 10), (2, 12, 28), (3, 3, 33), (3, 8, 38)]
+        );
+    }
+
+    // Testcases of sv-parser-pp which fail to preprocess or aren't valid SystemVerilog
+    const INVALID_TESTCASES: &[&str] = &[
+        "test7.sv",
+        "test8.sv",
+        "test9.sv",
+        "test10.sv",
+        "test25.sv",
+        "test29.sv",
+    ];
+
+    // Get paths of testcases and their include directories
+    fn testcases() -> Vec<(PathBuf, PathBuf)> {
+        let base = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let dirs = [
+            base.join("testcases"),
+            base.join("../sv-parser-pp/testcases"),
+        ];
+        let mut ret = vec![];
+        for dir in &dirs {
+            for entry in std::fs::read_dir(dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.extension() != Some("sv".as_ref()) {
+                    continue;
+                }
+                let name = path.file_name().unwrap().to_str().unwrap();
+                if dir.ends_with("sv-parser-pp/testcases") && INVALID_TESTCASES.contains(&name) {
+                    continue;
+                }
+                ret.push((path, dir.clone()));
+            }
+        }
+        ret.sort();
+        ret
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        for (path, dir) in testcases() {
+            let (syntax_tree, _) = parse_sv(&path, &HashMap::new(), &[dir], false, false).unwrap();
+
+            let json = serde_json::to_string(&syntax_tree).unwrap();
+            let mut deserializer = serde_json::Deserializer::from_str(&json);
+            deserializer.disable_recursion_limit();
+            let ret = SyntaxTree::deserialize(&mut deserializer).unwrap();
+            assert_eq!(ret, syntax_tree, "{:?}", path);
+            assert_eq!(ret.to_string(), syntax_tree.to_string(), "{:?}", path);
+        }
+    }
+
+    #[test]
+    fn test_to_source() {
+        for (path, dir) in testcases() {
+            let (syntax_tree, _) = parse_sv(&path, &HashMap::new(), &[dir], false, false).unwrap();
+            assert_eq!(
+                syntax_tree.to_source(),
+                syntax_tree.text.text(),
+                "{:?}",
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn test_unparse_edited() {
+        let src = "module A; wire a; endmodule";
+        let (syntax_tree, _) =
+            parse_sv_str(src, PathBuf::from(""), &HashMap::new(), &[""], false, false).unwrap();
+        let module = unwrap_node!(&syntax_tree, ModuleDeclarationAnsi).unwrap();
+        let mut module = match module {
+            RefNode::ModuleDeclarationAnsi(x) => x.clone(),
+            _ => unreachable!(),
+        };
+
+        let item = module.nodes.2[0].clone();
+        module.nodes.2.push(item);
+        assert_eq!(module.to_source(src), "module A; wire a; wire a; endmodule");
+
+        module.nodes.2.clear();
+        assert_eq!(module.to_source(src), "module A; endmodule");
+
+        // a tree made in code is printed from its tokens without the text
+        let mut tokens = Tokens::new();
+        let keyword = tokens.locate("endmodule");
+        let space = WhiteSpace::Space(Box::new(tokens.locate(" ")));
+        let keyword = Keyword {
+            nodes: (keyword, vec![space]),
+        };
+        let module = Keyword {
+            nodes: (tokens.locate("module"), vec![]),
+        };
+        let locate = Locate::try_from(&keyword).unwrap();
+        assert_eq!(tokens.get_str(&locate, ""), Some("endmodule "));
+        assert_eq!(
+            vec![keyword, module].to_source_with("", &tokens),
+            "endmodule module"
+        );
+
+        // a Locate out of the text is empty
+        let locate = Locate {
+            offset: src.len(),
+            len: 3,
+            ..core::default::Default::default()
+        };
+        assert_eq!(locate.to_source(src), "");
+    }
+
+    #[test]
+    fn test_index() {
+        let src = r##"module A;
+  function int f; return 1; endfunction
+endmodule
+module B; always_comb x = f(); endmodule"##;
+        let (syntax_tree, _) =
+            parse_sv_str(src, PathBuf::from(""), &HashMap::new(), &[""], false, false).unwrap();
+        let index = syntax_tree.index();
+
+        let module_name = |id| {
+            let (_, module) = index.enclosing::<ModuleDeclaration>(id).unwrap();
+            match unwrap_node!(module, ModuleIdentifier) {
+                Some(RefNode::ModuleIdentifier(x)) => syntax_tree.get_str_trim(x).unwrap(),
+                _ => unreachable!(),
+            }
+        };
+
+        // "1" in the function
+        let one = index.node_at_offset(src.find('1').unwrap()).unwrap();
+        assert_eq!(module_name(one), "A");
+        assert!(index.enclosing::<FunctionDeclaration>(one).is_some());
+        assert!(index.enclosing::<AlwaysConstruct>(one).is_none());
+
+        // "f" in always_comb
+        let f = index.node_at_offset(src.rfind('f').unwrap()).unwrap();
+        assert_eq!(module_name(f), "B");
+        assert!(index.enclosing::<AlwaysConstruct>(f).is_some());
+
+        // whitespace and out of range
+        assert!(index.node_at_offset(src.find('\n').unwrap()).is_some());
+        assert!(index.node_at_offset(src.len()).is_none());
+
+        let parent = index.parent(f).unwrap();
+        assert!(index.children(parent).contains(&f));
+        assert_eq!(index.ancestors(f).last(), Some(index.root()));
+        assert_eq!(index.parent(index.root()), None);
+    }
+
+    #[test]
+    fn test_index_deep() {
+        // Nested statements grow the stack on demand
+        let depth = 200;
+        let src = format!(
+            "module A; initial {} x = 1; {} endmodule module B; endmodule",
+            "begin ".repeat(depth),
+            "end ".repeat(depth)
+        );
+        let (syntax_tree, _) = parse_sv_str(
+            &src,
+            PathBuf::from(""),
+            &HashMap::new(),
+            &[""],
+            false,
+            false,
+        )
+        .unwrap();
+        let index = syntax_tree.index();
+
+        let x = index.node_at_offset(src.find('x').unwrap()).unwrap();
+        let (_, module) = index.enclosing::<ModuleDeclaration>(x).unwrap();
+        match unwrap_node!(module, ModuleIdentifier) {
+            Some(RefNode::ModuleIdentifier(x)) => {
+                assert_eq!(syntax_tree.get_str_trim(x), Some("A"))
+            }
+            _ => unreachable!(),
+        }
+
+        // the same as searching the ancestors for all nodes
+        for id in (0..index.len()).map(NodeId) {
+            let block = index
+                .ancestors(id)
+                .find(|x| matches!(index.node(*x), RefNode::SeqBlock(_)));
+            assert_eq!(index.enclosing::<SeqBlock>(id).map(|(x, _)| x), block);
+        }
+        let blocks = index
+            .ancestors(x)
+            .filter(|x| matches!(index.node(*x), RefNode::SeqBlock(_)))
+            .count();
+        assert_eq!(blocks, depth);
+    }
+
+    #[test]
+    fn test_visit() {
+        struct Identifiers<'a> {
+            text: &'a str,
+            names: Vec<&'a str>,
+        }
+
+        impl<'a> Visitor<'a> for Identifiers<'a> {
+            fn visit_function_body_declaration(
+                &mut self,
+                _node: &'a FunctionBodyDeclaration,
+            ) -> VisitControl {
+                VisitControl::SkipChildren
+            }
+
+            fn visit_simple_identifier(&mut self, node: &'a SimpleIdentifier) -> VisitControl {
+                self.names.push(node.nodes.0.str(self.text));
+                if self.names.len() == 4 {
+                    VisitControl::Break
+                } else {
+                    walk_simple_identifier(self, node)
+                }
+            }
+        }
+
+        let src = r##"module A;
+  function f; int x; x = 1; endfunction
+  wire a;
+endmodule
+module B; wire b; wire c; endmodule"##;
+        let (syntax_tree, _) =
+            parse_sv_str(src, PathBuf::from(""), &HashMap::new(), &[""], false, false).unwrap();
+
+        let mut visitor = Identifiers {
+            text: syntax_tree.text.text(),
+            names: vec![],
+        };
+        assert_eq!(syntax_tree.visit(&mut visitor), VisitControl::Break);
+        assert_eq!(visitor.names, vec!["A", "a", "B", "b"]);
+    }
+
+    #[test]
+    fn test_visit_mut() {
+        struct Rename {
+            from: String,
+            to: Locate,
+            text: String,
+        }
+
+        impl VisitMut for Rename {
+            fn visit_simple_identifier_mut(&mut self, node: &mut SimpleIdentifier) {
+                if node.nodes.0.str(&self.text) == self.from {
+                    node.nodes.0 = self.to;
+                }
+            }
+        }
+
+        let src = "module A; wire a; assign a = b & a; endmodule";
+        let (mut syntax_tree, _) =
+            parse_sv_str(src, PathBuf::from(""), &HashMap::new(), &[""], false, false).unwrap();
+
+        let mut rename = Rename {
+            from: String::from("a"),
+            to: syntax_tree.make_token("data"),
+            text: String::from(syntax_tree.text.text()),
+        };
+        syntax_tree.visit_mut(&mut rename);
+        assert_eq!(
+            syntax_tree.to_source(),
+            "module A; wire data; assign data = b & data; endmodule"
+        );
+        assert_eq!(syntax_tree.text.text(), src);
+
+        // Tokens made in code aren't in the text
+        let wire = match unwrap_node!(&syntax_tree, NetDeclAssignment) {
+            Some(RefNode::NetDeclAssignment(x)) => x,
+            _ => unreachable!(),
+        };
+        assert_eq!(syntax_tree.get_str(wire), None);
+        assert!(format!("{}", syntax_tree).contains("Token: 'data' @ line:0"));
+        assert_eq!(syntax_tree.get_location(&rename.to), None);
+        assert_eq!(syntax_tree.index().node_at_offset(rename.to.offset), None);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let src = "module A; wire a; endmodule";
+        let (mut syntax_tree, _) =
+            parse_sv_str(src, PathBuf::from(""), &HashMap::new(), &[""], false, false).unwrap();
+
+        let text = String::from(syntax_tree.text.text());
+        let upper: Vec<_> = syntax_tree
+            .iter_mut()
+            .map(|x| x.str(&text).to_uppercase())
+            .collect();
+        let upper: Vec<_> = upper.iter().map(|x| syntax_tree.make_token(x)).collect();
+        for (x, y) in syntax_tree.iter_mut().zip(upper) {
+            *x = y;
+        }
+        assert_eq!(syntax_tree.to_source(), "MODULE A; WIRE A; ENDMODULE");
+
+        // A mutated tree can be printed
+        let (mut syntax_tree, _) = parse_sv_str(
+            "module m; endmodule",
+            PathBuf::from(""),
+            &HashMap::new(),
+            &[""],
+            false,
+            false,
+        )
+        .unwrap();
+        let token = syntax_tree.make_token("mod");
+        *syntax_tree.iter_mut().next().unwrap() = token;
+        assert_eq!(syntax_tree.to_source(), "mod m; endmodule");
+        let printed = format!("{}", syntax_tree);
+        assert!(printed.contains("Token: 'mod' @ line:0"));
+        assert!(printed.contains("Token: 'endmodule' @ line:1"));
+    }
+
     #[test]
     fn test_continuous() {
         let src = r##"`ifdef A
@@ -351,4 +1761,477 @@ endmodule"##;
         let ret = parse_sv_str(src, &path, &defines, &[""], false, false);
         assert!(ret.is_ok());
     }
+
+    #[test]
+    fn test_source_provider() {
+        let mut files = HashMap::new();
+        files.insert(
+            PathBuf::from("src/top.sv"),
+            String::from("module top;\n`include \"defs.svh\"\nendmodule\n"),
+        );
+        files.insert(
+            PathBuf::from("inc/defs.svh"),
+            String::from("localparam A = `VALUE;\n`define VALUE 2\n"),
+        );
+        files.insert(
+            PathBuf::from("src/defs.svh"),
+            String::from("`define VALUE 1\nlocalparam A = `VALUE;\n"),
+        );
+
+        // The directory of the including file is searched before include paths
+        let parser = Parser::new(
+            ParseOptions::new()
+                .include_paths(&["inc"])
+                .provider(Arc::new(files.clone())),
+        );
+        let (syntax_tree, _) = parser.parse_file("src/top.sv").unwrap();
+        let param = unwrap_node!(&syntax_tree, LocalParameterDeclaration).unwrap();
+        let param = match param {
+            RefNode::LocalParameterDeclaration(x) => x,
+            _ => unreachable!(),
+        };
+        let locate = Locate::try_from(param).unwrap();
+        assert_eq!(syntax_tree.get_str(&locate), Some("localparam A = 1 "));
+        let location = syntax_tree.get_location(&locate).unwrap();
+        assert_eq!(location.path, PathBuf::from("src/defs.svh"));
+
+        let ret = parser.parse_file("src/missing.sv");
+        assert!(matches!(ret, Err(Error::File { .. })));
+
+        // The included files are read again from the provider of the tree
+        let edited = syntax_tree.edit(Range::new(0, 0), "`define X\n").unwrap();
+        let param = match unwrap_node!(&edited, LocalParameterDeclaration) {
+            Some(RefNode::LocalParameterDeclaration(x)) => x,
+            _ => unreachable!(),
+        };
+        let locate = Locate::try_from(param).unwrap();
+        assert_eq!(edited.get_str(&locate), Some("localparam A = 1 "));
+        let location = edited.get_location(&locate).unwrap();
+        assert_eq!(location.path, PathBuf::from("src/defs.svh"));
+    }
+
+    #[test]
+    fn test_parse_sv_configurations() {
+        let mut files = HashMap::new();
+        files.insert(
+            PathBuf::from("top.sv"),
+            String::from(
+                "module top;\n`ifdef A\nwire a;\n`elsif B\nwire b;\n`endif\n`ifdef C\nwire c\n`endif\nendmodule\n",
+            ),
+        );
+
+        let configurations = parse_sv_configurations(
+            "top.sv",
+            &ParseOptions::new().provider(Arc::new(files.clone())),
+        )
+        .unwrap();
+        let texts: Vec<_> = configurations
+            .iter()
+            .map(|x| {
+                (
+                    x.assignments.len(),
+                    x.result.as_ref().ok().map(|x| x.text.text()),
+                )
+            })
+            .collect();
+        assert_eq!(
+            texts,
+            vec![
+                (1, Some("module top;\n\n\nendmodule\n")),
+                (2, Some("module top;\nwire a;\n\n\nendmodule\n")),
+                (1, Some("module top;\nwire b;\n\n\nendmodule\n")),
+                (1, None),
+                (2, None),
+                (1, None),
+            ]
+        );
+        // Failed assignments are merged by the preprocessed text as well
+        let assignments = &configurations[4].assignments;
+        assert!(assignments
+            .iter()
+            .all(|x| x.contains(&(String::from("A"), true))
+                && x.contains(&(String::from("C"), true))));
+        assert_eq!(
+            configurations[1].assignments[1],
+            vec![
+                (String::from("A"), true),
+                (String::from("B"), true),
+                (String::from("C"), false)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_edit() {
+        let src = r##"`define W 8
+module A (input a);
+  wire [`W-1:0] b;
+  assign b = a;
+endmodule
+package P;
+  parameter X = 1;
+  parameter Y = 2;
+endpackage
+module B;
+  wire c;
+  initial begin
+    c = 1;
+  end
+endmodule
+"##;
+        let (tree, _) = parse_sv_str(
+            src,
+            PathBuf::from("test.sv"),
+            &HashMap::new(),
+            &[] as &[&Path],
+            false,
+            false,
+        )
+        .unwrap();
+
+        // (old text, new text, reparsed incrementally)
+        let edits = vec![
+            ("assign b = a;", "assign b = a & 1;", true),
+            (
+                "parameter Y = 2;",
+                "parameter Y = 2;\n  parameter Z = 3;",
+                true,
+            ),
+            ("c = 1;", "c = 2;\n    c = 3;", true),
+            ("wire c;\n", "", true),
+            ("module B;", "module C;", true),
+            ("endpackage", "endpackage\nmodule D; endmodule", false),
+            ("assign b = a;", "assign b = `W;", false),
+            ("wire c;", "wire c; // x", false),
+        ];
+        for (old, new, incremental) in edits {
+            let begin = src.find(old).unwrap();
+            let range = Range::new(begin, begin + old.len());
+            let edited = tree.edit(range, new).unwrap();
+            let expected = src.replacen(old, new, 1);
+            let (expected, _) = parse_sv_str(
+                &expected,
+                PathBuf::from("test.sv"),
+                &HashMap::new(),
+                &[] as &[&Path],
+                false,
+                false,
+            )
+            .unwrap();
+            assert_eq!(edited.node, expected.node, "{}", new);
+            assert_eq!(edited.text.text(), expected.text.text());
+            let source = |x: &SyntaxTree| x.input.as_ref().map(|x| x.source.clone());
+            assert_eq!(source(&edited), source(&expected));
+            assert_eq!(
+                tree.edit_incremental(tree.input.as_ref().unwrap(), range, new)
+                    .is_some(),
+                incremental,
+                "{}",
+                new
+            );
+
+            for node in &edited {
+                if let RefNode::Locate(x) = node {
+                    assert_eq!(edited.get_location(x), expected.get_location(x));
+                }
+            }
+        }
+
+        // A tree without its source can't be edited
+        let (text, _) = preprocess_str(
+            src,
+            "test.sv",
+            &HashMap::new(),
+            &[] as &[&Path],
+            false,
+            false,
+            0,
+        )
+        .unwrap();
+        let (tree, _) = parse_sv_pp(text, HashMap::new(), false).unwrap();
+        let range = Range::new(0, 0);
+        assert!(matches!(tree.edit(range, ""), Err(Error::Reparse)));
+
+        // but it is equal to the tree parsed from the source
+        let (parsed, _) = parse_sv_str(
+            src,
+            PathBuf::from("test.sv"),
+            &HashMap::new(),
+            &[] as &[&Path],
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(tree, parsed);
+    }
+
+    #[test]
+    fn test_edit_recover() {
+        let src = r##"module A;
+  wire a;
+  assign a = ;
+endmodule
+"##;
+        let options = ParseOptions::new();
+        let (tree, diagnostics) =
+            parse_sv_str_recover(src, PathBuf::from("test.sv"), &options).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+
+        // An edit of a recovered tree is parsed with error recovery
+        let edits = vec![
+            ("assign a = ;", "assign a = 1;", 0),
+            ("wire a;", "wire a", 1),
+            ("endmodule", "", 2),
+        ];
+        for (old, new, errors) in edits {
+            let begin = src.find(old).unwrap();
+            let edited = tree
+                .edit(Range::new(begin, begin + old.len()), new)
+                .unwrap();
+            let (expected, diagnostics) = parse_sv_str_recover(
+                &src.replacen(old, new, 1),
+                PathBuf::from("test.sv"),
+                &options,
+            )
+            .unwrap();
+            assert_eq!(edited, expected, "{}", new);
+            assert_eq!(diagnostics.len(), errors, "{}", new);
+        }
+    }
+
+    #[test]
+    fn test_parse_sv_many() {
+        let mut files = HashMap::new();
+        files.insert(
+            PathBuf::from("a.sv"),
+            String::from("`define W 8\nmodule A; endmodule\n"),
+        );
+        files.insert(
+            PathBuf::from("b.sv"),
+            String::from("module B; wire [`W-1:0] b; endmodule\n"),
+        );
+        files.insert(
+            PathBuf::from("c.sv"),
+            String::from("module C; wire endmodule\n"),
+        );
+        files.insert(PathBuf::from("d.sv"), String::from("module D; endmodule\n"));
+
+        let paths = ["a.sv", "b.sv", "c.sv", "missing.sv", "d.sv"];
+        let results = parse_sv_many(
+            &paths,
+            &ParseOptions::new().provider(Arc::new(files.clone())),
+            2,
+        );
+        assert_eq!(results.len(), 5);
+
+        let (tree, defines) = results[1].as_ref().unwrap();
+        assert_eq!(tree.text.text(), "module B; wire [8 -1:0] b; endmodule\n");
+        assert!(defines.contains_key("W"));
+        assert!(matches!(results[2], Err(Error::Parse(_))));
+        assert!(matches!(results[3], Err(Error::File { .. })));
+
+        let (tree, defines) = results[4].as_ref().unwrap();
+        let names: Vec<_> = tree
+            .into_iter()
+            .filter_map(|x| match x {
+                RefNode::ModuleIdentifier(x) => tree.get_str(x),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(names, vec!["D"]);
+        assert!(defines.contains_key("W"));
+
+        // Deeply nested statements fit in the stack of workers
+        let depth = 200;
+        let src = format!(
+            "module G; initial {}x = 1;{} endmodule\n",
+            "begin ".repeat(depth),
+            " end".repeat(depth)
+        );
+        files.insert(PathBuf::from("g.sv"), src);
+        let results = parse_sv_many(
+            &["g.sv"],
+            &ParseOptions::new().provider(Arc::new(files.clone())),
+            1,
+        );
+        let (tree, _) = results[0].as_ref().unwrap();
+        let blocks = tree
+            .into_iter()
+            .filter(|x| matches!(x, RefNode::SeqBlock(_)))
+            .count();
+        assert_eq!(blocks, depth);
+
+        // `Parser::parse_files` applies the options to each file
+        files.insert(
+            PathBuf::from("e.v"),
+            String::from("module E; logic e; endmodule\n"),
+        );
+        files.insert(
+            PathBuf::from("f.sv"),
+            String::from("module F; logic f; endmodule // F\n"),
+        );
+        let parser = Parser::new(
+            ParseOptions::new()
+                .strip_comments(true)
+                .extension_version("v", Some(Version::Ieee1364_2005))
+                .provider(Arc::new(files.clone())),
+        );
+        let results = parser.parse_files(&["e.v", "f.sv", "a.sv", "b.sv"], 2);
+        assert!(matches!(results[0], Err(Error::Parse(_))));
+        let (tree, _) = results[1].as_ref().unwrap();
+        assert!(!tree.text.text().contains("// F"));
+        let (tree, _) = results[3].as_ref().unwrap();
+        assert_eq!(tree.text.text(), "module B; wire [8 -1:0] b; endmodule\n");
+    }
+
+    #[test]
+    fn test_max_depth() {
+        // Statements, generate blocks, struct types and classes are limited as expressions,
+        // and the stack of the test thread grows on demand for them
+        let srcs = |n: usize| {
+            vec![
+                format!(
+                    "module A; initial {}x = 1;{} endmodule",
+                    "begin ".repeat(n),
+                    " end".repeat(n)
+                ),
+                format!("module A; initial {}x = 1; endmodule", "if (a) ".repeat(n)),
+                format!(
+                    "module A; {}assign a = 1;{} endmodule",
+                    "if (1) begin ".repeat(n),
+                    " end".repeat(n)
+                ),
+                format!(
+                    "module A; {}int a;{} x; endmodule",
+                    "struct { ".repeat(n),
+                    " } a;".repeat(n - 1) + " }"
+                ),
+                format!("{}{}", "class C; ".repeat(n), "endclass ".repeat(n)),
+            ]
+        };
+        let parser = Parser::new(ParseOptions::new().max_depth(100));
+        for src in &srcs(1000) {
+            assert!(matches!(
+                parser.parse_str(src, ""),
+                Err(Error::ExceedDepthLimit(Some(_)))
+            ));
+        }
+        let parser = Parser::new(ParseOptions::new());
+        for src in &srcs(300) {
+            assert!(parser.parse_str(src, "").is_ok());
+        }
+    }
+
+    #[test]
+    fn test_parser() {
+        let mut files = HashMap::new();
+        files.insert(
+            PathBuf::from("a.v"),
+            String::from("module A; // comment\n  wire logic;\nendmodule\n"),
+        );
+        files.insert(
+            PathBuf::from("b.sv"),
+            String::from("`define B1 1\n`define B2 `B1\nmodule B; wire [`B2:0] b; endmodule\n"),
+        );
+        files.insert(
+            PathBuf::from("c.sv"),
+            String::from("module C; localparam c = ((((1)))); endmodule\n"),
+        );
+
+        let parser = Parser::new(ParseOptions::new().provider(Arc::new(files.clone())));
+        assert!(matches!(parser.parse_file("a.v"), Err(Error::Parse(_))));
+        assert!(parser.parse_file("b.sv").is_ok());
+        assert!(parser.parse_file("c.sv").is_ok());
+        assert!(matches!(
+            parser.parse_file("missing.sv"),
+            Err(Error::File { .. })
+        ));
+
+        let parser = Parser::new(
+            ParseOptions::new()
+                .strip_comments(true)
+                .version(Some(Version::Ieee1364_2005))
+                .recursive_limit(0)
+                .max_depth(2)
+                .provider(Arc::new(files.clone())),
+        );
+        let (tree, _) = parser.parse_file("a.v").unwrap();
+        assert_eq!(tree.text.text(), "module A;   wire logic;\nendmodule\n");
+        assert!(matches!(
+            parser.parse_file("b.sv"),
+            Err(Error::ExceedRecursiveLimit)
+        ));
+        assert!(matches!(
+            parser.parse_file("c.sv"),
+            Err(Error::ExceedDepthLimit(Some(_)))
+        ));
+
+        // The edited file is parsed with the same options
+        let edited = tree.edit(Range::new(28, 33), "bit").unwrap();
+        assert_eq!(edited.text.text(), "module A;   wire bit;\nendmodule\n");
+
+        // A range which isn't on char boundaries is an error instead of a panic
+        let src = "module A; // é\nendmodule";
+        let (tree, _) = parse_sv_str(src, "d.sv", &HashMap::new(), &[""], false, false).unwrap();
+        let begin = src.find('é').unwrap() + 1;
+        assert!(matches!(
+            tree.edit(Range::new(begin, begin), "x"),
+            Err(Error::EditRange { .. })
+        ));
+        assert!(matches!(
+            tree.edit(Range { begin: 10, end: 5 }, "x"),
+            Err(Error::EditRange { .. })
+        ));
+        assert!(matches!(
+            tree.edit(Range::new(0, 100), "x"),
+            Err(Error::EditRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_parser_version() {
+        let mut files = HashMap::new();
+        files.insert(
+            PathBuf::from("a.v"),
+            String::from("module A; wire logic; endmodule\n"),
+        );
+        files.insert(
+            PathBuf::from("b.sv"),
+            String::from("module B; logic b; endmodule\n"),
+        );
+        files.insert(
+            PathBuf::from("c.vh"),
+            String::from("module C; logic c; endmodule\n"),
+        );
+
+        let parser = Parser::new(
+            ParseOptions::new()
+                .extension_version("v", Some(Version::Ieee1364_2005))
+                .extension_version("vh", Some(Version::Ieee1364_2005))
+                .extension_version("vh", None)
+                .provider(Arc::new(files.clone())),
+        );
+        assert!(parser.parse_file("a.v").is_ok());
+        assert!(parser.parse_file("b.sv").is_ok());
+        assert!(parser.parse_file("c.vh").is_ok());
+        assert!(matches!(
+            parser.parse_str("module A; logic a; endmodule", "d.v"),
+            Err(Error::Parse(_))
+        ));
+
+        let parser = Parser::new(
+            ParseOptions::new()
+                .version(Some(Version::Ieee1364_2005))
+                .extension_version("sv", None)
+                .provider(Arc::new(files.clone())),
+        );
+        assert!(parser.parse_file("b.sv").is_ok());
+        assert!(matches!(parser.parse_file("c.vh"), Err(Error::Parse(_))));
+
+        // The recovering parser uses the same options
+        let (_, diagnostics) = parser.parse_file_recover("c.vh").unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        let (_, diagnostics) = parser.parse_file_recover("b.sv").unwrap();
+        assert!(diagnostics.is_empty());
+    }
 }